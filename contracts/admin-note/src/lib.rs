@@ -0,0 +1,123 @@
+// Do not link against libstd (i.e. anything defined in `std::`)
+#![no_std]
+#![feature(alloc_error_handler)]
+
+use miden::*;
+
+// Import the bank account's generated bindings
+use crate::bindings::miden::bank_account::bank_account;
+
+/// Pause the bank
+const ACTION_PAUSE: u64 = 1;
+
+/// Unpause the bank
+const ACTION_UNPAUSE: u64 = 2;
+
+/// Transfer ownership to the AccountId in inputs [1-2]
+const ACTION_TRANSFER_OWNERSHIP: u64 = 3;
+
+/// Set the withdrawal limit described by inputs [1-5]
+const ACTION_SET_WITHDRAW_LIMIT: u64 = 4;
+
+/// Close the bank, returning it to its uninitialized state
+const ACTION_CLOSE: u64 = 5;
+
+/// Set the blacklist flag in input [3] for the AccountId in inputs [1-2]
+const ACTION_SET_BLACKLISTED: u64 = 6;
+
+/// Add the faucet AccountId in inputs [1-2] to the asset allowlist
+const ACTION_ALLOW_ASSET: u64 = 7;
+
+/// Sweep the fees retained in the faucet in inputs [1-2] to the owner, paid out in a
+/// P2ID note described by inputs [3-8]
+const ACTION_SWEEP_FEES: u64 = 8;
+
+/// Move the balance of the AccountId in inputs [1-2] in the faucet in inputs [5-6] to
+/// the AccountId in inputs [3-4]
+const ACTION_MIGRATE_DEPOSITOR: u64 = 9;
+
+/// Admin Note Script
+///
+/// When consumed by the Bank account, this note calls one of the bank's privileged
+/// methods. The bank checks that the note was sent by its owner, so only the owner
+/// can pause, unpause, hand over or close the bank, limit a depositor's withdrawals,
+/// blacklist an account, allow a faucet's assets, sweep retained fees or migrate a
+/// depositor's balance to a new AccountId.
+///
+/// # Flow
+/// 1. Note is created by the bank's owner, naming the action in its inputs
+/// 2. Bank account consumes this note
+/// 3. Note script calls `bank_account::pause()`, `bank_account::unpause()`,
+///    `bank_account::transfer_ownership(new_owner)`,
+///    `bank_account::set_withdraw_limit(depositor, faucet, limit)`,
+///    `bank_account::close()`, `bank_account::set_blacklisted(account, flag)`,
+///    `bank_account::allow_asset(faucet)`,
+///    `bank_account::sweep_fees(faucet, serial_num, tag, note_type)` or
+///    `bank_account::migrate_depositor(old_id, new_id, faucet)`
+/// 4. Bank rejects the call unless the note's sender is the owner
+///
+/// # Note Inputs (1, 3, 4, 6, 7 or 9 Felts)
+/// [0]: action (1 = pause, 2 = unpause, 3 = transfer ownership, 4 = set withdraw limit,
+///      5 = close, 6 = set blacklisted, 7 = allow asset, 8 = sweep fees,
+///      9 = migrate depositor)
+/// [1-2]: new owner AccountId (prefix, suffix) when transferring ownership, the
+///        depositor AccountId (prefix, suffix) when setting a withdraw limit, the
+///        account to block or unblock when setting the blacklist flag, or the faucet
+///        AccountId (prefix, suffix) when allowing an asset or sweeping fees, or the
+///        current depositor AccountId (prefix, suffix) when migrating
+/// [3-4]: faucet AccountId (prefix, suffix); only read when setting a withdraw limit
+/// [3-4]: new depositor AccountId (prefix, suffix); only read when migrating
+/// [5-6]: faucet AccountId (prefix, suffix); only read when migrating
+/// [3]: blacklist flag (1 = blacklisted, 0 = not); only read when setting the blacklist flag
+/// [5]: withdraw limit per window (0 = unlimited); only read when setting a withdraw limit
+/// [3-6]: serial_num of the P2ID fee note; only read when sweeping fees
+/// [7]: tag of the P2ID fee note; only read when sweeping fees
+/// [8]: note_type of the P2ID fee note (1 = Public, 2 = Private); only read when
+///      sweeping fees
+#[note]
+struct AdminNote;
+
+#[note]
+impl AdminNote {
+    #[note_script]
+    fn run(self, _arg: Word) {
+        let inputs = active_note::get_inputs();
+
+        // Compare Felts directly; branching on `as_u64()` of a note input mis-dispatches
+        let action = inputs[0];
+        if action == Felt::from_u64_unchecked(ACTION_PAUSE) {
+            bank_account::pause();
+        } else if action == Felt::from_u64_unchecked(ACTION_UNPAUSE) {
+            bank_account::unpause();
+        } else if action == Felt::from_u64_unchecked(ACTION_TRANSFER_OWNERSHIP) {
+            bank_account::transfer_ownership(AccountId::new(inputs[1], inputs[2]));
+        } else if action == Felt::from_u64_unchecked(ACTION_SET_WITHDRAW_LIMIT) {
+            bank_account::set_withdraw_limit(
+                AccountId::new(inputs[1], inputs[2]),
+                AccountId::new(inputs[3], inputs[4]),
+                inputs[5],
+            );
+        } else if action == Felt::from_u64_unchecked(ACTION_CLOSE) {
+            bank_account::close();
+        } else if action == Felt::from_u64_unchecked(ACTION_SET_BLACKLISTED) {
+            bank_account::set_blacklisted(AccountId::new(inputs[1], inputs[2]), inputs[3]);
+        } else if action == Felt::from_u64_unchecked(ACTION_ALLOW_ASSET) {
+            bank_account::allow_asset(AccountId::new(inputs[1], inputs[2]));
+        } else if action == Felt::from_u64_unchecked(ACTION_SWEEP_FEES) {
+            bank_account::sweep_fees(
+                AccountId::new(inputs[1], inputs[2]),
+                Word::from([inputs[3], inputs[4], inputs[5], inputs[6]]),
+                inputs[7],
+                inputs[8],
+            );
+        } else if action == Felt::from_u64_unchecked(ACTION_MIGRATE_DEPOSITOR) {
+            bank_account::migrate_depositor(
+                AccountId::new(inputs[1], inputs[2]),
+                AccountId::new(inputs[3], inputs[4]),
+                AccountId::new(inputs[5], inputs[6]),
+            );
+        } else {
+            panic!("Unknown admin action");
+        }
+    }
+}
@@ -0,0 +1,49 @@
+// Do not link against libstd (i.e. anything defined in `std::`)
+#![no_std]
+#![feature(alloc_error_handler)]
+
+use miden::*;
+
+// Import the bank account's generated bindings
+use crate::bindings::miden::bank_account::bank_account;
+
+/// Balance Proof Note Script
+///
+/// When consumed by the Bank account, this note asks the bank to emit a note
+/// attesting to the sender's current balance for one faucet, which the depositor
+/// can then hand to a third party.
+///
+/// # Flow
+/// 1. Note is created by the depositor specifying the faucet and proof note details
+/// 2. Bank account consumes this note
+/// 3. Note script reads the sender (depositor) and inputs
+/// 4. Calls `bank_account::emit_balance_proof(depositor, faucet, serial_num, script_root, tag, note_type)`
+/// 5. Bank creates a proof note whose inputs record the balance
+///
+/// # Note Inputs (12 Felts)
+/// [0-1]: faucet AccountId (prefix, suffix)
+/// [2-5]: serial_num for the proof note (random/unique per note)
+/// [6-9]: script root of the proof note
+/// [10]: tag for the proof note
+/// [11]: note_type (1 = Public, 2 = Private)
+#[note]
+struct BalanceProofNote;
+
+#[note]
+impl BalanceProofNote {
+    #[note_script]
+    fn run(self, _arg: Word) {
+        // A depositor can only request a proof of their own balance
+        let depositor = active_note::get_sender();
+
+        let inputs = active_note::get_inputs();
+
+        let faucet = AccountId::new(inputs[0], inputs[1]);
+        let serial_num = Word::from([inputs[2], inputs[3], inputs[4], inputs[5]]);
+        let script_root = Word::from([inputs[6], inputs[7], inputs[8], inputs[9]]);
+        let tag = inputs[10];
+        let note_type = inputs[11];
+
+        bank_account::emit_balance_proof(depositor, faucet, serial_num, script_root, tag, note_type);
+    }
+}
@@ -7,9 +7,10 @@ extern crate alloc;
 
 use miden::*;
 
-/// Maximum allowed deposit amount per transaction.
+/// Default maximum allowed deposit amount per transaction.
 ///
-/// This limit provides a safety constraint for the banking system.
+/// This limit provides a safety constraint for the banking system. Applies when
+/// the bank was created without a `max_deposit` override.
 ///
 /// Value: 1,000,000 tokens (arbitrary limit for demonstration)
 ///
@@ -24,13 +25,113 @@ use miden::*;
 /// effectively rejecting the transaction at the proving stage.
 const MAX_DEPOSIT_AMOUNT: u64 = 1_000_000;
 
+/// Minimum amount a single deposit must carry.
+///
+/// Every accepted deposit can add a balance key, so dust deposits would bloat the
+/// balances map for almost no value. Deposits below this amount are rejected.
+///
+/// Value: 10 tokens
+const MIN_DEPOSIT_AMOUNT: u64 = 10;
+
+/// Default maximum total amount the bank accepts across all deposits in one block.
+///
+/// Applies when the bank was created without a `max_deposit_per_block` override.
+///
+/// Value: 10,000,000 tokens (ten maximum-size deposits)
+const MAX_DEPOSIT_PER_BLOCK: u64 = 10_000_000;
+
+/// Default ceiling on any single depositor's balance for one faucet.
+///
+/// Balances are Felts, so an unchecked sum past the field modulus would wrap to a tiny
+/// balance. No fungible asset amount can exceed 2^63 - 1, so the default ceiling is
+/// that amount, well below the modulus. Applies when the bank was created without a
+/// `max_total_balance` override.
+const MAX_TOTAL_BALANCE: u64 = (1 << 63) - 1;
+
+/// Length of a depositor's withdrawal limit window, in blocks.
+///
+/// Withdrawals count against the depositor's limit until this many blocks have passed
+/// since the window opened; the next withdrawal then opens a fresh window.
+///
+/// Value: 28,800 blocks (about a day at one block every three seconds)
+const WITHDRAW_LIMIT_WINDOW: u64 = 28_800;
+
+/// Denominator for the withdrawal fee: a `fee_bps` of 10,000 is the whole withdrawal.
+const FEE_BPS_DENOMINATOR: u64 = 10_000;
+
+/// A depositor balance, as stored in the `balances` map.
+///
+/// Felt arithmetic is modular, so adding to or subtracting from a raw Felt balance
+/// silently wraps. `Balance` only changes through `checked_add` and `checked_sub`,
+/// which return `None` where a Felt would wrap; callers assert on `None` so the
+/// transaction fails to prove.
+#[derive(Clone, Copy)]
+struct Balance(Felt);
+
+impl Balance {
+    /// The balance as a Felt amount.
+    fn amount(self) -> Felt {
+        self.0
+    }
+
+    /// Add an amount to the balance.
+    ///
+    /// # Returns
+    /// The new balance, or `None` if it would exceed `MAX_TOTAL_BALANCE`
+    fn checked_add(self, amount: Felt) -> Option<Balance> {
+        let sum = self.0.as_u64().checked_add(amount.as_u64())?;
+        if sum > MAX_TOTAL_BALANCE {
+            return None;
+        }
+        Some(Balance(Felt::from_u64_unchecked(sum)))
+    }
+
+    /// An empty balance.
+    fn zero() -> Balance {
+        Balance(felt!(0))
+    }
+
+    /// A balance holding the given amount.
+    ///
+    /// # Returns
+    /// The balance, or `None` if the amount exceeds `MAX_TOTAL_BALANCE`
+    fn from_u64(amount: u64) -> Option<Balance> {
+        if amount > MAX_TOTAL_BALANCE {
+            return None;
+        }
+        Some(Balance(Felt::from_u64_unchecked(amount)))
+    }
+
+    /// Subtract an amount from the balance.
+    ///
+    /// # Returns
+    /// The new balance, or `None` if the amount exceeds the balance
+    fn checked_sub(self, amount: Felt) -> Option<Balance> {
+        let difference = self.0.as_u64().checked_sub(amount.as_u64())?;
+        Some(Balance(Felt::from_u64_unchecked(difference)))
+    }
+}
+
+impl From<Word> for Balance {
+    fn from(value: Word) -> Self {
+        Balance(Felt::from(value))
+    }
+}
+
+impl From<Balance> for Word {
+    fn from(value: Balance) -> Self {
+        Word::from(value.0)
+    }
+}
+
 /// Bank account component that tracks depositor balances.
 ///
 /// Users deposit assets via deposit notes, and the bank tracks
 /// each depositor's balance in a storage map keyed by their AccountId.
 ///
 /// The bank must be initialized before deposits are accepted. This is done
-/// via a transaction script that calls the `initialize()` method.
+/// via a transaction script that calls the `initialize()` method, or by the
+/// first deposit when auto-initialize is enabled at creation.
 #[component]
 struct Bank {
     /// Tracks whether the bank has been initialized (deposits enabled).
@@ -43,6 +144,169 @@ struct Bank {
     /// Key is derived from AccountId: [prefix, suffix, asset_prefix, asset_suffix]
     #[storage(description = "balances")]
     balances: StorageMap,
+
+    /// Optional default faucet for single-token banks.
+    /// Word layout: [faucet_prefix, faucet_suffix, 0, 0]
+    /// An all-zero word means no default is configured and any faucet is accepted.
+    #[storage(description = "default_faucet")]
+    default_faucet: Value,
+
+    /// Whether rejected deposits are refunded instead of failing the transaction.
+    /// Word layout: [is_enabled (0 or 1), clamp_over_cap (0 or 1), 0, 0]
+    /// With clamping, an over-cap deposit is credited up to the per-deposit cap and only
+    /// the excess is refunded. Set when the account is created; only affects
+    /// `deposit_with_refund`.
+    #[storage(description = "refund_mode")]
+    refund_mode: Value,
+
+    /// Maps depositor AccountId -> total amount refunded (as Felt)
+    /// Key is derived from AccountId: [prefix, suffix, asset_prefix, asset_suffix]
+    #[storage(description = "refunds")]
+    refunds: StorageMap,
+
+    /// Whether the first deposit initializes the bank instead of a separate init transaction.
+    /// Word layout: [is_enabled (0 or 1), 0, 0, 0]
+    /// Set when the account is created; the explicit `initialize()` path still works.
+    #[storage(description = "auto_initialize")]
+    auto_initialize: Value,
+
+    /// Maps depositor AccountId -> number of distinct faucets they have deposited (as Felt)
+    /// Key: [prefix, suffix, 0, 0]
+    #[storage(description = "depositor_faucet_counts")]
+    depositor_faucet_counts: StorageMap,
+
+    /// Maps (depositor, position) -> faucet AccountId, in order of first deposit
+    /// Key: [prefix, suffix, position, 0]
+    /// Value: [faucet_prefix, faucet_suffix, 0, 0]
+    #[storage(description = "depositor_faucets")]
+    depositor_faucets: StorageMap,
+
+    /// Minimum number of blocks between a depositor's first deposit and any withdrawal.
+    /// Word layout: [min_age_blocks, 0, 0, 0]
+    /// Set when the account is created; zero disables the check.
+    #[storage(description = "min_account_age")]
+    min_account_age: Value,
+
+    /// Maps depositor AccountId -> block number of their first deposit (as Felt)
+    /// Key: [prefix, suffix, 0, 0]
+    #[storage(description = "first_deposit_blocks")]
+    first_deposit_blocks: StorageMap,
+
+    /// Maps (from faucet, to faucet) -> exchange rate used by `withdraw_exchanged`
+    /// Key: [from_prefix, from_suffix, to_prefix, to_suffix]
+    /// Value: [numerator, denominator, 0, 0]; set when the account is created
+    #[storage(description = "exchange_rates")]
+    exchange_rates: StorageMap,
+
+    /// Maximum total deposited amount per block across all depositors.
+    /// Word layout: [max_per_block, 0, 0, 0]
+    /// Set when the account is created; zero uses `MAX_DEPOSIT_PER_BLOCK`.
+    #[storage(description = "max_deposit_per_block")]
+    max_deposit_per_block: Value,
+
+    /// Amount deposited in the current block, reset when the block advances.
+    /// Word layout: [used_this_block, block_number, 0, 0]
+    #[storage(description = "block_deposits")]
+    block_deposits: Value,
+
+    /// Minimum number of blocks between two withdrawals by the same depositor.
+    /// Word layout: [cooldown_blocks, 0, 0, 0]
+    /// Set when the account is created; zero disables the check.
+    #[storage(description = "withdraw_cooldown")]
+    withdraw_cooldown: Value,
+
+    /// Maps depositor AccountId -> block number of their last withdrawal (as Felt)
+    /// Key: [prefix, suffix, 0, 0]
+    #[storage(description = "last_withdraw_blocks")]
+    last_withdraw_blocks: StorageMap,
+
+    /// Maximum balance a depositor may hold for one faucet.
+    /// Word layout: [max_balance, 0, 0, 0]
+    /// Set when the account is created; zero uses `MAX_TOTAL_BALANCE`.
+    #[storage(description = "max_total_balance")]
+    max_total_balance: Value,
+
+    /// Maximum amount a single deposit may carry.
+    /// Word layout: [max_deposit, 0, 0, 0]
+    /// Set when the account is created; zero uses `MAX_DEPOSIT_AMOUNT`.
+    #[storage(description = "max_deposit")]
+    max_deposit: Value,
+
+    /// Whether deposits and withdrawals are halted.
+    /// Word layout: [is_paused (0 or 1), 0, 0, 0]
+    /// Starts as zero, so an initialized bank is unpaused until `pause()` is called.
+    #[storage(description = "paused")]
+    paused: Value,
+
+    /// Maps faucet AccountId -> sum of all depositor balances in that faucet (as Felt)
+    /// Key: [faucet_prefix, faucet_suffix, 0, 0]
+    #[storage(description = "totals")]
+    totals: StorageMap,
+
+    /// The account allowed to call privileged methods such as `pause()`.
+    /// Word layout: [owner_prefix, owner_suffix, 0, 0]
+    /// Set by `initialize()`; an all-zero word means the bank has no owner.
+    #[storage(description = "owner")]
+    owner: Value,
+
+    /// Maps (depositor, faucet) -> rolling withdrawal limit
+    /// Key: [prefix, suffix, asset_prefix, asset_suffix]
+    /// Value: [limit, window_start_block, withdrawn_in_window, 0]; a zero limit means
+    /// withdrawals are unlimited. Limits are set by the owner via `set_withdraw_limit()`.
+    #[storage(description = "withdraw_limits")]
+    withdraw_limits: StorageMap,
+
+    /// Fee the bank retains on each withdrawal, in basis points of the amount.
+    /// Word layout: [fee_bps, 0, 0, 0]
+    /// Set when the account is created; zero disables the fee.
+    #[storage(description = "fee_bps")]
+    fee_bps: Value,
+
+    /// Maps faucet AccountId -> withdrawal fees retained in the vault (as Felt)
+    /// Key: [faucet_prefix, faucet_suffix, 0, 0]
+    #[storage(description = "fees")]
+    fees: StorageMap,
+
+    /// Number of faucets whose total is nonzero, i.e. that still have depositor balances.
+    /// Word layout: [funded_faucets, 0, 0, 0]
+    /// Kept up to date by `add_to_total` and `subtract_from_total`; `close()` requires zero.
+    #[storage(description = "funded_faucets")]
+    funded_faucets: Value,
+
+    /// MAST root of the note script that withdrawal, refund and receipt notes are sent to.
+    /// Word layout: the full script root
+    /// Set when the account is created; an all-zero word uses `p2id_note_root()`.
+    #[storage(description = "p2id_root")]
+    p2id_root: Value,
+
+    /// Whether deposits are announced to an indexer through deposit event notes.
+    /// Word layout: [is_enabled (0 or 1), indexer_tag, event_count, 0]
+    /// Set when the account is created; `event_count` is kept up to date by deposits.
+    #[storage(description = "deposit_events")]
+    deposit_events: Value,
+
+    /// Maps a balance key -> the block number before which it cannot be withdrawn (as Felt)
+    /// Key: [depositor_prefix, depositor_suffix, faucet_prefix, faucet_suffix]
+    /// Set by `deposit_locked`; a missing entry means the balance is unlocked.
+    #[storage(description = "locks")]
+    locks: StorageMap,
+
+    /// Maps account AccountId -> blacklist flag (as Felt: 1 = blacklisted)
+    /// Key: [prefix, suffix, 0, 0]
+    /// Set by the owner via `set_blacklisted`.
+    #[storage(description = "blacklist")]
+    blacklist: StorageMap,
+
+    /// Maps faucet AccountId -> allowlist flag (as Felt: 1 = allowed)
+    /// Key: [faucet_prefix, faucet_suffix, 0, 0]
+    /// Set by the owner via `allow_asset`.
+    #[storage(description = "allowed_assets")]
+    allowed_assets: StorageMap,
+
+    /// Number of faucets on the allowlist: [count, 0, 0, 0]
+    /// While zero, deposits from any faucet are accepted.
+    #[storage(description = "allowed_asset_count")]
+    allowed_asset_count: Value,
 }
 
 #[component]
@@ -53,9 +317,14 @@ impl Bank {
     /// Once initialized, the bank can accept deposits. This also serves to "deploy"
     /// the account on-chain (accounts are only visible after their first state change).
     ///
+    /// # Arguments
+    /// * `owner` - The AccountId of the initializing account, stored as the bank's owner.
+    ///   An all-zero AccountId leaves the bank without an owner, so its privileged
+    ///   methods can never be called.
+    ///
     /// # Panics
     /// Panics if the bank is already initialized.
-    pub fn initialize(&mut self) {
+    pub fn initialize(&mut self, owner: AccountId) {
         // Check not already initialized
         let current: Word = self.initialized.read();
         assert!(
@@ -63,150 +332,1715 @@ impl Bank {
             "Bank already initialized"
         );
 
+        self.owner.write(Word::from([owner.prefix, owner.suffix, felt!(0), felt!(0)]));
+
         // Set initialized flag to 1
         let initialized_word = Word::from([felt!(1), felt!(0), felt!(0), felt!(0)]);
         self.initialized.write(initialized_word);
     }
 
-    /// Check that the bank is initialized.
+    /// Initialize the bank account unless it is already initialized.
+    ///
+    /// Lets deployment scripts run initialization defensively: an already initialized
+    /// bank is left unchanged instead of failing the transaction. Callers that want a
+    /// second initialization to be an error should use `initialize`.
+    ///
+    /// A call returning 0 changes no state, so a transaction that does nothing else is
+    /// still rejected by the transaction kernel for leaving the account unchanged.
+    ///
+    /// # Arguments
+    /// * `owner` - The AccountId stored as the bank's owner, as in `initialize`.
+    ///   Ignored if the bank is already initialized.
+    ///
+    /// # Returns
+    /// 1 if this call initialized the bank, 0 if it was already initialized.
+    pub fn try_initialize(&mut self, owner: AccountId) -> Felt {
+        let current: Word = self.initialized.read();
+        if current[0].as_u64() != 0 {
+            return felt!(0);
+        }
+
+        self.initialize(owner);
+        felt!(1)
+    }
+
+    /// Initialize the bank with its per-deposit cap and withdrawal fee in one step.
+    ///
+    /// Lets a deployer configure and initialize the bank in a single atomic
+    /// transaction instead of setting the config slots at account creation.
+    /// Otherwise behaves exactly like `initialize`.
+    ///
+    /// # Arguments
+    /// * `max_deposit` - The per-deposit cap, stored in the `max_deposit` slot
+    /// * `fee_bps` - The withdrawal fee in basis points, stored in the `fee_bps` slot
+    /// * `owner` - The AccountId stored as the bank's owner, as in `initialize`
+    ///
+    /// # Panics
+    /// Panics if `max_deposit` is zero.
+    /// Panics if `fee_bps` exceeds 10,000 basis points.
+    /// Panics if the bank is already initialized.
+    pub fn initialize_with_config(&mut self, max_deposit: Felt, fee_bps: Felt, owner: AccountId) {
+        assert!(
+            max_deposit.as_u64() > 0,
+            "Maximum deposit must be positive"
+        );
+        assert!(
+            fee_bps.as_u64() <= FEE_BPS_DENOMINATOR,
+            "Withdrawal fee exceeds 10,000 basis points"
+        );
+
+        self.max_deposit.write(Word::from([max_deposit, felt!(0), felt!(0), felt!(0)]));
+        self.fee_bps.write(Word::from([fee_bps, felt!(0), felt!(0), felt!(0)]));
+
+        self.initialize(owner);
+    }
+
+    /// Return the bank to its uninitialized state.
+    ///
+    /// Lets an operator tear down a test bank and initialize it again. Configuration set
+    /// at creation, such as the per-deposit cap, is kept. Withdrawal fees retained in the
+    /// vault are not depositor funds and do not block closing.
+    ///
+    /// # Panics
+    /// Panics if the note being consumed was not sent by the owner.
+    /// Panics if the bank has not been initialized.
+    /// Panics if any faucet still has depositor balances, so no deposit is stranded.
+    pub fn close(&mut self) {
+        self.require_owner();
+        self.require_initialized();
+
+        let funded: Word = self.funded_faucets.read();
+        assert!(
+            funded[0].as_u64() == 0,
+            "Cannot close the bank while depositor balances remain"
+        );
+
+        self.initialized.write(Word::from([felt!(0), felt!(0), felt!(0), felt!(0)]));
+    }
+
+    /// Get the bank's owner.
+    ///
+    /// # Returns
+    /// The owner's AccountId, or an all-zero AccountId if the bank has no owner
+    pub fn get_owner(&self) -> AccountId {
+        let owner_word: Word = self.owner.read();
+        AccountId::new(owner_word[0], owner_word[1])
+    }
+
+    /// Hand the bank's privileged methods over to a new owner.
+    ///
+    /// # Arguments
+    /// * `new_owner` - The AccountId that becomes the bank's owner
+    ///
+    /// # Panics
+    /// Panics if the note being consumed was not sent by the current owner.
+    pub fn transfer_ownership(&mut self, new_owner: AccountId) {
+        self.require_owner();
+
+        self.owner.write(Word::from([new_owner.prefix, new_owner.suffix, felt!(0), felt!(0)]));
+    }
+
+    /// Cap how much a depositor can withdraw from one faucet per limit window.
+    ///
+    /// Limits the damage a compromised depositor key can do: once the depositor has
+    /// withdrawn `limit` within `WITHDRAW_LIMIT_WINDOW` blocks, further withdrawals fail
+    /// until the window expires. The current window's usage is kept, so lowering a limit
+    /// takes effect immediately.
+    ///
+    /// # Arguments
+    /// * `depositor` - The AccountId whose withdrawals to limit
+    /// * `faucet` - The faucet the limit applies to
+    /// * `limit` - Maximum amount per window; zero removes the limit
+    ///
+    /// # Panics
+    /// Panics if the note being consumed was not sent by the owner.
+    pub fn set_withdraw_limit(&mut self, depositor: AccountId, faucet: AccountId, limit: Felt) {
+        self.require_owner();
+
+        let key = Word::from([depositor.prefix, depositor.suffix, faucet.prefix, faucet.suffix]);
+        let current: Word = self.withdraw_limits.get(&key);
+        self.withdraw_limits.set(key, Word::from([limit, current[1], current[2], felt!(0)]));
+    }
+
+    /// Block or unblock an account from using the bank.
+    ///
+    /// For compliance: a blacklisted account can neither deposit nor move its balance
+    /// out, whether by withdrawal, exchange, transfer or migration, and cannot receive
+    /// transfers or migrations. Balances the account already holds stay frozen in place
+    /// until it is unblocked.
+    ///
+    /// # Arguments
+    /// * `account` - The AccountId to block or unblock
+    /// * `flag` - 1 to blacklist the account, 0 to remove it from the blacklist
+    ///
+    /// # Panics
+    /// Panics if the note being consumed was not sent by the owner.
+    /// Panics if `flag` is neither 0 nor 1.
+    pub fn set_blacklisted(&mut self, account: AccountId, flag: Felt) {
+        self.require_owner();
+
+        assert!(
+            flag.as_u64() == 0 || flag.as_u64() == 1,
+            "Blacklist flag must be 0 or 1"
+        );
+
+        let key = Word::from([account.prefix, account.suffix, felt!(0), felt!(0)]);
+        self.blacklist.set(key, flag);
+    }
+
+    /// Add a faucet to the bank's asset allowlist.
+    ///
+    /// While the allowlist is empty the bank accepts deposits from any faucet. Once
+    /// the owner allows a first faucet, only assets issued by allowed faucets can be
+    /// deposited. Balances already held in other faucets can still be withdrawn.
+    /// Allowing a faucet that is already allowed changes nothing.
+    ///
+    /// # Arguments
+    /// * `faucet` - The faucet whose assets the bank should accept
+    ///
+    /// # Panics
+    /// Panics if the note being consumed was not sent by the owner.
+    pub fn allow_asset(&mut self, faucet: AccountId) {
+        self.require_owner();
+
+        let key = Word::from([faucet.prefix, faucet.suffix, felt!(0), felt!(0)]);
+        let allowed: Felt = self.allowed_assets.get(&key);
+        if allowed.as_u64() == 1 {
+            return;
+        }
+
+        self.allowed_assets.set(key, felt!(1));
+        let count: Word = self.allowed_asset_count.read();
+        self.allowed_asset_count
+            .write(Word::from([count[0] + felt!(1), felt!(0), felt!(0), felt!(0)]));
+    }
+
+    /// Check whether a faucet is on the bank's asset allowlist.
+    ///
+    /// # Arguments
+    /// * `faucet` - The faucet to query
+    ///
+    /// # Returns
+    /// 1 if the faucet was allowed by the owner, 0 otherwise (including when the
+    /// allowlist is empty and every faucet is accepted)
+    pub fn is_asset_allowed(&self, faucet: AccountId) -> Felt {
+        let key = Word::from([faucet.prefix, faucet.suffix, felt!(0), felt!(0)]);
+        self.allowed_assets.get(&key)
+    }
+
+    /// Check whether an account is blacklisted.
+    ///
+    /// # Arguments
+    /// * `account` - The AccountId to query
+    ///
+    /// # Returns
+    /// 1 if the account is blacklisted, 0 otherwise
+    pub fn is_blacklisted(&self, account: AccountId) -> Felt {
+        let key = Word::from([account.prefix, account.suffix, felt!(0), felt!(0)]);
+        self.blacklist.get(&key)
+    }
+
+    /// Configure the default faucet for a single-token bank.
+    ///
+    /// Once set, `deposit` only accepts assets issued by this faucet. This must be
+    /// called before `initialize()` (the init transaction script does this when it
+    /// is given a non-zero argument), so the accepted token is fixed at deployment.
+    ///
+    /// # Arguments
+    /// * `faucet` - The AccountId of the faucet whose assets the bank accepts
+    ///
+    /// # Panics
+    /// Panics if the bank is already initialized.
+    pub fn set_default_faucet(&mut self, faucet: AccountId) {
+        let current: Word = self.initialized.read();
+        assert!(
+            current[0].as_u64() == 0,
+            "Default faucet must be set before initialization"
+        );
+
+        let faucet_word = Word::from([faucet.prefix, faucet.suffix, felt!(0), felt!(0)]);
+        self.default_faucet.write(faucet_word);
+    }
+
+    /// Get the default faucet configured for this bank.
+    ///
+    /// # Returns
+    /// The default faucet's AccountId, or an all-zero AccountId if none is configured
+    pub fn get_default_faucet(&self) -> AccountId {
+        let faucet_word: Word = self.default_faucet.read();
+        AccountId::new(faucet_word[0], faucet_word[1])
+    }
+
+    /// Halt deposits, withdrawals and balance migrations.
+    ///
+    /// An incident kill switch, called by the owner through an admin note. Read-only
+    /// methods such as balance queries and balance proofs keep working.
+    ///
+    /// # Panics
+    /// Panics if the bank has not been initialized.
+    /// Panics if the note being consumed was not sent by the owner.
+    /// Panics if the bank is already paused.
+    pub fn pause(&mut self) {
+        self.require_initialized();
+        self.require_owner();
+
+        let paused: Word = self.paused.read();
+        assert!(paused[0].as_u64() == 0, "Bank already paused");
+
+        self.paused.write(Word::from([felt!(1), felt!(0), felt!(0), felt!(0)]));
+    }
+
+    /// Resume deposits, withdrawals and balance migrations after `pause()`.
+    ///
+    /// # Panics
+    /// Panics if the note being consumed was not sent by the owner.
+    /// Panics if the bank is not paused.
+    pub fn unpause(&mut self) {
+        self.require_owner();
+
+        let paused: Word = self.paused.read();
+        assert!(paused[0].as_u64() == 1, "Bank not paused");
+
+        self.paused.write(Word::from([felt!(0), felt!(0), felt!(0), felt!(0)]));
+    }
+
+    /// Check that the bank is initialized.
+    ///
+    /// This internal function is called at the start of operations that require
+    /// the bank to be initialized (e.g., deposits).
+    ///
+    /// # Panics
+    /// Panics if the bank has not been initialized.
+    fn require_initialized(&self) {
+        let current: Word = self.initialized.read();
+        assert!(
+            current[0].as_u64() == 1,
+            "Bank not initialized - deposits not enabled"
+        );
+    }
+
+    /// Check that the bank has not been paused.
+    ///
+    /// # Panics
+    /// Panics if the bank is paused.
+    fn require_not_paused(&self) {
+        let paused: Word = self.paused.read();
+        assert!(paused[0].as_u64() == 0, "Bank is paused");
+    }
+
+    /// Check that an account has not been blacklisted by the owner.
+    ///
+    /// # Panics
+    /// Panics if the account is blacklisted.
+    fn require_not_blacklisted(&self, account: AccountId) {
+        let key = Word::from([account.prefix, account.suffix, felt!(0), felt!(0)]);
+        let flag: Felt = self.blacklist.get(&key);
+        assert!(flag.as_u64() == 0, "Account is blacklisted");
+    }
+
+    /// Initialize the bank on its first deposit if auto-initialize is enabled.
+    ///
+    /// Does nothing if the bank is already initialized or auto-initialize is disabled,
+    /// so callers still rely on `require_initialized()` afterwards.
+    fn auto_initialize_if_enabled(&mut self) {
+        let current: Word = self.initialized.read();
+        let auto_initialize: Word = self.auto_initialize.read();
+        if current[0].as_u64() == 0 && auto_initialize[0].as_u64() == 1 {
+            let initialized_word = Word::from([felt!(1), felt!(0), felt!(0), felt!(0)]);
+            self.initialized.write(initialized_word);
+        }
+    }
+
+    /// Returns the P2ID note script root digest.
+    ///
+    /// This is a constant value derived from the standard P2ID note script in miden-standards.
+    /// The digest is the MAST root of the compiled P2ID note script.
+    ///
+    /// Note: This value is version-specific to miden-standards. If the P2ID script changes
+    /// in a future version, this digest will need to be updated, or banks can be created
+    /// with the new root in their `p2id_root` slot (see `payout_note_root`).
+    ///
+    fn p2id_note_root() -> Digest {
+        Digest::from_word(Word::new([
+            Felt::from_u64_unchecked(13362761878458161062),
+            Felt::from_u64_unchecked(15090726097241769395),
+            Felt::from_u64_unchecked(444910447169617901),
+            Felt::from_u64_unchecked(3558201871398422326),
+        ]))
+    }
+
+    /// Returns the script root used for the bank's P2ID output notes.
+    ///
+    /// A bank created with a root in its `p2id_root` slot keeps working when the P2ID
+    /// script changes without a contract rebuild; otherwise the compiled-in root is used.
+    fn payout_note_root(&self) -> Digest {
+        let root: Word = self.p2id_root.read();
+        if root[0].as_u64() == 0
+            && root[1].as_u64() == 0
+            && root[2].as_u64() == 0
+            && root[3].as_u64() == 0
+        {
+            return Self::p2id_note_root();
+        }
+
+        Digest::from_word(root)
+    }
+
+    /// Get the limits compiled into this contract.
+    ///
+    /// These are the built-in defaults, independent of any per-deployment
+    /// configuration, so clients can tell a compiled default from a configured value.
+    ///
+    /// # Returns
+    /// A Word laid out as [MAX_DEPOSIT_AMOUNT, MIN_DEPOSIT_AMOUNT, 0, 0]. The remaining
+    /// elements are reserved for further compiled limits and are currently zero.
+    pub fn get_compiled_limits(&self) -> Word {
+        Word::from([
+            Felt::from_u64_unchecked(MAX_DEPOSIT_AMOUNT),
+            Felt::from_u64_unchecked(MIN_DEPOSIT_AMOUNT),
+            felt!(0),
+            felt!(0),
+        ])
+    }
+
+    /// Get a depositor's balance in one faucet's asset.
+    ///
+    /// Balances are keyed per depositor and faucet, using the same key that
+    /// `deposit` and `withdraw` write.
+    ///
+    /// # Arguments
+    /// * `depositor` - The AccountId to query the balance for
+    /// * `faucet` - The faucet whose asset the balance is held in
+    ///
+    /// # Returns
+    /// The depositor's current balance in that faucet as a Felt
+    pub fn get_balance_for_asset(&self, depositor: AccountId, faucet: AccountId) -> Felt {
+        let key = Word::from([depositor.prefix, depositor.suffix, faucet.prefix, faucet.suffix]);
+        let balance: Balance = self.balances.get(&key);
+        balance.amount()
+    }
+
+    /// Deposit an asset into the bank for a specific depositor.
+    ///
+    /// The asset is added to the bank's vault and the depositor's
+    /// balance is updated in the mapping. If deposit events are enabled, a deposit
+    /// event note is also created for the bank's indexer (see `emit_deposit_event`).
+    ///
+    /// # Arguments
+    /// * `depositor` - The AccountId of the user making the deposit
+    /// * `asset` - The fungible asset being deposited
+    ///
+    /// # Panics
+    /// Panics if the asset is non-fungible.
+    /// Panics if the deposit amount is zero.
+    /// Panics if the deposit amount is below `MIN_DEPOSIT_AMOUNT`.
+    /// Panics if the deposit amount exceeds the bank's per-deposit cap.
+    /// Panics if the bank has not been initialized and auto-initialize is disabled.
+    /// Panics if a default faucet is configured and the asset was issued by another faucet.
+    /// Panics if the allowlist is non-empty and the asset's faucet is not on it.
+    /// Panics if the deposit would exceed the bank's per-block deposit throttle.
+    /// Panics if the depositor's new balance would exceed the bank's balance ceiling.
+    /// Panics if the bank's total for the faucet would exceed `MAX_TOTAL_BALANCE`.
+    /// Panics if the bank is paused.
+    /// Panics if the depositor or the sender of the note being consumed is blacklisted.
+    pub fn deposit(&mut self, depositor: AccountId, deposit_asset: Asset) {
+        // Ensure the bank is initialized before accepting deposits
+        self.auto_initialize_if_enabled();
+        self.require_initialized();
+        self.require_not_paused();
+        self.require_not_blacklisted(depositor);
+        // Naming someone else as the beneficiary must not let a blacklisted sender in
+        self.require_not_blacklisted(active_note::get_sender());
+
+        self.credit_deposit(depositor, deposit_asset);
+    }
+
+    /// Deposit every asset of the note being consumed for one depositor in a single call.
+    ///
+    /// Equivalent to calling `deposit` once per asset, but the initialization, pause
+    /// and blacklist checks run once for the whole batch, and a note carrying several assets
+    /// makes one cross-component call instead of one per asset. The assets are read
+    /// from the note here because exported methods cannot take a list of assets.
+    ///
+    /// # Arguments
+    /// * `depositor` - The AccountId of the user making the deposit
+    ///
+    /// # Panics
+    /// Panics under the same conditions as `deposit`, for any asset in the note.
+    pub fn deposit_batch(&mut self, depositor: AccountId) {
+        // Ensure the bank is initialized before accepting deposits
+        self.auto_initialize_if_enabled();
+        self.require_initialized();
+        self.require_not_paused();
+        self.require_not_blacklisted(depositor);
+        self.require_not_blacklisted(active_note::get_sender());
+
+        for deposit_asset in active_note::get_assets() {
+            self.credit_deposit(depositor, deposit_asset);
+        }
+    }
+
+    /// Validate one deposited asset, credit it to the depositor and add it to the vault.
+    ///
+    /// Callers must have checked that the bank is initialized and not paused.
+    fn credit_deposit(&mut self, depositor: AccountId, deposit_asset: Asset) {
+        // The checks below read the first element as an amount, which only holds for
+        // fungible assets
+        Self::require_fungible(&deposit_asset);
+
+        // An empty deposit would only spend proving cycles and add an empty balance key
+        assert!(
+            deposit_asset.inner[0].as_u64() > 0,
+            "Deposit amount must be positive"
+        );
+
+        // Dust deposits would only add balance keys for negligible amounts
+        assert!(
+            deposit_asset.inner[0].as_u64() >= MIN_DEPOSIT_AMOUNT,
+            "Deposit amount is below the minimum allowed"
+        );
+
+        // If the bank has a default faucet, only accept assets from that faucet
+        assert!(
+            self.matches_default_faucet(&deposit_asset),
+            "Deposit asset does not match the default faucet"
+        );
+
+        // If the bank has an allowlist, only accept assets from allowed faucets
+        assert!(
+            self.matches_allowlist(&deposit_asset),
+            "Deposit asset is not on the allowlist"
+        );
+
+        // Extract the fungible amount from the asset
+        // Asset inner layout for fungible: [amount, 0, faucet_suffix, faucet_prefix]
+        let deposit_amount = deposit_asset.inner[0];
+
+        // Validate deposit amount does not exceed maximum
+        assert!(
+            deposit_amount.as_u64() <= self.max_deposit_amount(),
+            "Deposit amount exceeds maximum allowed"
+        );
+
+        // Count the deposit against the bank-wide throttle for this block
+        self.record_block_deposit(deposit_amount);
+
+        // Create key from depositor's AccountId and asset faucet ID
+        // This allows tracking balances per depositor per asset type
+        let key = Word::from([
+            depositor.prefix,
+            depositor.suffix,
+            deposit_asset.inner[3], // asset prefix (faucet)
+            deposit_asset.inner[2], // asset suffix (faucet)
+        ]);
+
+        // Update balance: current + deposit_amount, held to the bank's balance ceiling
+        let current_balance: Balance = self.balances.get(&key);
+        let max_total_balance = self.max_total_balance();
+        let new_balance = current_balance
+            .checked_add(deposit_amount)
+            .filter(|balance| balance.amount().as_u64() <= max_total_balance)
+            .expect("Deposit would exceed the maximum total balance");
+        self.balances.set(key, new_balance);
+        self.add_to_total(deposit_asset.inner[3], deposit_asset.inner[2], deposit_amount);
+
+        // Remember the faucet so the depositor's holdings can be listed later
+        self.index_depositor_faucet(depositor, deposit_asset.inner[3], deposit_asset.inner[2]);
+
+        // Start the depositor's account age on their first deposit
+        let age_key = Word::from([depositor.prefix, depositor.suffix, felt!(0), felt!(0)]);
+        let first_deposit_block: Felt = self.first_deposit_blocks.get(&age_key);
+        if first_deposit_block.as_u64() == 0 {
+            self.first_deposit_blocks.set(age_key, tx::get_block_number());
+        }
+
+        // Add asset to the bank's vault
+        native_account::add_asset(deposit_asset);
+
+        self.emit_deposit_event(depositor, deposit_asset.inner[3], deposit_amount);
+    }
+
+    /// Announce a credited deposit to the bank's indexer, if deposit events are enabled.
+    ///
+    /// The event is a public, asset-less note tagged with the configured indexer tag and
+    /// built with the payout script root, like receipt notes. Its inputs record the
+    /// deposit, so off-chain services can rebuild balance history from the notes alone
+    /// instead of parsing account deltas. The serial number is the bank's running event
+    /// count, so two identical deposits still produce distinct notes.
+    ///
+    /// # Deposit Event Note Inputs (4 Felts)
+    /// [depositor_prefix, depositor_suffix, faucet_prefix, amount]
+    fn emit_deposit_event(&mut self, depositor: AccountId, faucet_prefix: Felt, amount: Felt) {
+        let deposit_events: Word = self.deposit_events.read();
+        if deposit_events[0].as_u64() == 0 {
+            return;
+        }
+
+        let event_count = deposit_events[2] + felt!(1);
+        self.deposit_events.write(Word::from([
+            deposit_events[0],
+            deposit_events[1],
+            event_count,
+            felt!(0),
+        ]));
+
+        let recipient = Recipient::compute(
+            Word::from([event_count, felt!(0), felt!(0), felt!(0)]),
+            self.payout_note_root(),
+            vec![depositor.prefix, depositor.suffix, faucet_prefix, amount],
+        );
+
+        output_note::create(
+            Tag::from(deposit_events[1]),
+            Self::note_type_from_felt(felt!(1)),
+            recipient,
+        );
+    }
+
+    /// Deposit an asset, refunding it to the depositor if the bank rejects it.
+    ///
+    /// When refund mode is enabled and the asset fails one of the recoverable deposit
+    /// checks (default faucet mismatch, faucet not on the allowlist or amount above the
+    /// per-deposit cap), the asset is returned to the depositor in a P2ID note and the
+    /// refunded amount is recorded, so the transaction still succeeds and the assets are
+    /// not stranded in the note.
+    /// If clamping is also enabled, an accepted-faucet deposit above the cap is credited
+    /// up to the per-deposit cap and only the excess is refunded.
+    /// Otherwise this behaves exactly like `deposit`.
+    ///
+    /// # Arguments
+    /// * `depositor` - The AccountId of the user making the deposit
+    /// * `deposit_asset` - The fungible asset being deposited
+    /// * `serial_num` - Unique serial number for the P2ID refund note
+    /// * `tag` - The note tag for the P2ID refund note
+    /// * `note_type` - Note type: 1 = Public (stored on-chain), 2 = Private (off-chain)
+    ///
+    /// # Panics
+    /// Panics if the bank has not been initialized or is paused.
+    /// Panics if the asset is non-fungible, as it has no amount to refund.
+    /// Panics if the depositor or the note's sender is blacklisted, whether or not refund
+    /// mode is enabled.
+    /// Panics under the same conditions as `deposit` when refund mode is disabled.
+    pub fn deposit_with_refund(
+        &mut self,
+        depositor: AccountId,
+        deposit_asset: Asset,
+        serial_num: Word,
+        tag: Felt,
+        note_type: Felt,
+    ) {
+        Self::require_fungible(&deposit_asset);
+
+        // A blacklisted depositor or sender gets no refund either; the deposit fails outright
+        self.require_not_blacklisted(depositor);
+        self.require_not_blacklisted(active_note::get_sender());
+
+        let refund_mode: Word = self.refund_mode.read();
+        let max_deposit = self.max_deposit_amount();
+        let accepted_faucet =
+            self.matches_default_faucet(&deposit_asset) && self.matches_allowlist(&deposit_asset);
+        let accepted = deposit_asset.inner[0].as_u64() <= max_deposit && accepted_faucet;
+
+        if refund_mode[0].as_u64() == 0 || accepted {
+            self.deposit(depositor, deposit_asset);
+            return;
+        }
+
+        // Clamp mode: credit what fits under the cap and refund only the excess
+        let refund_asset = if refund_mode[1].as_u64() == 1 && accepted_faucet {
+            // Asset inner layout for fungible: [amount, 0, faucet_suffix, faucet_prefix]
+            let accepted_amount = Felt::from_u64_unchecked(max_deposit);
+            let accepted_asset = Asset::new(Word::from([
+                accepted_amount,
+                felt!(0),
+                deposit_asset.inner[2],
+                deposit_asset.inner[3],
+            ]));
+            self.deposit(depositor, accepted_asset);
+
+            Asset::new(Word::from([
+                deposit_asset.inner[0] - accepted_amount,
+                felt!(0),
+                deposit_asset.inner[2],
+                deposit_asset.inner[3],
+            ]))
+        } else {
+            // A refund is still a bank operation, so the bank must be live
+            self.auto_initialize_if_enabled();
+            self.require_initialized();
+            self.require_not_paused();
+            deposit_asset
+        };
+
+        // Record the rejection before returning the asset
+        let key = Word::from([
+            depositor.prefix,
+            depositor.suffix,
+            refund_asset.inner[3], // asset prefix (faucet)
+            refund_asset.inner[2], // asset suffix (faucet)
+        ]);
+        let refunded: Felt = self.refunds.get(&key);
+        self.refunds.set(key, refunded + refund_asset.inner[0]);
+
+        // Take custody of the asset and send it straight back to the depositor
+        native_account::add_asset(refund_asset);
+        self.create_p2id_note(serial_num, &refund_asset, depositor, tag, note_type);
+    }
+
+    /// Deposit an asset and send the depositor a receipt note confirming it.
+    ///
+    /// The deposit itself behaves exactly like `deposit`. The receipt is an asset-less
+    /// note addressed to the depositor through `tag`, built with the P2ID script root
+    /// like the notes from `create_p2id_note`, but whose inputs record the deposit
+    /// instead of a target account. The standard P2ID script expects exactly two inputs,
+    /// so the receipt serves as an off-chain record: its recipient digest commits to the
+    /// inputs below.
+    ///
+    /// # Arguments
+    /// * `depositor` - The AccountId of the user making the deposit
+    /// * `deposit_asset` - The fungible asset being deposited
+    /// * `serial_num` - Unique serial number for the receipt note
+    /// * `tag` - The note tag for the receipt note
+    /// * `note_type` - Note type: 1 = Public (stored on-chain), 2 = Private (off-chain)
+    ///
+    /// # Receipt Note Inputs (4 Felts)
+    /// [amount, faucet_prefix, faucet_suffix, new_balance]
+    ///
+    /// # Panics
+    /// Panics under the same conditions as `deposit`.
+    /// Panics if `note_type` is neither 1 nor 2.
+    pub fn deposit_with_receipt(
+        &mut self,
+        depositor: AccountId,
+        deposit_asset: Asset,
+        serial_num: Word,
+        tag: Felt,
+        note_type: Felt,
+    ) {
+        self.deposit(depositor, deposit_asset);
+
+        // Asset inner layout for fungible: [amount, 0, faucet_suffix, faucet_prefix]
+        let faucet = AccountId::new(deposit_asset.inner[3], deposit_asset.inner[2]);
+        let new_balance = self.get_balance_for_asset(depositor, faucet);
+
+        let recipient = Recipient::compute(
+            serial_num,
+            self.payout_note_root(),
+            vec![deposit_asset.inner[0], faucet.prefix, faucet.suffix, new_balance],
+        );
+
+        output_note::create(Tag::from(tag), Self::note_type_from_felt(note_type), recipient);
+    }
+
+    /// Deposit an asset that cannot be withdrawn before a given block.
+    ///
+    /// The deposit itself behaves exactly like `deposit`. The lock applies to the
+    /// depositor's whole balance in the asset's faucet, which can then be neither
+    /// withdrawn nor transferred until the chain reaches `unlock_block`. A later lock
+    /// only ever extends an existing one, so a short lock cannot release savings
+    /// locked for longer.
+    ///
+    /// # Arguments
+    /// * `depositor` - The AccountId of the user making the deposit
+    /// * `deposit_asset` - The fungible asset being deposited
+    /// * `unlock_block` - First block number at which the balance can be withdrawn
+    ///
+    /// # Panics
+    /// Panics under the same conditions as `deposit`.
+    pub fn deposit_locked(&mut self, depositor: AccountId, deposit_asset: Asset, unlock_block: Felt) {
+        self.deposit(depositor, deposit_asset);
+
+        let key = Word::from([
+            depositor.prefix,
+            depositor.suffix,
+            deposit_asset.inner[3], // asset prefix (faucet)
+            deposit_asset.inner[2], // asset suffix (faucet)
+        ]);
+        let current_unlock_block: Felt = self.locks.get(&key);
+        if unlock_block.as_u64() > current_unlock_block.as_u64() {
+            self.locks.set(key, unlock_block);
+        }
+    }
+
+    /// Get the block at which a depositor's balance for a given faucet unlocks.
+    ///
+    /// # Arguments
+    /// * `depositor` - The AccountId whose lock to query
+    /// * `faucet` - The faucet of the locked balance
+    ///
+    /// # Returns
+    /// The unlock block number as a Felt, or 0 if the balance was never locked
+    pub fn get_unlock_block(&self, depositor: AccountId, faucet: AccountId) -> Felt {
+        let key = Word::from([depositor.prefix, depositor.suffix, faucet.prefix, faucet.suffix]);
+        self.locks.get(&key)
+    }
+
+    /// Get the total amount refunded to a depositor for a given faucet.
+    ///
+    /// # Arguments
+    /// * `depositor` - The AccountId whose refunds to query
+    /// * `faucet` - The faucet of the refunded asset
+    ///
+    /// # Returns
+    /// The cumulative refunded amount as a Felt
+    pub fn get_refunded(&self, depositor: AccountId, faucet: AccountId) -> Felt {
+        let key = Word::from([depositor.prefix, depositor.suffix, faucet.prefix, faucet.suffix]);
+        self.refunds.get(&key)
+    }
+
+    /// Get the block number of a depositor's first deposit.
+    ///
+    /// # Arguments
+    /// * `depositor` - The AccountId to query
+    ///
+    /// # Returns
+    /// The first deposit's block number as a Felt, or zero if they never deposited
+    pub fn get_first_deposit_block(&self, depositor: AccountId) -> Felt {
+        let age_key = Word::from([depositor.prefix, depositor.suffix, felt!(0), felt!(0)]);
+        self.first_deposit_blocks.get(&age_key)
+    }
+
+    /// Get how much more the bank accepts in deposits during the current block.
+    ///
+    /// # Returns
+    /// The remaining per-block deposit allowance as a Felt
+    pub fn get_block_deposit_remaining(&self) -> Felt {
+        let used = self.block_deposits_used(tx::get_block_number().as_u64());
+        Felt::from_u64_unchecked(self.block_deposit_limit() - used)
+    }
+
+    /// Get the block number of a depositor's last withdrawal.
+    ///
+    /// # Arguments
+    /// * `depositor` - The AccountId to query
+    ///
+    /// # Returns
+    /// The last withdrawal's block number as a Felt, or zero if they never withdrew
+    pub fn get_last_withdraw_block(&self, depositor: AccountId) -> Felt {
+        let withdraw_key = Word::from([depositor.prefix, depositor.suffix, felt!(0), felt!(0)]);
+        self.last_withdraw_blocks.get(&withdraw_key)
+    }
+
+    /// Get the number of distinct faucets a depositor has ever deposited.
+    ///
+    /// # Arguments
+    /// * `depositor` - The AccountId whose faucet index to query
+    ///
+    /// # Returns
+    /// The number of entries in the depositor's faucet index as a Felt
+    pub fn get_depositor_faucet_count(&self, depositor: AccountId) -> Felt {
+        let count_key = Word::from([depositor.prefix, depositor.suffix, felt!(0), felt!(0)]);
+        self.depositor_faucet_counts.get(&count_key)
+    }
+
+    /// Get one faucet from a depositor's faucet index.
+    ///
+    /// Storage maps can't be iterated, so callers read `get_depositor_faucet_count()`
+    /// and then fetch each position.
+    ///
+    /// # Arguments
+    /// * `depositor` - The AccountId whose faucet index to query
+    /// * `position` - Zero-based position in order of first deposit
+    ///
+    /// # Returns
+    /// The faucet's AccountId, or an all-zero AccountId past the end of the index
+    pub fn get_depositor_faucet(&self, depositor: AccountId, position: Felt) -> AccountId {
+        let entry_key = Word::from([depositor.prefix, depositor.suffix, position, felt!(0)]);
+        let faucet_word: Word = self.depositor_faucets.get(&entry_key);
+        AccountId::new(faucet_word[0], faucet_word[1])
+    }
+
+    /// Get the bank's total liability in one faucet's asset.
+    ///
+    /// This is the sum of every depositor's balance in that faucet, so an auditor can
+    /// compare it against the vault's actual holdings.
+    ///
+    /// # Arguments
+    /// * `faucet` - The faucet to query the total for
+    ///
+    /// # Returns
+    /// The total of all depositor balances in that faucet as a Felt
+    pub fn get_total(&self, faucet: AccountId) -> Felt {
+        let key = Word::from([faucet.prefix, faucet.suffix, felt!(0), felt!(0)]);
+        self.totals.get(&key)
+    }
+
+    /// Get the withdrawal fees the bank has retained in one faucet's asset.
+    ///
+    /// Fees stay in the vault but belong to no depositor, so they are not part of
+    /// `get_total()`. The owner can pay them out with `sweep_fees()`.
+    ///
+    /// # Arguments
+    /// * `faucet` - The faucet to query the fees for
+    ///
+    /// # Returns
+    /// The retained fees in that faucet as a Felt
+    pub fn get_fees(&self, faucet: AccountId) -> Felt {
+        let key = Word::from([faucet.prefix, faucet.suffix, felt!(0), felt!(0)]);
+        self.fees.get(&key)
+    }
+
+    /// Get the amount of one faucet's asset actually held in the bank's vault.
+    ///
+    /// Meant for audits: every deposit, withdrawal and fee moves the vault and the
+    /// tracked amounts together, so the result should always equal `get_total()` plus
+    /// `get_fees()` for the same faucet. Any difference is accounting drift.
+    ///
+    /// # Arguments
+    /// * `faucet` - The faucet to query the vault holdings for
+    ///
+    /// # Returns
+    /// The vault's balance of that faucet's asset as a Felt
+    pub fn get_vault_balance(&self, faucet: AccountId) -> Felt {
+        active_account::get_balance(faucet)
+    }
+
+    /// Add a credited amount to a faucet's total.
+    ///
+    /// # Panics
+    /// Panics if the total would exceed `MAX_TOTAL_BALANCE`, as Felt addition would
+    /// otherwise wrap near the field modulus.
+    fn add_to_total(&mut self, faucet_prefix: Felt, faucet_suffix: Felt, amount: Felt) {
+        let key = Word::from([faucet_prefix, faucet_suffix, felt!(0), felt!(0)]);
+        let total: Felt = self.totals.get(&key);
+        assert!(
+            total.as_u64() + amount.as_u64() <= MAX_TOTAL_BALANCE,
+            "Deposit would exceed the bank's total for this faucet"
+        );
+        self.totals.set(key, total + amount);
+
+        if total.as_u64() == 0 && amount.as_u64() > 0 {
+            let funded: Word = self.funded_faucets.read();
+            self.funded_faucets
+                .write(Word::from([funded[0] + felt!(1), felt!(0), felt!(0), felt!(0)]));
+        }
+    }
+
+    /// Subtract a debited amount from a faucet's total.
+    ///
+    /// Callers debit a depositor balance first, and every balance is part of the total,
+    /// so the total always covers the amount.
+    fn subtract_from_total(&mut self, faucet_prefix: Felt, faucet_suffix: Felt, amount: Felt) {
+        let key = Word::from([faucet_prefix, faucet_suffix, felt!(0), felt!(0)]);
+        let total: Felt = self.totals.get(&key);
+        let new_total = total - amount;
+        self.totals.set(key, new_total);
+
+        if total.as_u64() > 0 && new_total.as_u64() == 0 {
+            let funded: Word = self.funded_faucets.read();
+            self.funded_faucets
+                .write(Word::from([funded[0] - felt!(1), felt!(0), felt!(0), felt!(0)]));
+        }
+    }
+
+    /// Append a faucet to a depositor's faucet index unless it is already listed.
+    ///
+    /// The index is expected to stay small, so membership is a linear scan.
+    fn index_depositor_faucet(
+        &mut self,
+        depositor: AccountId,
+        faucet_prefix: Felt,
+        faucet_suffix: Felt,
+    ) {
+        let count_key = Word::from([depositor.prefix, depositor.suffix, felt!(0), felt!(0)]);
+        let count: Felt = self.depositor_faucet_counts.get(&count_key);
+
+        for position in 0..count.as_u64() {
+            let entry_key = Word::from([
+                depositor.prefix,
+                depositor.suffix,
+                Felt::from_u64_unchecked(position),
+                felt!(0),
+            ]);
+            let entry: Word = self.depositor_faucets.get(&entry_key);
+            if entry[0] == faucet_prefix && entry[1] == faucet_suffix {
+                return;
+            }
+        }
+
+        let entry_key = Word::from([depositor.prefix, depositor.suffix, count, felt!(0)]);
+        self.depositor_faucets
+            .set(entry_key, Word::from([faucet_prefix, faucet_suffix, felt!(0), felt!(0)]));
+        self.depositor_faucet_counts.set(count_key, count + felt!(1));
+    }
+
+    /// Check whether an asset satisfies the default faucet restriction.
+    ///
+    /// Returns true if no default faucet is configured, or if the asset was
+    /// issued by the configured default faucet.
+    fn matches_default_faucet(&self, asset: &Asset) -> bool {
+        let default_faucet: Word = self.default_faucet.read();
+        if default_faucet[0].as_u64() == 0 && default_faucet[1].as_u64() == 0 {
+            return true;
+        }
+
+        asset.inner[3] == default_faucet[0] && asset.inner[2] == default_faucet[1]
+    }
+
+    /// Check whether an asset satisfies the asset allowlist.
+    ///
+    /// Returns true if the allowlist is empty, or if the asset was issued by
+    /// an allowed faucet.
+    fn matches_allowlist(&self, asset: &Asset) -> bool {
+        let count: Word = self.allowed_asset_count.read();
+        if count[0].as_u64() == 0 {
+            return true;
+        }
+
+        let key = Word::from([asset.inner[3], asset.inner[2], felt!(0), felt!(0)]);
+        let allowed: Felt = self.allowed_assets.get(&key);
+        allowed.as_u64() == 1
+    }
+
+    /// Check that an asset is fungible before its first element is read as an amount.
+    ///
+    /// Fungible assets are laid out as [amount, 0, faucet_suffix, faucet_prefix], while
+    /// non-fungible assets carry part of their data hash in the second element.
+    ///
+    /// # Panics
+    /// Panics if the asset is non-fungible.
+    fn require_fungible(asset: &Asset) {
+        assert!(
+            asset.inner[1].as_u64() == 0,
+            "Only fungible assets can be deposited"
+        );
+    }
+
+    /// Move a depositor's entire balance for one faucet to a new AccountId.
     ///
-    /// This internal function is called at the start of operations that require
-    /// the bank to be initialized (e.g., deposits).
+    /// Used when a depositor rotates keys and receives a new AccountId, either by the
+    /// depositor (migrate note) or on their behalf by the owner (admin note). The funds
+    /// stay in the bank's vault; only the balance entry moves, so no P2ID note is created.
+    /// A time lock on the balance (see `deposit_locked`) moves with it, and `new_id`
+    /// inherits `old_id`'s withdrawal limit window and withdraw cooldown.
+    ///
+    /// # Arguments
+    /// * `old_id` - The AccountId currently holding the balance
+    /// * `new_id` - The AccountId that receives the balance
+    /// * `faucet` - The faucet of the balance being moved
     ///
     /// # Panics
     /// Panics if the bank has not been initialized.
-    fn require_initialized(&self) {
-        let current: Word = self.initialized.read();
+    /// Panics if the note being consumed was sent by neither `old_id` nor the owner.
+    /// Panics if `old_id` has no balance for this faucet.
+    /// Panics if `new_id` already holds a balance for this faucet.
+    /// Panics if the bank is paused.
+    /// Panics if `old_id` or `new_id` is blacklisted.
+    pub fn migrate_depositor(&mut self, old_id: AccountId, new_id: AccountId, faucet: AccountId) {
+        self.require_initialized();
+        self.require_not_paused();
+        self.require_note_sender_or_owner(old_id);
+        self.require_not_blacklisted(old_id);
+        self.require_not_blacklisted(new_id);
+
+        let old_key = Word::from([old_id.prefix, old_id.suffix, faucet.prefix, faucet.suffix]);
+        let new_key = Word::from([new_id.prefix, new_id.suffix, faucet.prefix, faucet.suffix]);
+
+        let balance: Balance = self.balances.get(&old_key);
+        assert!(balance.amount().as_u64() > 0, "No balance to migrate");
+
+        // Refuse to merge into an existing balance so a migration can't be mistaken for a transfer
+        let target_balance: Balance = self.balances.get(&new_key);
         assert!(
-            current[0].as_u64() == 1,
-            "Bank not initialized - deposits not enabled"
+            target_balance.amount().as_u64() == 0,
+            "Migration target already holds a balance"
         );
+
+        let new_balance = target_balance
+            .checked_add(balance.amount())
+            .expect("Migration would exceed the maximum total balance");
+        self.balances.set(old_key, Balance::zero());
+        self.balances.set(new_key, new_balance);
+        self.index_depositor_faucet(new_id, faucet.prefix, faucet.suffix);
+
+        // The lock moves with the balance so migrating can't release locked savings
+        let unlock_block: Felt = self.locks.get(&old_key);
+        if unlock_block.as_u64() != 0 {
+            self.locks.set(old_key, felt!(0));
+            self.locks.set(new_key, unlock_block);
+        }
+
+        // Carry the account age over so migrating can't skip the minimum age
+        let old_age_key = Word::from([old_id.prefix, old_id.suffix, felt!(0), felt!(0)]);
+        let new_age_key = Word::from([new_id.prefix, new_id.suffix, felt!(0), felt!(0)]);
+        let new_first_deposit_block: Felt = self.first_deposit_blocks.get(&new_age_key);
+        if new_first_deposit_block.as_u64() == 0 {
+            let old_first_deposit_block: Felt = self.first_deposit_blocks.get(&old_age_key);
+            self.first_deposit_blocks.set(new_age_key, old_first_deposit_block);
+        }
+
+        // Carry the withdrawal limit window and the cooldown over so migrating can't
+        // reset either; `old_id` keeps its own copies
+        let withdraw_limit: Word = self.withdraw_limits.get(&old_key);
+        let target_withdraw_limit: Word = self.withdraw_limits.get(&new_key);
+        if withdraw_limit[0].as_u64() != 0 && target_withdraw_limit[0].as_u64() == 0 {
+            self.withdraw_limits.set(new_key, withdraw_limit);
+        }
+
+        let old_withdraw_block: Felt = self.last_withdraw_blocks.get(&old_age_key);
+        let new_withdraw_block: Felt = self.last_withdraw_blocks.get(&new_age_key);
+        if old_withdraw_block.as_u64() > new_withdraw_block.as_u64() {
+            self.last_withdraw_blocks.set(new_age_key, old_withdraw_block);
+        }
     }
 
-    /// Returns the P2ID note script root digest.
+    /// Move part of a depositor's balance for one faucet to another depositor.
     ///
-    /// This is a constant value derived from the standard P2ID note script in miden-standards.
-    /// The digest is the MAST root of the compiled P2ID note script.
+    /// Saves a withdraw-and-deposit round trip: the assets stay in the bank's vault and
+    /// only the two balance entries change, so the faucet's total is unaffected. The
+    /// amount counts against the sender's withdrawal limit, as it leaves their control
+    /// just like a withdrawal.
     ///
-    /// Note: This value is version-specific to miden-standards. If the P2ID script changes
-    /// in a future version, this digest will need to be updated.
+    /// # Arguments
+    /// * `sender` - The AccountId whose balance is debited
+    /// * `recipient` - The AccountId whose balance is credited
+    /// * `faucet` - The faucet of the balance being moved
+    /// * `amount` - The amount to move
     ///
-    fn p2id_note_root() -> Digest {
-        Digest::from_word(Word::new([
-            Felt::from_u64_unchecked(13362761878458161062),
-            Felt::from_u64_unchecked(15090726097241769395),
-            Felt::from_u64_unchecked(444910447169617901),
-            Felt::from_u64_unchecked(3558201871398422326),
-        ]))
+    /// # Panics
+    /// Panics if the bank has not been initialized or is paused.
+    /// Panics if the note being consumed was not sent by `sender`.
+    /// Panics if the amount is zero or exceeds `sender`'s balance.
+    /// Panics if `recipient`'s new balance would exceed the bank's balance ceiling.
+    /// Panics if a withdrawal limit is set for `sender` and the amount would exceed what
+    /// is left of it in the current window.
+    /// Panics if `sender`'s balance is locked and the current block is before its
+    /// unlock block.
+    /// Panics if `sender` or `recipient` is blacklisted.
+    pub fn transfer(&mut self, sender: AccountId, recipient: AccountId, faucet: AccountId, amount: Felt) {
+        self.require_initialized();
+        self.require_not_paused();
+        self.require_note_sender(sender);
+        self.require_not_blacklisted(sender);
+        self.require_not_blacklisted(recipient);
+
+        assert!(amount.as_u64() > 0, "Transfer amount must be positive");
+
+        // A transfer would otherwise move locked savings out from under their lock
+        self.require_unlocked(sender, faucet.prefix, faucet.suffix);
+
+        let sender_key = Word::from([sender.prefix, sender.suffix, faucet.prefix, faucet.suffix]);
+        let recipient_key = Word::from([recipient.prefix, recipient.suffix, faucet.prefix, faucet.suffix]);
+
+        // Felt arithmetic is modular, so both sides go through checked arithmetic
+        let sender_balance: Balance = self.balances.get(&sender_key);
+        let new_sender_balance = sender_balance
+            .checked_sub(amount)
+            .expect("Transfer amount exceeds available balance");
+
+        self.record_withdraw_amount(sender, faucet.prefix, faucet.suffix, amount);
+
+        self.balances.set(sender_key, new_sender_balance);
+
+        let recipient_balance: Balance = self.balances.get(&recipient_key);
+        let max_total_balance = self.max_total_balance();
+        let new_recipient_balance = recipient_balance
+            .checked_add(amount)
+            .filter(|balance| balance.amount().as_u64() <= max_total_balance)
+            .expect("Transfer would exceed the maximum total balance");
+        self.balances.set(recipient_key, new_recipient_balance);
+        self.index_depositor_faucet(recipient, faucet.prefix, faucet.suffix);
+
+        // A recipient with no deposits inherits the sender's age, so a transfer can't be
+        // used to skip the minimum account age
+        let sender_age_key = Word::from([sender.prefix, sender.suffix, felt!(0), felt!(0)]);
+        let recipient_age_key = Word::from([recipient.prefix, recipient.suffix, felt!(0), felt!(0)]);
+        let recipient_first_deposit_block: Felt = self.first_deposit_blocks.get(&recipient_age_key);
+        if recipient_first_deposit_block.as_u64() == 0 {
+            let sender_first_deposit_block: Felt = self.first_deposit_blocks.get(&sender_age_key);
+            self.first_deposit_blocks.set(recipient_age_key, sender_first_deposit_block);
+        }
     }
 
-    /// Get the balance for a depositor.
+    /// Emit a note attesting to a depositor's current balance.
+    ///
+    /// The bank creates an asset-less note whose inputs record the depositor, the faucet,
+    /// the balance and the block it was read at. Because the bank is the note's sender,
+    /// a third party who is shown the note can check those inputs against its recipient
+    /// digest as evidence of the balance at that block.
+    ///
+    /// The proof note's script only decides who may later consume it; the bank does not
+    /// interpret it.
     ///
     /// # Arguments
-    /// * `depositor` - The AccountId to query the balance for
+    /// * `depositor` - The AccountId whose balance to attest
+    /// * `faucet` - The faucet of the attested balance
+    /// * `serial_num` - Unique serial number for the proof note
+    /// * `script_root` - MAST root of the proof note's script
+    /// * `tag` - The note tag for the proof note
+    /// * `note_type` - Note type: 1 = Public (stored on-chain), 2 = Private (off-chain)
     ///
-    /// # Returns
-    /// The depositor's current balance as a Felt
-    pub fn get_balance(&self, depositor: AccountId) -> Felt {
-        let key = Word::from([depositor.prefix, depositor.suffix, felt!(0), felt!(0)]);
-        self.balances.get(&key)
+    /// # Proof Note Inputs (6 Felts)
+    /// [depositor_prefix, depositor_suffix, faucet_prefix, faucet_suffix, balance, block_number]
+    ///
+    /// # Panics
+    /// Panics if the bank has not been initialized.
+    /// Panics if the note being consumed was not sent by `depositor`.
+    /// Panics if `note_type` is neither 1 nor 2.
+    pub fn emit_balance_proof(
+        &mut self,
+        depositor: AccountId,
+        faucet: AccountId,
+        serial_num: Word,
+        script_root: Word,
+        tag: Felt,
+        note_type: Felt,
+    ) {
+        self.require_initialized();
+
+        // Only the depositor may publish their own balance
+        self.require_note_sender(depositor);
+
+        let key = Word::from([depositor.prefix, depositor.suffix, faucet.prefix, faucet.suffix]);
+        let balance: Felt = self.balances.get(&key);
+
+        let recipient = Recipient::compute(
+            serial_num,
+            Digest::from_word(script_root),
+            vec![
+                depositor.prefix,
+                depositor.suffix,
+                faucet.prefix,
+                faucet.suffix,
+                balance,
+                tx::get_block_number(),
+            ],
+        );
+
+        output_note::create(Tag::from(tag), Self::note_type_from_felt(note_type), recipient);
     }
 
-    /// Deposit an asset into the bank for a specific depositor.
+    /// Withdraw assets back to the depositor.
     ///
-    /// The asset is added to the bank's vault and the depositor's
-    /// balance is updated in the mapping.
+    /// Creates a P2ID note that sends the requested asset to the depositor's account.
+    /// If a withdrawal fee is configured, the balance is debited by the full amount but
+    /// the note carries the amount less the fee, which stays in the vault.
     ///
     /// # Arguments
-    /// * `depositor` - The AccountId of the user making the deposit
-    /// * `asset` - The fungible asset being deposited
+    /// * `depositor` - The AccountId of the user withdrawing
+    /// * `withdraw_asset` - The fungible asset to withdraw
+    /// * `serial_num` - Unique serial number for the P2ID output note
+    /// * `tag` - The note tag for the P2ID output note (allows caller to specify routing)
+    /// * `note_type` - Note type: 1 = Public (stored on-chain), 2 = Private (off-chain)
+    /// * `deadline` - Last block number at which the request may execute (0 = no deadline)
+    ///
+    /// # Returns
+    /// `[new_balance, note_index, 0, 0]`: the depositor's balance left in the faucet
+    /// after the withdrawal, and the index of the P2ID note among the transaction's
+    /// output notes, so the client can look up the exact note it has to watch for
     ///
     /// # Panics
-    /// Panics if the deposit amount exceeds `MAX_DEPOSIT_AMOUNT`.
+    /// Panics if the withdrawal amount is zero or exceeds the depositor's current balance.
+    /// Panics if the fee would take the whole amount, leaving an empty P2ID note.
     /// Panics if the bank has not been initialized.
-    pub fn deposit(&mut self, depositor: AccountId, deposit_asset: Asset) {
-        // Ensure the bank is initialized before accepting deposits
+    /// Panics if the note being consumed was not sent by `depositor`.
+    /// Panics if a deadline is set and the current block is past it.
+    /// Panics if a minimum account age is configured and the depositor's first deposit
+    /// was fewer than that many blocks ago.
+    /// Panics if a withdraw cooldown is configured and the depositor's last withdrawal
+    /// was fewer than that many blocks ago.
+    /// Panics if the balance was locked by `deposit_locked` and the current block is
+    /// before its unlock block.
+    /// Panics if the bank is paused.
+    /// Panics if the depositor is blacklisted.
+    /// Panics if `note_type` is neither 1 nor 2.
+    /// Panics if a withdrawal limit is set and the amount would exceed what is left of it
+    /// in the current window.
+    /// Panics if the configured withdrawal fee exceeds 10,000 basis points.
+    pub fn withdraw(
+        &mut self,
+        depositor: AccountId,
+        withdraw_asset: Asset,
+        serial_num: Word,
+        tag: Felt,
+        note_type: Felt,
+        deadline: Felt,
+    ) -> Word {
+        // Ensure the bank is initialized and live before processing withdrawals
         self.require_initialized();
+        self.require_not_paused();
+
+        // Only the depositor may withdraw their own balance
+        self.require_note_sender(depositor);
+        self.require_not_blacklisted(depositor);
+
+        // Reject stale requests, e.g. one queued long ago with an outdated tag
+        if deadline.as_u64() != 0 {
+            assert!(
+                tx::get_block_number().as_u64() <= deadline.as_u64(),
+                "Withdraw request has expired"
+            );
+        }
+
+        // Deter rapid deposit-withdraw cycling by requiring a minimum account age
+        self.require_min_account_age(depositor);
+
+        // Time-locked savings stay in the bank until their unlock block
+        self.require_unlocked(depositor, withdraw_asset.inner[3], withdraw_asset.inner[2]);
+
+        // Limit how often the depositor can withdraw, regardless of amount
+        self.record_withdraw(depositor);
 
         // Extract the fungible amount from the asset
-        // Asset inner layout for fungible: [amount, 0, faucet_suffix, faucet_prefix]
-        let deposit_amount = deposit_asset.inner[0];
+        let withdraw_amount = withdraw_asset.inner[0];
 
-        // Validate deposit amount does not exceed maximum
-        assert!(
-            deposit_amount.as_u64() <= MAX_DEPOSIT_AMOUNT,
-            "Deposit amount exceeds maximum allowed"
-        );
+        // An empty P2ID note would only clutter the depositor's inbox
+        assert!(withdraw_amount.as_u64() > 0, "Withdrawal amount must be positive");
+
+        // Cap how much the depositor can withdraw per window
+        self.record_withdraw_amount(depositor, withdraw_asset.inner[3], withdraw_asset.inner[2], withdraw_amount);
 
         // Create key from depositor's AccountId and asset faucet ID
-        // This allows tracking balances per depositor per asset type
         let key = Word::from([
             depositor.prefix,
             depositor.suffix,
-            deposit_asset.inner[3], // asset prefix (faucet)
-            deposit_asset.inner[2], // asset suffix (faucet)
+            withdraw_asset.inner[3], // asset prefix (faucet)
+            withdraw_asset.inner[2], // asset suffix (faucet)
         ]);
 
-        // Update balance: current + deposit_amount
-        let current_balance: Felt = self.balances.get(&key);
-        let new_balance = current_balance + deposit_amount;
+        // Update balance: current - withdraw_amount.
+        // This check is critical: Felt arithmetic is modular, so subtracting
+        // more than the balance would silently wrap to a large positive number.
+        let current_balance: Balance = self.balances.get(&key);
+        let new_balance = current_balance
+            .checked_sub(withdraw_amount)
+            .expect("Withdrawal amount exceeds available balance");
         self.balances.set(key, new_balance);
+        self.subtract_from_total(withdraw_asset.inner[3], withdraw_asset.inner[2], withdraw_amount);
 
-        // Add asset to the bank's vault
-        native_account::add_asset(deposit_asset);
+        // Keep the fee in the vault and pay out the rest
+        let fee = self.retain_withdraw_fee(withdraw_asset.inner[3], withdraw_asset.inner[2], withdraw_amount);
+        let payout_amount = withdraw_amount - fee;
+        assert!(payout_amount.as_u64() > 0, "Withdrawal fee leaves nothing to pay out");
+        let payout_asset = Asset::new(Word::from([
+            payout_amount,
+            withdraw_asset.inner[1],
+            withdraw_asset.inner[2],
+            withdraw_asset.inner[3],
+        ]));
+
+        // Create a P2ID note to send the requested asset back to the depositor
+        let note_index = self.create_p2id_note(serial_num, &payout_asset, depositor, tag, note_type);
+
+        Word::from([new_balance.amount(), note_index, felt!(0), felt!(0)])
     }
 
-    /// Withdraw assets back to the depositor.
+    /// Withdraw a depositor's entire balance for one faucet.
     ///
-    /// Creates a P2ID note that sends the requested asset to the depositor's account.
+    /// Lets a depositor exit without knowing their exact balance: the full amount is
+    /// read from storage and withdrawn as if requested through `withdraw` with no
+    /// deadline, leaving the balance at zero.
     ///
     /// # Arguments
     /// * `depositor` - The AccountId of the user withdrawing
-    /// * `withdraw_asset` - The fungible asset to withdraw
+    /// * `faucet` - The faucet whose balance is withdrawn
     /// * `serial_num` - Unique serial number for the P2ID output note
-    /// * `tag` - The note tag for the P2ID output note (allows caller to specify routing)
+    /// * `tag` - The note tag for the P2ID output note
+    /// * `note_type` - Note type: 1 = Public (stored on-chain), 2 = Private (off-chain)
+    ///
+    /// # Panics
+    /// Panics if the depositor has no balance in the faucet.
+    /// Panics under the same conditions as `withdraw`.
+    pub fn withdraw_all(
+        &mut self,
+        depositor: AccountId,
+        faucet: AccountId,
+        serial_num: Word,
+        tag: Felt,
+        note_type: Felt,
+    ) {
+        let balance = self.get_balance_for_asset(depositor, faucet);
+
+        // An empty P2ID note would only clutter the depositor's inbox
+        assert!(balance.as_u64() > 0, "No balance to withdraw");
+
+        // Asset inner layout for fungible: [amount, 0, faucet_suffix, faucet_prefix]
+        let withdraw_asset = Asset::new(Word::from([balance, felt!(0), faucet.suffix, faucet.prefix]));
+        self.withdraw(depositor, withdraw_asset, serial_num, tag, note_type, felt!(0));
+    }
+
+    /// Withdraw a balance in one faucet, paid out in another faucet's asset.
+    ///
+    /// Lets the bank act as a simple exchanger: the depositor's balance in the withdrawn
+    /// faucet is debited by the full amount, and a P2ID note pays them
+    /// `amount * numerator / denominator` of the payout faucet's asset, using the rate
+    /// configured for that faucet pair when the account was created.
+    ///
+    /// # Arguments
+    /// * `depositor` - The AccountId of the user withdrawing
+    /// * `withdraw_asset` - The fungible asset whose balance is debited
+    /// * `payout_faucet` - The faucet whose asset is paid out
+    /// * `serial_num` - Unique serial number for the P2ID output note
+    /// * `tag` - The note tag for the P2ID output note
     /// * `note_type` - Note type: 1 = Public (stored on-chain), 2 = Private (off-chain)
     ///
     /// # Panics
-    /// Panics if the withdrawal amount exceeds the depositor's current balance.
     /// Panics if the bank has not been initialized.
-    pub fn withdraw(
+    /// Panics if the note being consumed was not sent by `depositor`.
+    /// Panics if no exchange rate is configured for the faucet pair.
+    /// Panics if the withdrawal amount exceeds the depositor's current balance.
+    /// Panics if the exchanged payout overflows or rounds down to zero.
+    /// Panics if the payout asset the vault holds beyond depositor balances and retained
+    /// fees in that faucet is less than the payout.
+    /// Panics under the same minimum account age, withdraw cooldown, withdrawal limit,
+    /// lock, pause and blacklist rules as `withdraw`.
+    pub fn withdraw_exchanged(
         &mut self,
         depositor: AccountId,
         withdraw_asset: Asset,
+        payout_faucet: AccountId,
         serial_num: Word,
         tag: Felt,
         note_type: Felt,
     ) {
-        // Ensure the bank is initialized before processing withdrawals
         self.require_initialized();
+        self.require_not_paused();
+        self.require_note_sender(depositor);
+        self.require_not_blacklisted(depositor);
+        self.require_min_account_age(depositor);
+        self.require_unlocked(depositor, withdraw_asset.inner[3], withdraw_asset.inner[2]);
+        self.record_withdraw(depositor);
+
+        // Rate key: [from_prefix, from_suffix, to_prefix, to_suffix]
+        // Rate value: [numerator, denominator, 0, 0]
+        let rate_key = Word::from([
+            withdraw_asset.inner[3], // asset prefix (faucet)
+            withdraw_asset.inner[2], // asset suffix (faucet)
+            payout_faucet.prefix,
+            payout_faucet.suffix,
+        ]);
+        let rate: Word = self.exchange_rates.get(&rate_key);
+        assert!(
+            rate[0].as_u64() != 0 && rate[1].as_u64() != 0,
+            "No exchange rate configured for this faucet pair"
+        );
 
-        // Extract the fungible amount from the asset
         let withdraw_amount = withdraw_asset.inner[0];
+        self.record_withdraw_amount(depositor, withdraw_asset.inner[3], withdraw_asset.inner[2], withdraw_amount);
 
-        // Create key from depositor's AccountId and asset faucet ID
         let key = Word::from([
             depositor.prefix,
             depositor.suffix,
             withdraw_asset.inner[3], // asset prefix (faucet)
             withdraw_asset.inner[2], // asset suffix (faucet)
         ]);
+        let current_balance: Balance = self.balances.get(&key);
+        let new_balance = current_balance
+            .checked_sub(withdraw_amount)
+            .expect("Withdrawal amount exceeds available balance");
 
-        // Get current balance and validate sufficient funds exist.
-        // This check is critical: Felt arithmetic is modular, so subtracting
-        // more than the balance would silently wrap to a large positive number.
-        let current_balance: Felt = self.balances.get(&key);
+        // The rate is applied in u64, where an unchecked product could overflow
+        let payout_amount = withdraw_amount
+            .as_u64()
+            .checked_mul(rate[0].as_u64())
+            .expect("Exchanged payout overflows")
+            / rate[1].as_u64();
+
+        // A payout rounded down to nothing would burn the balance for an empty P2ID note
+        assert!(payout_amount > 0, "Exchanged payout rounds down to zero");
+        let payout = Balance::from_u64(payout_amount).expect("Exchanged payout overflows");
+
+        // The payout asset in the vault is partly owed to its depositors and partly
+        // retained fees; only the reserve beyond both can be paid out
+        let reserve = active_account::get_balance(payout_faucet)
+            .as_u64()
+            .checked_sub(self.get_total(payout_faucet).as_u64())
+            .and_then(|reserve| reserve.checked_sub(self.get_fees(payout_faucet).as_u64()))
+            .unwrap_or(0);
         assert!(
-            current_balance.as_u64() >= withdraw_amount.as_u64(),
-            "Withdrawal amount exceeds available balance"
+            reserve >= payout_amount,
+            "Bank vault cannot cover the exchanged payout"
         );
 
-        // Update balance: current - withdraw_amount
-        let new_balance = current_balance - withdraw_amount;
         self.balances.set(key, new_balance);
+        self.subtract_from_total(withdraw_asset.inner[3], withdraw_asset.inner[2], withdraw_amount);
 
-        // Create a P2ID note to send the requested asset back to the depositor
-        self.create_p2id_note(serial_num, &withdraw_asset, depositor, tag, note_type);
+        // Asset inner layout for fungible: [amount, 0, faucet_suffix, faucet_prefix]
+        let payout_asset = Asset::new(Word::from([
+            payout.amount(),
+            felt!(0),
+            payout_faucet.suffix,
+            payout_faucet.prefix,
+        ]));
+        self.create_p2id_note(serial_num, &payout_asset, depositor, tag, note_type);
+    }
+
+    /// Pay the withdrawal fees retained in one faucet's asset out to the owner.
+    ///
+    /// Fees stay in the vault apart from depositor balances, so without a sweep they
+    /// could never leave it. The faucet's fees are reset to zero and a P2ID note
+    /// carrying them is sent to the owner.
+    ///
+    /// # Arguments
+    /// * `faucet` - The faucet whose retained fees are swept
+    /// * `serial_num` - Unique serial number for the P2ID output note
+    /// * `tag` - The note tag for the P2ID output note
+    /// * `note_type` - Note type: 1 = Public (stored on-chain), 2 = Private (off-chain)
+    ///
+    /// # Panics
+    /// Panics if the note being consumed was not sent by the owner.
+    /// Panics if no fees have been retained in the faucet.
+    /// Panics if `note_type` is neither 1 nor 2.
+    pub fn sweep_fees(&mut self, faucet: AccountId, serial_num: Word, tag: Felt, note_type: Felt) {
+        self.require_owner();
+
+        let key = Word::from([faucet.prefix, faucet.suffix, felt!(0), felt!(0)]);
+        let fees: Felt = self.fees.get(&key);
+
+        // An empty P2ID note would only clutter the owner's inbox
+        assert!(fees.as_u64() > 0, "No fees to sweep");
+
+        self.fees.set(key, felt!(0));
+
+        // Asset inner layout for fungible: [amount, 0, faucet_suffix, faucet_prefix]
+        let fee_asset = Asset::new(Word::from([fees, felt!(0), faucet.suffix, faucet.prefix]));
+        let owner = self.get_owner();
+        self.create_p2id_note(serial_num, &fee_asset, owner, tag, note_type);
+    }
+
+    /// Check that the note being consumed was sent by the bank's owner.
+    ///
+    /// The bank uses NoAuth, so any transaction script can call its methods; the
+    /// sender of a note is the only caller identity the bank can check. Privileged
+    /// methods are therefore only reachable through a note sent by the owner.
+    ///
+    /// # Panics
+    /// Panics if the bank has no owner or the active note's sender is not the owner.
+    fn require_owner(&self) {
+        let owner: Word = self.owner.read();
+        let sender = active_note::get_sender();
+        assert!(
+            (owner[0].as_u64() != 0 || owner[1].as_u64() != 0)
+                && sender.prefix == owner[0]
+                && sender.suffix == owner[1],
+            "Only the owner can call this method"
+        );
+    }
+
+    /// Check that the note being consumed was sent by the given depositor.
+    ///
+    /// Public methods can be called by any note script, so methods that debit a balance
+    /// must not trust the `depositor` argument alone.
+    ///
+    /// # Panics
+    /// Panics if the active note's sender is not `depositor`.
+    fn require_note_sender(&self, depositor: AccountId) {
+        let sender = active_note::get_sender();
+        assert!(
+            sender.prefix == depositor.prefix && sender.suffix == depositor.suffix,
+            "Only the depositor can debit their balance"
+        );
+    }
+
+    /// Check that the note being consumed was sent by the given depositor or by the owner.
+    ///
+    /// # Panics
+    /// Panics if the active note's sender is neither `depositor` nor the owner, or if
+    /// it is not `depositor` and the bank has no owner.
+    fn require_note_sender_or_owner(&self, depositor: AccountId) {
+        let owner: Word = self.owner.read();
+        let sender = active_note::get_sender();
+        let is_depositor = sender.prefix == depositor.prefix && sender.suffix == depositor.suffix;
+        let is_owner = (owner[0].as_u64() != 0 || owner[1].as_u64() != 0)
+            && sender.prefix == owner[0]
+            && sender.suffix == owner[1];
+        assert!(
+            is_depositor || is_owner,
+            "Only the depositor or the owner can move their balance"
+        );
+    }
+
+    /// Check that a depositor has reached the configured minimum account age.
+    ///
+    /// # Panics
+    /// Panics if a minimum account age is configured and the depositor's first deposit
+    /// was fewer than that many blocks ago.
+    fn require_min_account_age(&self, depositor: AccountId) {
+        let min_account_age: Word = self.min_account_age.read();
+        if min_account_age[0].as_u64() != 0 {
+            let age_key = Word::from([depositor.prefix, depositor.suffix, felt!(0), felt!(0)]);
+            let first_deposit_block: Felt = self.first_deposit_blocks.get(&age_key);
+            assert!(
+                tx::get_block_number().as_u64()
+                    >= first_deposit_block.as_u64() + min_account_age[0].as_u64(),
+                "Depositor account is too new to withdraw"
+            );
+        }
+    }
+
+    /// Check that a depositor's balance for one faucet is not time-locked.
+    ///
+    /// # Panics
+    /// Panics if the balance was locked by `deposit_locked` and the current block is
+    /// before its unlock block.
+    fn require_unlocked(&self, depositor: AccountId, faucet_prefix: Felt, faucet_suffix: Felt) {
+        let key = Word::from([depositor.prefix, depositor.suffix, faucet_prefix, faucet_suffix]);
+        let unlock_block: Felt = self.locks.get(&key);
+        assert!(
+            tx::get_block_number().as_u64() >= unlock_block.as_u64(),
+            "Balance is locked until its unlock block"
+        );
+    }
+
+    /// Record a withdrawal by the depositor in the current block.
+    ///
+    /// # Panics
+    /// Panics if a withdraw cooldown is configured and the depositor's last withdrawal
+    /// was fewer than that many blocks ago.
+    fn record_withdraw(&mut self, depositor: AccountId) {
+        let withdraw_key = Word::from([depositor.prefix, depositor.suffix, felt!(0), felt!(0)]);
+        let block_number = tx::get_block_number();
+
+        let withdraw_cooldown: Word = self.withdraw_cooldown.read();
+        let last_withdraw_block: Felt = self.last_withdraw_blocks.get(&withdraw_key);
+        if withdraw_cooldown[0].as_u64() != 0 && last_withdraw_block.as_u64() != 0 {
+            assert!(
+                block_number.as_u64() >= last_withdraw_block.as_u64() + withdraw_cooldown[0].as_u64(),
+                "Withdraw cooldown has not elapsed"
+            );
+        }
+
+        self.last_withdraw_blocks.set(withdraw_key, block_number);
+    }
+
+    /// Compute the fee on a withdrawal and credit it to the faucet's retained fees.
+    ///
+    /// # Returns
+    /// The fee, `amount * fee_bps / 10_000` rounded down
+    ///
+    /// # Panics
+    /// Panics if the configured fee exceeds 10,000 basis points.
+    fn retain_withdraw_fee(&mut self, faucet_prefix: Felt, faucet_suffix: Felt, amount: Felt) -> Felt {
+        let fee_bps: Word = self.fee_bps.read();
+        assert!(
+            fee_bps[0].as_u64() <= FEE_BPS_DENOMINATOR,
+            "Withdrawal fee exceeds 10,000 basis points"
+        );
+
+        let fee = Felt::from_u64_unchecked(amount.as_u64() * fee_bps[0].as_u64() / FEE_BPS_DENOMINATOR);
+
+        let key = Word::from([faucet_prefix, faucet_suffix, felt!(0), felt!(0)]);
+        let fees: Felt = self.fees.get(&key);
+        self.fees.set(key, fees + fee);
+
+        fee
+    }
+
+    /// Count a withdrawal against the depositor's limit for the faucet.
+    ///
+    /// Opens a fresh window if the current one started `WITHDRAW_LIMIT_WINDOW` or more
+    /// blocks ago. Does nothing if no limit is set.
+    ///
+    /// # Panics
+    /// Panics if the window's withdrawals would exceed the limit.
+    fn record_withdraw_amount(
+        &mut self,
+        depositor: AccountId,
+        faucet_prefix: Felt,
+        faucet_suffix: Felt,
+        amount: Felt,
+    ) {
+        let key = Word::from([depositor.prefix, depositor.suffix, faucet_prefix, faucet_suffix]);
+        let withdraw_limit: Word = self.withdraw_limits.get(&key);
+        let limit = withdraw_limit[0].as_u64();
+        if limit == 0 {
+            return;
+        }
+
+        let block_number = tx::get_block_number();
+        let (window_start, withdrawn) =
+            if block_number.as_u64() >= withdraw_limit[1].as_u64() + WITHDRAW_LIMIT_WINDOW {
+                (block_number, 0)
+            } else {
+                (withdraw_limit[1], withdraw_limit[2].as_u64())
+            };
+
+        let withdrawn = withdrawn + amount.as_u64();
+        assert!(withdrawn <= limit, "Withdrawal exceeds the depositor's withdrawal limit");
+
+        self.withdraw_limits.set(
+            key,
+            Word::from([
+                withdraw_limit[0],
+                window_start,
+                Felt::from_u64_unchecked(withdrawn),
+                felt!(0),
+            ]),
+        );
+    }
+
+    /// The per-deposit cap, falling back to `MAX_DEPOSIT_AMOUNT` if unset.
+    fn max_deposit_amount(&self) -> u64 {
+        let max_deposit: Word = self.max_deposit.read();
+        if max_deposit[0].as_u64() == 0 {
+            MAX_DEPOSIT_AMOUNT
+        } else {
+            max_deposit[0].as_u64()
+        }
+    }
+
+    /// The per-depositor balance ceiling, falling back to `MAX_TOTAL_BALANCE` if unset.
+    fn max_total_balance(&self) -> u64 {
+        let max_total_balance: Word = self.max_total_balance.read();
+        if max_total_balance[0].as_u64() == 0 {
+            MAX_TOTAL_BALANCE
+        } else {
+            max_total_balance[0].as_u64()
+        }
+    }
+
+    /// The per-block deposit limit, falling back to `MAX_DEPOSIT_PER_BLOCK` if unset.
+    fn block_deposit_limit(&self) -> u64 {
+        let max_deposit_per_block: Word = self.max_deposit_per_block.read();
+        if max_deposit_per_block[0].as_u64() == 0 {
+            MAX_DEPOSIT_PER_BLOCK
+        } else {
+            max_deposit_per_block[0].as_u64()
+        }
+    }
+
+    /// The amount already deposited in `block_number`; zero once the block has advanced.
+    fn block_deposits_used(&self, block_number: u64) -> u64 {
+        let block_deposits: Word = self.block_deposits.read();
+        if block_deposits[1].as_u64() == block_number {
+            block_deposits[0].as_u64()
+        } else {
+            0
+        }
+    }
+
+    /// Add a deposit to the current block's total.
+    ///
+    /// # Panics
+    /// Panics if the block's total would exceed the per-block deposit limit.
+    fn record_block_deposit(&mut self, deposit_amount: Felt) {
+        let block_number = tx::get_block_number();
+        let used = self.block_deposits_used(block_number.as_u64()) + deposit_amount.as_u64();
+        assert!(
+            used <= self.block_deposit_limit(),
+            "Deposits for this block exceed the bank's per-block limit"
+        );
+
+        self.block_deposits.write(Word::from([
+            Felt::from_u64_unchecked(used),
+            block_number,
+            felt!(0),
+            felt!(0),
+        ]));
+    }
+
+    /// Convert a caller-supplied note type to a `NoteType`.
+    ///
+    /// Note tags carry no note type, so only the value itself can be checked.
+    ///
+    /// # Panics
+    /// Panics if `note_type` is neither 1 (Public) nor 2 (Private).
+    fn note_type_from_felt(note_type: Felt) -> NoteType {
+        assert!(
+            note_type.as_u64() == 1 || note_type.as_u64() == 2,
+            "Invalid note type"
+        );
+        NoteType::from(note_type)
     }
 
     /// Create a P2ID (Pay-to-ID) note to send assets to a recipient.
@@ -217,6 +2051,12 @@ impl Bank {
     /// * `recipient_id` - The AccountId that can consume this note
     /// * `tag` - The note tag (passed by caller to allow proper P2ID routing)
     /// * `note_type` - Note type as Felt: 1 = Public, 2 = Private
+    ///
+    /// # Returns
+    /// The index of the created note among the transaction's output notes
+    ///
+    /// # Panics
+    /// Panics if `note_type` is neither 1 nor 2.
     fn create_p2id_note(
         &mut self,
         serial_num: Word,
@@ -224,7 +2064,7 @@ impl Bank {
         recipient_id: AccountId,
         tag: Felt,
         note_type: Felt,
-    ) {
+    ) -> Felt {
         // Convert the passed tag Felt to a Tag
         // The caller is responsible for computing the proper P2ID tag
         // (typically with_account_target for the recipient)
@@ -232,10 +2072,10 @@ impl Bank {
 
         // Convert note_type Felt to NoteType
         // 1 = Public (stored on-chain), 2 = Private (off-chain)
-        let note_type = NoteType::from(note_type);
+        let note_type = Self::note_type_from_felt(note_type);
 
         // Get the P2ID note script root digest
-        let script_root = Self::p2id_note_root();
+        let script_root = self.payout_note_root();
 
         // Compute the recipient hash from:
         // - serial_num: unique identifier for this note instance
@@ -259,7 +2099,11 @@ impl Bank {
         // Remove the asset from the bank's vault
         native_account::remove_asset(asset.clone());
 
+        let note_index = note_idx.inner;
+
         // Add the asset to the output note
         output_note::add_asset(asset.clone(), note_idx);
+
+        note_index
     }
 }
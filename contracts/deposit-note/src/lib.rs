@@ -10,18 +10,36 @@ use crate::bindings::miden::bank_account::bank_account;
 /// Deposit Note Script
 ///
 /// When consumed by the Bank account, this note transfers all its assets
-/// to the bank and credits the depositor (note sender) with the deposited amount.
+/// to the bank and credits the depositor (note sender, or a named beneficiary)
+/// with the deposited amount.
 ///
 /// # Flow
 /// 1. Note is created by a user with fungible assets attached
 /// 2. Bank account consumes this note
-/// 3. Note script reads the sender (depositor) and assets
+/// 3. Note script reads the depositor (the beneficiary if named, else the sender) and assets
 ///
-/// 4. For each asset, calls `bank_account::deposit(depositor,y asset)`
-/// 5. Bank receives the asset and updates the depositor's balance
+/// 4. Calls `bank_account::deposit_batch(depositor)` once for all assets
+///    (or, with refund or receipt parameters, one call per asset)
+/// 5. Bank receives the assets and updates the depositor's balances
 ///
 /// # Note Inputs
 /// None required - the depositor is automatically the note's sender.
+///
+/// Optionally, refund parameters (6 Felts) can be supplied so a bank running in
+/// refund mode returns rejected assets instead of failing the transaction:
+/// [0-3]: serial_num for the P2ID refund note
+/// [4]: tag (P2ID note tag for routing the refund)
+/// [5]: note_type (1 = Public, 2 = Private)
+///
+/// A seventh input set to 1 uses the same parameters for a deposit receipt note
+/// instead, sent back to the depositor on every deposit:
+/// [6]: receipt flag (1 = send a receipt instead of refunding)
+///
+/// To deposit on behalf of someone else (e.g. a gift or payroll), a beneficiary
+/// AccountId can be named in two extra leading inputs, either alone (2 Felts) or
+/// followed by the parameters above (8 or 9 Felts). The beneficiary is credited and
+/// receives any refund or receipt; an all-zero beneficiary credits the sender.
+/// [0-1]: beneficiary AccountId (prefix, suffix)
 #[note]
 struct DepositNote;
 
@@ -29,15 +47,33 @@ struct DepositNote;
 impl DepositNote {
     #[note_script]
     fn run(self, _arg: Word) {
-        // The depositor is whoever created/sent this note
-        let depositor = active_note::get_sender();
+        // The beneficiary and refund parameters are optional
+        let mut inputs = active_note::get_inputs();
 
-        // Get all assets attached to this note
-        let assets = active_note::get_assets();
+        // The depositor is the named beneficiary, or whoever created/sent this note
+        let mut depositor = active_note::get_sender();
+        if inputs.len() == 2 || inputs.len() >= 8 {
+            let beneficiary = AccountId::new(inputs[0], inputs[1]);
+            if beneficiary.prefix != felt!(0) || beneficiary.suffix != felt!(0) {
+                depositor = beneficiary;
+            }
+            inputs.drain(..2);
+        }
+
+        // Without refund or receipt parameters, the bank deposits every asset in one call
+        if inputs.is_empty() {
+            bank_account::deposit_batch(depositor);
+            return;
+        }
 
-        // Deposit each asset into the bank
-        for asset in assets {
-            bank_account::deposit(depositor, asset);
+        // Otherwise deposit each asset into the bank with its own refund or receipt
+        let serial_num = Word::from([inputs[0], inputs[1], inputs[2], inputs[3]]);
+        for asset in active_note::get_assets() {
+            if inputs.len() > 6 && inputs[6] == felt!(1) {
+                bank_account::deposit_with_receipt(depositor, asset, serial_num, inputs[4], inputs[5]);
+            } else {
+                bank_account::deposit_with_refund(depositor, asset, serial_num, inputs[4], inputs[5]);
+            }
         }
     }
 }
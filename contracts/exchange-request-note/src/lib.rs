@@ -0,0 +1,62 @@
+// Do not link against libstd (i.e. anything defined in `std::`)
+#![no_std]
+#![feature(alloc_error_handler)]
+
+use miden::*;
+
+// Import the bank account's generated bindings
+use crate::bindings::miden::bank_account::bank_account;
+
+/// Exchange Request Note Script
+///
+/// When consumed by the Bank account, this note withdraws part of the sender's
+/// balance in one faucet and has the bank pay it out in another faucet's asset at
+/// the bank's configured exchange rate.
+///
+/// # Flow
+/// 1. Note is created by a depositor specifying the withdrawal and payout faucet
+/// 2. Bank account consumes this note
+/// 3. Note script reads the sender (depositor) and inputs
+/// 4. Calls `bank_account::withdraw_exchanged(depositor, asset, payout_faucet, serial_num, tag, note_type)`
+/// 5. Bank debits the depositor's balance in the withdrawn faucet
+/// 6. Bank creates a P2ID note paying the exchanged amount in the payout faucet
+///
+/// # Note Inputs (12 Felts)
+/// [0-3]: withdraw asset (amount, 0, faucet_suffix, faucet_prefix)
+/// [4-5]: payout faucet AccountId (prefix, suffix)
+/// [6-9]: serial_num (random/unique per note)
+/// [10]: tag (P2ID note tag for routing)
+/// [11]: note_type (1 = Public, 2 = Private)
+#[note]
+struct ExchangeRequestNote;
+
+#[note]
+impl ExchangeRequestNote {
+    #[note_script]
+    fn run(self, _arg: Word) {
+        // The depositor is whoever created/sent this note
+        let depositor = active_note::get_sender();
+
+        let inputs = active_note::get_inputs();
+
+        // Asset: [amount, 0, faucet_suffix, faucet_prefix]
+        let withdraw_asset = Asset::new(Word::from([inputs[0], inputs[1], inputs[2], inputs[3]]));
+
+        let payout_faucet = AccountId::new(inputs[4], inputs[5]);
+
+        // Serial number: full 4 Felts (random/unique per note)
+        let serial_num = Word::from([inputs[6], inputs[7], inputs[8], inputs[9]]);
+
+        let tag = inputs[10];
+        let note_type = inputs[11];
+
+        bank_account::withdraw_exchanged(
+            depositor,
+            withdraw_asset,
+            payout_faucet,
+            serial_num,
+            tag,
+            note_type,
+        );
+    }
+}
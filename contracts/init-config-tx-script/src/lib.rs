@@ -0,0 +1,33 @@
+// Do not link against libstd (i.e. anything defined in `std::`)
+#![no_std]
+#![feature(alloc_error_handler)]
+
+use miden::*;
+
+// Import the Account binding which wraps the bank-account component methods
+use crate::bindings::Account;
+
+/// Initialize With Config Transaction Script
+///
+/// This transaction script configures and initializes the bank account in one
+/// atomic transaction, setting its per-deposit cap, withdrawal fee and owner.
+///
+/// # Flow
+/// 1. Transaction is created with this script attached
+/// 2. Script executes in the context of the bank account
+/// 3. Calls `account.initialize_with_config(max_deposit, fee_bps, owner)`
+/// 4. Bank account is now "deployed" and visible on chain
+///
+/// # Arguments
+/// * `arg` - [max_deposit, fee_bps, owner_prefix, owner_suffix].
+///   The per-deposit cap must be positive and the fee at most 10,000 basis points;
+///   an all-zero owner initializes a bank without an owner.
+/// * `account` - Mutable reference to the Account (bank component)
+#[tx_script]
+fn run(arg: Word, account: &mut Account) {
+    // The script argument arrives with its elements in reverse order relative to the
+    // Word supplied by the client, so restore the client-side layout first.
+    let arg = arg.reverse();
+
+    account.initialize_with_config(arg[0], arg[1], AccountId::new(arg[2], arg[3]));
+}
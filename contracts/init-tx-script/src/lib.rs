@@ -15,13 +15,24 @@ use crate::bindings::Account;
 /// # Flow
 /// 1. Transaction is created with this script attached
 /// 2. Script executes in the context of the bank account
-/// 3. Calls `account.initialize()` to enable deposits
-/// 4. Bank account is now "deployed" and visible on chain
+/// 3. If a default faucet is passed in the argument, calls `account.set_default_faucet()`
+/// 4. Calls `account.initialize(owner)` to record the owner and enable deposits
+/// 5. Bank account is now "deployed" and visible on chain
 ///
 /// # Arguments
-/// * `_arg` - Transaction script argument (unused in this script)
+/// * `arg` - [faucet_prefix, faucet_suffix, owner_prefix, owner_suffix].
+///   An all-zero faucet initializes a bank that accepts any faucet; an all-zero
+///   owner initializes a bank without an owner.
 /// * `account` - Mutable reference to the Account (bank component)
 #[tx_script]
-fn run(_arg: Word, account: &mut Account) {
-    account.initialize();
+fn run(arg: Word, account: &mut Account) {
+    // The script argument arrives with its elements in reverse order relative to the
+    // Word supplied by the client, so restore the client-side layout first.
+    let arg = arg.reverse();
+
+    if arg[0].as_u64() != 0 || arg[1].as_u64() != 0 {
+        account.set_default_faucet(AccountId::new(arg[0], arg[1]));
+    }
+
+    account.initialize(AccountId::new(arg[2], arg[3]));
 }
@@ -0,0 +1,41 @@
+// Do not link against libstd (i.e. anything defined in `std::`)
+#![no_std]
+#![feature(alloc_error_handler)]
+
+use miden::*;
+
+// Import the bank account's generated bindings
+use crate::bindings::miden::bank_account::bank_account;
+
+/// Locked Deposit Note Script
+///
+/// When consumed by the Bank account, this note deposits all its assets for the
+/// sender as time-locked savings: the bank refuses to release the balance before
+/// the unlock block named in the note's inputs.
+///
+/// # Flow
+/// 1. Note is created by a user with fungible assets attached
+/// 2. Bank account consumes this note
+/// 3. Note script reads the sender (depositor), the unlock block and the assets
+/// 4. Calls `bank_account::deposit_locked(depositor, asset, unlock_block)` per asset
+/// 5. Bank credits the depositor and locks the balance until the unlock block
+///
+/// # Note Inputs (1 Felt)
+/// [0]: unlock block (first block number at which the balance can be withdrawn)
+#[note]
+struct LockedDepositNote;
+
+#[note]
+impl LockedDepositNote {
+    #[note_script]
+    fn run(self, _arg: Word) {
+        let depositor = active_note::get_sender();
+
+        let inputs = active_note::get_inputs();
+        let unlock_block = inputs[0];
+
+        for asset in active_note::get_assets() {
+            bank_account::deposit_locked(depositor, asset, unlock_block);
+        }
+    }
+}
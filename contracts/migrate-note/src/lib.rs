@@ -0,0 +1,42 @@
+// Do not link against libstd (i.e. anything defined in `std::`)
+#![no_std]
+#![feature(alloc_error_handler)]
+
+use miden::*;
+
+// Import the bank account's generated bindings
+use crate::bindings::miden::bank_account::bank_account;
+
+/// Migrate Note Script
+///
+/// When consumed by the Bank account, this note moves the sender's entire balance
+/// for one faucet to a new AccountId, e.g. after the depositor rotated keys.
+///
+/// # Flow
+/// 1. Note is created by the depositor from their current (old) account
+/// 2. Bank account consumes this note
+/// 3. Note script reads the sender (old depositor) and inputs
+/// 4. Calls `bank_account::migrate_depositor(old_id, new_id, faucet)`
+/// 5. Bank moves the balance to the new key; no assets leave the vault
+///
+/// # Note Inputs (4 Felts)
+/// [0-1]: new depositor AccountId (prefix, suffix)
+/// [2-3]: faucet AccountId (prefix, suffix)
+#[note]
+struct MigrateNote;
+
+#[note]
+impl MigrateNote {
+    #[note_script]
+    fn run(self, _arg: Word) {
+        // Only the current holder can migrate their own balance
+        let old_id = active_note::get_sender();
+
+        let inputs = active_note::get_inputs();
+
+        let new_id = AccountId::new(inputs[0], inputs[1]);
+        let faucet = AccountId::new(inputs[2], inputs[3]);
+
+        bank_account::migrate_depositor(old_id, new_id, faucet);
+    }
+}
@@ -0,0 +1,44 @@
+// Do not link against libstd (i.e. anything defined in `std::`)
+#![no_std]
+#![feature(alloc_error_handler)]
+
+use miden::*;
+
+// Import the bank account's generated bindings
+use crate::bindings::miden::bank_account::bank_account;
+
+/// Transfer Note Script
+///
+/// When consumed by the Bank account, this note moves part of the sender's balance
+/// for one faucet to another depositor without the assets leaving the bank.
+///
+/// # Flow
+/// 1. Note is created by the depositor sending the balance
+/// 2. Bank account consumes this note
+/// 3. Note script reads the sender and inputs
+/// 4. Calls `bank_account::transfer(sender, recipient, faucet, amount)`
+/// 5. Bank debits the sender's balance and credits the recipient's; no assets leave the vault
+///
+/// # Note Inputs (5 Felts)
+/// [0-1]: recipient AccountId (prefix, suffix)
+/// [2-3]: faucet AccountId (prefix, suffix)
+/// [4]: amount to transfer
+#[note]
+struct TransferNote;
+
+#[note]
+impl TransferNote {
+    #[note_script]
+    fn run(self, _arg: Word) {
+        // Only the holder of a balance can transfer it
+        let sender = active_note::get_sender();
+
+        let inputs = active_note::get_inputs();
+
+        let recipient = AccountId::new(inputs[0], inputs[1]);
+        let faucet = AccountId::new(inputs[2], inputs[3]);
+        let amount = inputs[4];
+
+        bank_account::transfer(sender, recipient, faucet, amount);
+    }
+}
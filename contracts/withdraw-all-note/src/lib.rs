@@ -0,0 +1,47 @@
+// Do not link against libstd (i.e. anything defined in `std::`)
+#![no_std]
+#![feature(alloc_error_handler)]
+
+use miden::*;
+
+// Import the bank account's generated bindings
+use crate::bindings::miden::bank_account::bank_account;
+
+/// Withdraw All Note Script
+///
+/// When consumed by the Bank account, this note withdraws the sender's entire
+/// balance for one faucet, so the depositor does not need to know the exact amount.
+///
+/// # Flow
+/// 1. Note is created by a depositor naming the faucet to exit from
+/// 2. Bank account consumes this note
+/// 3. Note script reads the sender (depositor) and inputs
+/// 4. Calls `bank_account::withdraw_all(depositor, faucet, serial_num, tag, note_type)`
+/// 5. Bank zeroes the depositor's balance
+/// 6. Bank creates a P2ID note carrying the full balance back to the depositor
+///
+/// # Note Inputs (8 Felts)
+/// [0-1]: faucet AccountId (prefix, suffix)
+/// [2-5]: serial_num (random/unique per note)
+/// [6]: tag (P2ID note tag for routing)
+/// [7]: note_type (1 = Public, 2 = Private)
+#[note]
+struct WithdrawAllNote;
+
+#[note]
+impl WithdrawAllNote {
+    #[note_script]
+    fn run(self, _arg: Word) {
+        // The depositor is whoever created/sent this note
+        let depositor = active_note::get_sender();
+
+        let inputs = active_note::get_inputs();
+
+        let faucet = AccountId::new(inputs[0], inputs[1]);
+        let serial_num = Word::from([inputs[2], inputs[3], inputs[4], inputs[5]]);
+        let tag = inputs[6];
+        let note_type = inputs[7];
+
+        bank_account::withdraw_all(depositor, faucet, serial_num, tag, note_type);
+    }
+}
@@ -16,15 +16,18 @@ use crate::bindings::miden::bank_account::bank_account;
 /// 1. Note is created by a depositor specifying the withdrawal details
 /// 2. Bank account consumes this note
 /// 3. Note script reads the sender (depositor) and inputs
-/// 4. Calls `bank_account::withdraw(depositor, asset, serial_num, tag, note_type)`
+/// 4. Calls `bank_account::withdraw(depositor, asset, serial_num, tag, note_type, deadline)`
 /// 5. Bank updates the depositor's balance
 /// 6. Bank creates a P2ID note with the specified parameters to send assets back
 ///
-/// # Note Inputs (10 Felts)
+/// # Note Inputs (10 or 11 Felts)
 /// [0-3]: withdraw asset (amount, 0, faucet_suffix, faucet_prefix)
 /// [4-7]: serial_num (random/unique per note)
 /// [8]: tag (P2ID note tag for routing)
-/// [9]: note_type (1 = Public, 2 = Private)
+/// [9]: note_type of the P2ID payout (0 = default, 1 = Public, 2 = Private);
+///      0 falls back to Private so the payout stays off-chain unless the
+///      depositor opts into Public
+/// [10]: optional deadline block number; the request fails after this block
 #[note]
 struct WithdrawRequestNote;
 
@@ -35,8 +38,9 @@ impl WithdrawRequestNote {
         // The depositor is whoever created/sent this note
         let depositor = active_note::get_sender();
 
-        // Get the inputs
+        // Get the inputs; everything up to the note type is required
         let inputs = active_note::get_inputs();
+        assert!(inputs.len() >= 10, "Withdraw request note requires at least 10 inputs");
 
         // Asset: [amount, 0, faucet_suffix, faucet_prefix]
         let withdraw_asset = Asset::new(Word::from([inputs[0], inputs[1], inputs[2], inputs[3]]));
@@ -47,10 +51,14 @@ impl WithdrawRequestNote {
         // Tag: single Felt for P2ID note routing
         let tag = inputs[8];
 
-        // Note type: 1 = Public, 2 = Private
-        let note_type = inputs[9];
+        // Note type: 1 = Public, 2 = Private; 0 defaults to Private rather than
+        // an undefined NoteType
+        let note_type = if inputs[9].as_u64() == 0 { felt!(2) } else { inputs[9] };
+
+        // Deadline: optional, 0 means the request never expires
+        let deadline = if inputs.len() > 10 { inputs[10] } else { felt!(0) };
 
         // Call the bank account to withdraw the assets
-        bank_account::withdraw(depositor, withdraw_asset, serial_num, tag, note_type);
+        bank_account::withdraw(depositor, withdraw_asset, serial_num, tag, note_type, deadline);
     }
 }
@@ -0,0 +1,105 @@
+//! Bank Balance Query Binary
+//!
+//! This binary reads a depositor's balance from an existing bank account on the Miden
+//! network. It only reads the bank's storage and executes no transaction.
+//!
+//! # Usage
+//! ```bash
+//! cargo run --bin balance -- <BANK_ACCOUNT_ID> <DEPOSITOR_ID> [FAUCET_ID]
+//! ```
+//!
+//! # Arguments
+//! * `BANK_ACCOUNT_ID` - The hex ID of the bank account to read
+//! * `DEPOSITOR_ID` - The hex ID of the depositor whose balance to read
+//! * `FAUCET_ID` - Optional hex ID of a faucet; without it, the balance of every faucet
+//!   the depositor has deposited is printed
+//!
+//! # Environment
+//! `MIDEN_RPC_ENDPOINT`, `MIDEN_STORE_PATH` and `MIDEN_KEYSTORE_PATH` choose the network
+//! and the client's state; see `ClientSetupConfig::from_env`.
+//!
+//! # Example
+//! ```bash
+//! cargo run --bin balance -- 0x1234567890abcdef... 0xabcdef...
+//! ```
+
+use integration::helpers::{
+    get_balance_for_asset, get_depositor_faucets, setup_client_with, ClientSetup,
+    ClientSetupConfig,
+};
+
+use anyhow::{bail, Context, Result};
+use miden_client::account::{Account, AccountId};
+use std::env;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    println!("=== Miden Bank Balance ===\n");
+
+    // Parse command line arguments
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 3 {
+        bail!(
+            "Usage: {} <BANK_ACCOUNT_ID> <DEPOSITOR_ID> [FAUCET_ID]\n\n\
+             Example: {} 0x1234567890abcdef... 0xabcdef...",
+            args[0],
+            args[0]
+        );
+    }
+
+    let parse_account_id = |label: &str, hex: &String| {
+        AccountId::from_hex(hex).context(format!("Invalid {}: {}", label, hex))
+    };
+    let bank_account_id = parse_account_id("bank account ID", &args[1])?;
+    let depositor_id = parse_account_id("depositor ID", &args[2])?;
+    let faucet_id = args
+        .get(3)
+        .map(|faucet_id_hex| parse_account_id("faucet ID", faucet_id_hex))
+        .transpose()?;
+
+    // Initialize client
+    let ClientSetup { mut client, .. } = setup_client_with(ClientSetupConfig::from_env()?).await?;
+
+    let sync_summary = client.sync_state().await?;
+    println!("Connected to network. Latest block: {}", sync_summary.block_num);
+
+    // Fetch the bank account's current state
+    let bank_account_record = client
+        .get_account(bank_account_id)
+        .await
+        .context("Failed to fetch bank account")?
+        .with_context(|| {
+            format!(
+                "Bank account {} not found in client.\n\
+                 Make sure you've run 'cargo run --bin initialize' first.",
+                bank_account_id.to_hex()
+            )
+        })?;
+    let bank_account =
+        Account::try_from(bank_account_record).context("Bank account state is not fully known")?;
+
+    println!("\nBank Account: {}", bank_account_id.to_hex());
+    println!("Depositor: {}", depositor_id.to_hex());
+
+    // One faucet if given, otherwise every faucet in the depositor's index
+    let faucets = match faucet_id {
+        Some(faucet_id) => vec![faucet_id],
+        None => get_depositor_faucets(&bank_account, depositor_id)?,
+    };
+    if faucets.is_empty() {
+        println!("\nNo balance: the depositor has never deposited into this bank.");
+        return Ok(());
+    }
+
+    println!();
+    for faucet in faucets {
+        let balance = get_balance_for_asset(&bank_account, depositor_id, faucet)?;
+        if balance == 0 {
+            println!("Faucet {}: no balance", faucet.to_hex());
+        } else {
+            println!("Faucet {}: {}", faucet.to_hex(), balance);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+//! Build Contract Binary
+//!
+//! Compiles a single contract with cargo-miden. cargo-miden exits the process when
+//! the Rust compiler fails, so tests that need to report a failed build run this
+//! binary in a child process and capture its output instead of building in-process.
+//!
+//! # Usage
+//! ```bash
+//! cargo run --bin build-contract -- ../contracts/withdraw-request-note
+//! ```
+//!
+//! # Exit Status
+//! Exits with zero when the contract compiles, and with a non-zero status (after the
+//! compiler output) when it does not.
+
+use anyhow::{Context, Result};
+use integration::helpers::build_project_in_dir;
+use std::path::PathBuf;
+
+fn main() -> Result<()> {
+    let dir = std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .context("Usage: build-contract <contract directory>")?;
+
+    build_project_in_dir(&dir, true)
+        .with_context(|| format!("Failed to build contract in {}", dir.display()))?;
+
+    println!("  ✓ Built {}", dir.display());
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+//! Check P2ID Script Root Binary
+//!
+//! The bank account contract hard-codes the MAST root of the standard P2ID note script
+//! in `Bank::p2id_note_root()`. This binary recomputes the root from the installed
+//! miden-standards version and compares it against the constant in the contract source,
+//! so maintainers can catch a stale digest before a release.
+//!
+//! # Usage
+//! ```bash
+//! cargo run --bin check-p2id-root
+//! ```
+//!
+//! # Exit Status
+//! Exits with zero when the roots match, and with an error describing the
+//! replacement values when they do not.
+
+use anyhow::{bail, Context, Result};
+use miden_client::{note::WellKnownNote, Felt, Word};
+use std::path::Path;
+
+/// Path to the bank account contract source, relative to the integration crate
+const BANK_SOURCE_PATH: &str = "../contracts/bank-account/src/lib.rs";
+
+/// Extracts the four Felts of the P2ID root from the body of `p2id_note_root()`
+fn parse_contract_p2id_root(source: &str) -> Result<Word> {
+    let body_start = source
+        .find("fn p2id_note_root()")
+        .context("`p2id_note_root()` not found in bank account source")?;
+    let body = &source[body_start..];
+    let body_end = body
+        .find("\n    }")
+        .context("End of `p2id_note_root()` not found")?;
+
+    let felts = body[..body_end]
+        .split("Felt::from_u64_unchecked(")
+        .skip(1)
+        .map(|chunk| {
+            let digits: String = chunk.chars().take_while(|c| c.is_ascii_digit()).collect();
+            digits
+                .parse::<u64>()
+                .map(Felt::new)
+                .with_context(|| format!("Invalid Felt literal: {}", digits))
+        })
+        .collect::<Result<Vec<Felt>>>()?;
+
+    let felts: [Felt; 4] = felts
+        .try_into()
+        .map_err(|felts: Vec<Felt>| anyhow::anyhow!("Expected 4 Felts, found {}", felts.len()))?;
+
+    Ok(Word::from(felts))
+}
+
+fn main() -> Result<()> {
+    println!("=== P2ID Script Root Check ===\n");
+
+    let source = std::fs::read_to_string(Path::new(BANK_SOURCE_PATH))
+        .context(format!("Failed to read {}", BANK_SOURCE_PATH))?;
+    let contract_root = parse_contract_p2id_root(&source)?;
+    let expected_root = WellKnownNote::P2ID.script_root();
+
+    println!("Contract P2ID root:  {}", contract_root.to_hex());
+    println!("miden-standards root: {}", expected_root.to_hex());
+
+    if contract_root != expected_root {
+        let replacement = expected_root
+            .iter()
+            .map(|felt| format!("            Felt::from_u64_unchecked({}),", felt.as_int()))
+            .collect::<Vec<_>>()
+            .join("\n");
+        bail!(
+            "P2ID root mismatch.\n\n\
+             Update `p2id_note_root()` in {} to:\n{}",
+            BANK_SOURCE_PATH,
+            replacement
+        );
+    }
+
+    println!("\n  ✓ P2ID roots match");
+
+    Ok(())
+}
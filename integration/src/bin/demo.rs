@@ -0,0 +1,202 @@
+//! End-to-End Bank Demo Binary
+//!
+//! This binary runs the whole bank flow in one go: it creates and initializes a bank
+//! account, creates a faucet and a depositor wallet funded from it, deposits into the
+//! bank and reads the credited balance back. Each step is printed as it completes, so
+//! the run doubles as a smoke test against a live network.
+//!
+//! # Usage
+//! ```bash
+//! cargo run --bin demo -- [AMOUNT]
+//! ```
+//!
+//! # Arguments
+//! * `AMOUNT` - Optional amount to deposit (in base units), between
+//!   `MIN_DEPOSIT_AMOUNT` and `MAX_DEPOSIT_AMOUNT`; defaults to `DEFAULT_DEPOSIT_AMOUNT`
+//!
+//! # Environment
+//! `MIDEN_RPC_ENDPOINT`, `MIDEN_STORE_PATH` and `MIDEN_KEYSTORE_PATH` choose the network
+//! and the client's state; see `ClientSetupConfig::from_env`.
+
+use integration::helpers::{
+    bank_consume_request, build_project_in_dir, create_account_from_package,
+    create_basic_faucet_account, create_basic_wallet_account, create_note_from_package,
+    ensure_bank_initialized, get_balance_for_asset, initialize_bank_account, mint_to_wallet,
+    publish_note_request, setup_client_with, AccountCreationConfig, ClientSetup,
+    ClientSetupConfig, NoteCreationConfig, MAX_DEPOSIT_AMOUNT, MIN_DEPOSIT_AMOUNT,
+};
+
+use anyhow::{bail, Context, Result};
+use miden_client::{account::Account, note::NoteAssets};
+use std::{env, path::Path, sync::Arc};
+
+/// Deposit amount (in base units) used when no amount is given
+const DEFAULT_DEPOSIT_AMOUNT: u64 = 1000;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    println!("=== Miden Bank Demo ===\n");
+
+    // Parse command line arguments
+    let args: Vec<String> = env::args().collect();
+    let deposit_amount = match args.get(1) {
+        Some(amount) => amount
+            .parse::<u64>()
+            .with_context(|| format!("Invalid amount: {}", amount))?,
+        None => DEFAULT_DEPOSIT_AMOUNT,
+    };
+    if !(MIN_DEPOSIT_AMOUNT..=MAX_DEPOSIT_AMOUNT).contains(&deposit_amount) {
+        bail!(
+            "Deposit amount must be between {} and {} tokens, got {}",
+            MIN_DEPOSIT_AMOUNT,
+            MAX_DEPOSIT_AMOUNT,
+            deposit_amount
+        );
+    }
+
+    // Initialize client
+    let ClientSetup {
+        mut client,
+        keystore,
+    } = setup_client_with(ClientSetupConfig::from_env()?).await?;
+
+    let sync_summary = client.sync_state().await?;
+    println!("Connected to network. Latest block: {}", sync_summary.block_num);
+
+    // Build contracts
+    println!("\n[1/6] Building contracts...");
+    let bank_package = Arc::new(
+        build_project_in_dir(Path::new("../contracts/bank-account"), true)
+            .context("Failed to build bank account contract")?,
+    );
+    let init_tx_script_package = Arc::new(
+        build_project_in_dir(Path::new("../contracts/init-tx-script"), true)
+            .context("Failed to build init transaction script")?,
+    );
+    let deposit_note_package = Arc::new(
+        build_project_in_dir(Path::new("../contracts/deposit-note"), true)
+            .context("Failed to build deposit note contract")?,
+    );
+    println!("  ✓ Bank account, init script and deposit note built");
+
+    // Create the bank and the depositor, who also becomes the bank's owner
+    println!("\n[2/6] Creating bank account and depositor wallet...");
+    let bank_account = create_account_from_package(
+        &mut client,
+        bank_package,
+        AccountCreationConfig::bank_default()?,
+    )
+    .await
+    .context("Failed to create bank account")?;
+    println!("  ✓ Bank account created: {}", bank_account.id().to_hex());
+
+    let depositor = create_basic_wallet_account(
+        &mut client,
+        keystore.clone(),
+        AccountCreationConfig::default(),
+    )
+    .await
+    .context("Failed to create depositor wallet")?;
+    println!("  ✓ Depositor wallet created: {}", depositor.id().to_hex());
+
+    println!("\n[3/6] Initializing bank account...");
+    let init_tx_id = initialize_bank_account(
+        &mut client,
+        init_tx_script_package,
+        bank_account.id(),
+        depositor.id(),
+    )
+    .await?;
+    println!("  ✓ Init transaction: {}", init_tx_id.to_hex());
+
+    // Fund the depositor from a fresh faucet
+    println!("\n[4/6] Funding the depositor with {} tokens...", deposit_amount);
+    let faucet = create_basic_faucet_account(&mut client, keystore.clone(), "DEMO", deposit_amount)
+        .await
+        .context("Failed to create faucet")?;
+    println!("  ✓ Faucet created: {}", faucet.id().to_hex());
+
+    let deposit_asset = mint_to_wallet(&mut client, faucet.id(), depositor.id(), deposit_amount)
+        .await
+        .context("Failed to fund the depositor")?;
+    println!("  ✓ Minted tokens received by the depositor");
+
+    // Deposit: the depositor publishes the note, the bank consumes it
+    println!("\n[5/6] Depositing into the bank...");
+    let bank_record = client
+        .get_account(bank_account.id())
+        .await
+        .context("Failed to fetch bank account")?
+        .context("Bank account not found in client after initialization")?;
+    ensure_bank_initialized(
+        &Account::try_from(bank_record).context("Bank account state is not fully known")?,
+    )?;
+
+    let deposit_note = create_note_from_package(
+        &mut client,
+        deposit_note_package,
+        depositor.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![deposit_asset.into()])
+                .context("Failed to create deposit note assets")?,
+            ..Default::default()
+        },
+    )
+    .context("Failed to create deposit note")?;
+    println!("  ✓ Deposit note created: {}", deposit_note.id().to_hex());
+
+    client
+        .submit_new_transaction(depositor.id(), publish_note_request(deposit_note.clone())?)
+        .await
+        .context("Failed to publish deposit note")?;
+    client
+        .sync_state()
+        .await
+        .context("Failed to sync state after publishing note")?;
+    println!("  ✓ Deposit note published");
+
+    let consume_tx_id = client
+        .submit_new_transaction(bank_account.id(), bank_consume_request(vec![deposit_note])?)
+        .await
+        .context("Failed to execute deposit transaction")?;
+    client
+        .sync_state()
+        .await
+        .context("Failed to sync state after deposit")?;
+    println!("  ✓ Deposit transaction: {}", consume_tx_id.to_hex());
+
+    // Read the credited balance back from the bank's balances map
+    println!("\n[6/6] Reading the depositor's balance...");
+    let bank_record = client
+        .get_account(bank_account.id())
+        .await
+        .context("Failed to fetch bank account after deposit")?
+        .context("Bank account disappeared from client after deposit")?;
+    let bank_account =
+        Account::try_from(bank_record).context("Bank account state is not fully known")?;
+    let balance = get_balance_for_asset(&bank_account, depositor.id(), faucet.id())?;
+
+    if balance != deposit_amount {
+        bail!(
+            "Expected a balance of {} tokens after the deposit, found {}",
+            deposit_amount,
+            balance
+        );
+    }
+    println!("  ✓ Balance matches the deposit");
+
+    println!("\n=== Demo Complete ===");
+    println!("\nBank Account: {}", bank_account.id().to_hex());
+    println!("Depositor (bank owner): {}", depositor.id().to_hex());
+    println!("Faucet: {}", faucet.id().to_hex());
+    println!("Depositor Balance: {} tokens", balance);
+    println!("\nTo check the balance again, run:");
+    println!(
+        "  cargo run --bin balance -- {} {} {}",
+        bank_account.id().to_hex(),
+        depositor.id().to_hex(),
+        faucet.id().to_hex()
+    );
+
+    Ok(())
+}
@@ -6,51 +6,173 @@
 //!
 //! # Usage
 //! ```bash
-//! cargo run --bin deposit -- <BANK_ACCOUNT_ID>
+//! cargo run --bin deposit -- <BANK_ACCOUNT_ID> --faucet <FAUCET_ID> [--amount <AMOUNT>] [--note-type <public|private>] [--private]
 //! ```
 //!
 //! # Arguments
 //! * `BANK_ACCOUNT_ID` - The hex ID of the bank account to deposit into
+//! * `--faucet` - The hex ID of a fungible faucet tracked by this client; the deposit
+//!   is minted from it into a new depositor wallet, and the bank is checked for a
+//!   go/no-go before the deposit note is built
+//! * `--amount` - Optional amount to deposit (in base units), between
+//!   `MIN_DEPOSIT_AMOUNT` and `MAX_DEPOSIT_AMOUNT`; defaults to `DEFAULT_DEPOSIT_AMOUNT`
+//! * `--note-type` - Optional type of the deposit note, `public` (the default) or
+//!   `private`
+//! * `--private` - Shorthand for `--note-type private`
+//!
+//! # Environment
+//! `MIDEN_RPC_ENDPOINT`, `MIDEN_STORE_PATH` and `MIDEN_KEYSTORE_PATH` choose the network
+//! and the client's state; see `ClientSetupConfig::from_env`.
+//!
+//! # Private deposit notes
+//! Only the header of a private note is stored on chain; its script, inputs and assets
+//! stay with the client that created it. The network cannot deliver a private note, so
+//! this binary hands the note it built straight to the bank's consume transaction as an
+//! unauthenticated input note. Another client operating the bank would need to receive
+//! the full note out of band before it could consume it.
 //!
 //! # Example
 //! ```bash
-//! cargo run --bin deposit -- 0x1234567890abcdef...
+//! cargo run --bin deposit -- 0x1234567890abcdef... --faucet 0x0123... --amount 1000
 //! ```
 
 use integration::helpers::{
-    build_project_in_dir, create_basic_wallet_account, create_note_from_package,
-    setup_client, AccountCreationConfig, ClientSetup, NoteCreationConfig,
+    bank_consume_request, build_project_in_dir, create_basic_wallet_account,
+    create_note_from_package, ensure_bank_initialized, get_balance_for_asset, mint_to_wallet,
+    preflight_bank, publish_note_request, setup_client_with, AccountCreationConfig, ClientSetup,
+    ClientSetupConfig, NoteCreationConfig, MAX_DEPOSIT_AMOUNT, MIN_DEPOSIT_AMOUNT,
 };
 
 use anyhow::{bail, Context, Result};
 use miden_client::{
-    account::AccountId,
-    transaction::{OutputNote, TransactionRequestBuilder},
+    account::{Account, AccountId},
+    note::{NoteAssets, NoteType},
 };
 use std::{env, path::Path, sync::Arc};
 
-/// Default deposit amount (in base units)
+/// Deposit amount (in base units) used when no amount is given
 const DEFAULT_DEPOSIT_AMOUNT: u64 = 1000;
 
+/// Parsed command line arguments
+struct DepositArgs {
+    bank_account_id: AccountId,
+    faucet_id: AccountId,
+    amount: u64,
+    note_type: NoteType,
+}
+
+/// Builds the usage message listing every argument and flag
+fn usage(program: &str) -> String {
+    format!(
+        "Usage: {program} <BANK_ACCOUNT_ID> --faucet <FAUCET_ID> [--amount <AMOUNT>] [--note-type <public|private>] [--private]\n\n\
+         Flags:\n  \
+           --faucet <FAUCET_ID>       Fungible faucet (tracked by this client) to mint the deposit from\n  \
+           --amount <AMOUNT>          Amount to deposit, {MIN_DEPOSIT_AMOUNT} to {MAX_DEPOSIT_AMOUNT} (default: {DEFAULT_DEPOSIT_AMOUNT})\n  \
+           --note-type <TYPE>         Deposit note type: public or private (default: public)\n  \
+           --private                  Shorthand for --note-type private\n\n\
+         Example: {program} 0x1234567890abcdef... --faucet 0x0123... --amount 1000\n\n\
+         Run 'cargo run --bin initialize' first to get a bank account ID."
+    )
+}
+
+/// Parses the bank account ID and the `--faucet`, `--amount`, `--note-type` and
+/// `--private` flags
+///
+/// # Errors
+/// Returns an error, including the usage message, if an argument is missing, unknown
+/// or invalid, if `--private` is combined with `--note-type public`, or if the amount
+/// is below `MIN_DEPOSIT_AMOUNT` or above `MAX_DEPOSIT_AMOUNT`
+fn parse_args(args: &[String]) -> Result<DepositArgs> {
+    let program = args.first().map(String::as_str).unwrap_or("deposit");
+    let usage = usage(program);
+
+    let mut bank_account_id = None;
+    let mut faucet_id = None;
+    let mut amount = DEFAULT_DEPOSIT_AMOUNT;
+    let mut note_type = None;
+    let mut private = false;
+
+    let mut rest = args.iter().skip(1);
+    while let Some(arg) = rest.next() {
+        let mut flag_value = || {
+            rest.next()
+                .with_context(|| format!("Missing value for {}\n\n{}", arg, usage))
+        };
+        match arg.as_str() {
+            "--faucet" => {
+                let value = flag_value()?;
+                faucet_id = Some(
+                    AccountId::from_hex(value)
+                        .with_context(|| format!("Invalid faucet ID: {}", value))?,
+                );
+            }
+            "--amount" => {
+                let value = flag_value()?;
+                amount = value
+                    .parse()
+                    .with_context(|| format!("Invalid amount: {}", value))?;
+            }
+            "--note-type" => {
+                note_type = Some(match flag_value()?.as_str() {
+                    "public" => NoteType::Public,
+                    "private" => NoteType::Private,
+                    other => bail!("Invalid note type: {} (expected public or private)\n\n{}", other, usage),
+                });
+            }
+            "--private" => private = true,
+            flag if flag.starts_with("--") => bail!("Unknown flag: {}\n\n{}", flag, usage),
+            value if bank_account_id.is_none() => {
+                bank_account_id = Some(
+                    AccountId::from_hex(value)
+                        .with_context(|| format!("Invalid bank account ID: {}", value))?,
+                );
+            }
+            value => bail!("Unexpected argument: {}\n\n{}", value, usage),
+        }
+    }
+
+    let Some(bank_account_id) = bank_account_id else {
+        bail!("{}", usage);
+    };
+    let Some(faucet_id) = faucet_id else {
+        bail!("Missing --faucet\n\n{}", usage);
+    };
+    let note_type = match (note_type, private) {
+        (Some(NoteType::Public), true) => {
+            bail!("--private conflicts with --note-type public\n\n{}", usage)
+        }
+        (_, true) => NoteType::Private,
+        (note_type, false) => note_type.unwrap_or(NoteType::Public),
+    };
+    if !(MIN_DEPOSIT_AMOUNT..=MAX_DEPOSIT_AMOUNT).contains(&amount) {
+        bail!(
+            "Invalid amount: {} (must be between {} and {})",
+            amount,
+            MIN_DEPOSIT_AMOUNT,
+            MAX_DEPOSIT_AMOUNT
+        );
+    }
+
+    Ok(DepositArgs {
+        bank_account_id,
+        faucet_id,
+        amount,
+        note_type,
+    })
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     println!("=== Miden Bank Deposit ===\n");
 
     // Parse command line arguments
     let args: Vec<String> = env::args().collect();
-    if args.len() < 2 {
-        bail!(
-            "Usage: {} <BANK_ACCOUNT_ID>\n\n\
-             Example: {} 0x1234567890abcdef...\n\n\
-             Run 'cargo run --bin initialize' first to get a bank account ID.",
-            args[0],
-            args[0]
-        );
-    }
-
-    let bank_account_id_hex = &args[1];
-    let bank_account_id = AccountId::from_hex(bank_account_id_hex)
-        .context(format!("Invalid bank account ID: {}", bank_account_id_hex))?;
+    let DepositArgs {
+        bank_account_id,
+        faucet_id,
+        amount: deposit_amount,
+        note_type,
+    } = parse_args(&args)?;
 
     println!("Target bank account: {}", bank_account_id.to_hex());
 
@@ -58,7 +180,7 @@ async fn main() -> Result<()> {
     let ClientSetup {
         mut client,
         keystore,
-    } = setup_client().await?;
+    } = setup_client_with(ClientSetupConfig::from_env()?).await?;
 
     let sync_summary = client.sync_state().await?;
     println!("Connected to network. Latest block: {}", sync_summary.block_num);
@@ -69,9 +191,10 @@ async fn main() -> Result<()> {
         .await
         .context("Failed to fetch bank account")?;
 
-    match bank_account_record {
+    let bank_account = match bank_account_record {
         Some(record) => {
             println!("  ✓ Bank account found: {}", record.account_data().id().to_hex());
+            Account::try_from(record).context("Bank account state is not fully known")?
         }
         None => {
             bail!(
@@ -80,7 +203,60 @@ async fn main() -> Result<()> {
                 bank_account_id.to_hex()
             );
         }
+    };
+
+    // Fail here rather than deep in the consume transaction
+    ensure_bank_initialized(&bank_account)?;
+    println!("  ✓ Bank account is initialized");
+
+    // The deposit is minted from the faucet, so this client must control it
+    if client
+        .get_account(faucet_id)
+        .await
+        .context("Failed to fetch faucet account")?
+        .is_none()
+    {
+        bail!(
+            "Faucet account {} not found in client.\n\
+             The faucet must be a fungible faucet created by this client.",
+            faucet_id.to_hex()
+        );
+    }
+    println!("  ✓ Faucet account found: {}", faucet_id.to_hex());
+
+    // Create a sender account (the depositor) that will hold the minted assets
+    println!("\nCreating depositor wallet...");
+    let sender_cfg = AccountCreationConfig::default();
+    let sender_account = create_basic_wallet_account(&mut client, keystore.clone(), sender_cfg)
+        .await
+        .context("Failed to create sender wallet account")?;
+    println!("  ✓ Depositor wallet created: {}", sender_account.id().to_hex());
+
+    // Check the bank would accept the deposit before minting and building a note
+    println!("\nPreflight check for faucet {}...", faucet_id.to_hex());
+    let report =
+        preflight_bank(&mut client, bank_account_id, sender_account.id(), faucet_id).await?;
+    println!(
+        "  Initialized: {}{}",
+        report.initialized,
+        if report.auto_initialize { " (auto-initializes on first deposit)" } else { "" }
+    );
+    println!("  Paused: {}", report.paused);
+    println!("  Faucet accepted: {}", report.faucet_accepted);
+    println!("  Depositor blacklisted: {}", report.depositor_blacklisted);
+    println!("  Deposit limit: {} tokens", report.deposit_limit);
+
+    if !report.is_go() {
+        bail!("Preflight failed: the bank would reject this deposit");
     }
+    if deposit_amount > report.deposit_limit {
+        bail!(
+            "Preflight failed: deposit of {} tokens exceeds the limit of {}",
+            deposit_amount,
+            report.deposit_limit
+        );
+    }
+    println!("  ✓ Bank is ready to accept the deposit");
 
     // Build contracts
     println!("\nBuilding deposit note contract...");
@@ -90,27 +266,28 @@ async fn main() -> Result<()> {
     );
     println!("  ✓ Deposit note contract built");
 
-    // Create a sender account (the depositor) with assets
-    println!("\nCreating depositor wallet...");
-    let sender_cfg = AccountCreationConfig::default();
-    let sender_account = create_basic_wallet_account(&mut client, keystore.clone(), sender_cfg)
+    // Mint the deposit into the depositor wallet
+    println!("\nMinting {} tokens to the depositor...", deposit_amount);
+    let deposit_asset = mint_to_wallet(&mut client, faucet_id, sender_account.id(), deposit_amount)
         .await
-        .context("Failed to create sender wallet account")?;
-    println!("  ✓ Depositor wallet created: {}", sender_account.id().to_hex());
+        .context("Failed to mint deposit asset")?;
+    println!("  ✓ Minted tokens received by the depositor");
 
-    // For this demo, we'll create a deposit note without actual assets
-    // In a real scenario, you would have a faucet or existing assets
+    // Create the deposit note carrying the minted asset
     println!("\nCreating deposit note...");
-    println!("  Deposit amount: {} tokens", DEFAULT_DEPOSIT_AMOUNT);
+    println!("  Deposit amount: {} tokens", deposit_amount);
+    println!("  Note type: {:?}", note_type);
 
-    // Create the deposit note
-    // Note: In a real scenario, you would attach actual assets from a faucet
-    // For now, we create the note structure (assets would come from the sender's vault)
     let deposit_note = create_note_from_package(
         &mut client,
         deposit_note_package.clone(),
         sender_account.id(),
-        NoteCreationConfig::default(),
+        NoteCreationConfig {
+            note_type,
+            assets: NoteAssets::new(vec![deposit_asset.into()])
+                .context("Failed to create deposit note assets")?,
+            ..Default::default()
+        },
     )
     .context("Failed to create deposit note")?;
 
@@ -118,10 +295,7 @@ async fn main() -> Result<()> {
 
     // Publish the deposit note
     println!("\nPublishing deposit note...");
-    let note_publish_request = TransactionRequestBuilder::new()
-        .own_output_notes(vec![OutputNote::Full(deposit_note.clone())])
-        .build()
-        .context("Failed to build note publish transaction request")?;
+    let note_publish_request = publish_note_request(deposit_note.clone())?;
 
     let note_publish_tx_id = client
         .submit_new_transaction(sender_account.id(), note_publish_request)
@@ -136,12 +310,14 @@ async fn main() -> Result<()> {
         .await
         .context("Failed to sync state after publishing note")?;
 
-    // Consume the deposit note with the bank account
+    // Consume the deposit note with the bank account. The full note is passed along
+    // either way; for a private note this is the only copy of its details, since the
+    // chain stores just its header.
+    if note_type == NoteType::Private {
+        println!("\nPrivate note: handing the full note to the bank off-chain");
+    }
     println!("\nExecuting deposit (bank consuming the note)...");
-    let consume_note_request = TransactionRequestBuilder::new()
-        .input_notes([(deposit_note.clone(), None)])
-        .build()
-        .context("Failed to build consume note transaction request")?;
+    let consume_note_request = bank_consume_request(vec![deposit_note.clone()])?;
 
     let consume_tx_id = client
         .submit_new_transaction(bank_account_id, consume_note_request)
@@ -156,10 +332,21 @@ async fn main() -> Result<()> {
         .await
         .context("Failed to sync state after deposit")?;
 
+    // Read the credited balance back from the bank's balances map
+    let bank_account_record = client
+        .get_account(bank_account_id)
+        .await
+        .context("Failed to fetch bank account after deposit")?
+        .context("Bank account disappeared from client after deposit")?;
+    let bank_account =
+        Account::try_from(bank_account_record).context("Bank account state is not fully known")?;
+    let balance = get_balance_for_asset(&bank_account, sender_account.id(), faucet_id)?;
+
     println!("\n=== Deposit Complete ===");
     println!("\nDepositor: {}", sender_account.id().to_hex());
     println!("Bank Account: {}", bank_account_id.to_hex());
     println!("Deposit Note ID: {}", deposit_note.id().to_hex());
+    println!("Depositor Balance: {} tokens", balance);
     println!("Transaction ID: {}", consume_tx_id.to_hex());
 
     Ok(())
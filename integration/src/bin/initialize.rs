@@ -9,20 +9,20 @@
 //! cargo run --bin initialize
 //! ```
 //!
+//! # Environment
+//! `MIDEN_RPC_ENDPOINT`, `MIDEN_STORE_PATH` and `MIDEN_KEYSTORE_PATH` choose the network
+//! and the client's state; see `ClientSetupConfig::from_env`.
+//!
 //! # Output
 //! Prints the bank account ID that should be used for subsequent deposits.
 
 use integration::helpers::{
     build_project_in_dir, create_account_from_package, create_basic_wallet_account,
-    setup_client, AccountCreationConfig, ClientSetup,
+    initialize_bank_account, setup_client_with, AccountCreationConfig, ClientSetup,
+    ClientSetupConfig,
 };
 
 use anyhow::{Context, Result};
-use miden_client::{
-    account::{StorageMap, StorageSlot, StorageSlotName},
-    transaction::{TransactionRequestBuilder, TransactionScript},
-    Word,
-};
 use std::{path::Path, sync::Arc};
 
 #[tokio::main]
@@ -33,7 +33,7 @@ async fn main() -> Result<()> {
     let ClientSetup {
         mut client,
         keystore,
-    } = setup_client().await?;
+    } = setup_client_with(ClientSetupConfig::from_env()?).await?;
 
     let sync_summary = client.sync_state().await?;
     println!("Connected to network. Latest block: {}", sync_summary.block_num);
@@ -52,28 +52,11 @@ async fn main() -> Result<()> {
     );
     println!("  ✓ Init transaction script built");
 
-    // Create the bank account with named storage slots:
-    // - initialized: Value (starts as 0)
-    // - balances: StorageMap
+    // Create the bank account with the canonical storage layout: every value slot
+    // starts at zero (initialized = 0, owner set by initialize, zero limits = the
+    // compiled defaults) and every map starts empty
     println!("\nCreating bank account...");
-    let initialized_slot =
-        StorageSlotName::new("miden::component::miden_bank_account::initialized")
-            .expect("Valid slot name");
-    let balances_slot =
-        StorageSlotName::new("miden::component::miden_bank_account::balances")
-            .expect("Valid slot name");
-
-    let bank_cfg = AccountCreationConfig {
-        storage_slots: vec![
-            StorageSlot::with_value(initialized_slot, Word::default()),
-            StorageSlot::with_map(
-                balances_slot,
-                StorageMap::with_entries([])
-                    .context("Failed to create empty storage map")?,
-            ),
-        ],
-        ..Default::default()
-    };
+    let bank_cfg = AccountCreationConfig::bank_default()?;
 
     let bank_account = create_account_from_package(&mut client, bank_package.clone(), bank_cfg)
         .await
@@ -94,30 +77,18 @@ async fn main() -> Result<()> {
     // Build and execute the initialization transaction
     println!("\nInitializing bank account...");
 
-    let init_program = init_tx_script_package.unwrap_program();
-    let init_tx_script = TransactionScript::new((*init_program).clone());
-
-    // Build transaction request with the init script
-    // The script will call bank_account.initialize()
-    let init_request = TransactionRequestBuilder::new()
-        .custom_script(init_tx_script)
-        .build()
-        .context("Failed to build init transaction request")?;
-
-    // Submit the initialization transaction from the bank account
-    let init_tx_id = client
-        .submit_new_transaction(bank_account.id(), init_request)
-        .await
-        .context("Failed to submit init transaction")?;
+    // The init script calls bank_account.initialize(owner), making the admin wallet the
+    // owner, and the client is synced to confirm the transaction
+    let init_tx_id = initialize_bank_account(
+        &mut client,
+        init_tx_script_package.clone(),
+        bank_account.id(),
+        admin_account.id(),
+    )
+    .await?;
 
     println!("  ✓ Init transaction submitted: {}", init_tx_id.to_hex());
 
-    // Sync to confirm the transaction
-    client
-        .sync_state()
-        .await
-        .context("Failed to sync state after initialization")?;
-
     println!("\n=== Initialization Complete ===");
     println!("\nBank Account ID (use this for deposits):");
     println!("  {}", bank_account.id().to_hex());
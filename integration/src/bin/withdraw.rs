@@ -0,0 +1,227 @@
+//! Withdraw from Bank Account Binary
+//!
+//! This binary withdraws assets from an existing bank account on the Miden network.
+//! The depositor must be a wallet tracked by this client (with its key in the
+//! keystore), since it sends the withdraw request note that the bank consumes.
+//!
+//! # Usage
+//! ```bash
+//! cargo run --bin withdraw -- <BANK_ACCOUNT_ID> <DEPOSITOR_ID> <FAUCET_ID> <AMOUNT>
+//! ```
+//!
+//! # Arguments
+//! * `BANK_ACCOUNT_ID` - The hex ID of the bank account to withdraw from
+//! * `DEPOSITOR_ID` - The hex ID of the depositor wallet whose balance is withdrawn
+//! * `FAUCET_ID` - The hex ID of the faucet whose asset is withdrawn
+//! * `AMOUNT` - The amount to withdraw (in base units)
+//!
+//! # Environment
+//! `MIDEN_RPC_ENDPOINT`, `MIDEN_STORE_PATH` and `MIDEN_KEYSTORE_PATH` choose the network
+//! and the client's state; see `ClientSetupConfig::from_env`.
+//!
+//! # Example
+//! ```bash
+//! cargo run --bin withdraw -- 0x1234567890abcdef... 0xabcdef... 0x0123... 400
+//! ```
+
+use integration::helpers::{
+    bank_consume_request, build_project_in_dir, build_withdraw_note_inputs, create_note_from_package,
+    p2id_tag_for_account, publish_note_request, read_bank_config, setup_client_with, ClientSetup,
+    ClientSetupConfig, NoteCreationConfig,
+};
+
+use anyhow::{bail, Context, Result};
+use miden_client::{
+    account::{Account, AccountId},
+    asset::FungibleAsset,
+    crypto::FeltRng,
+    note::{build_p2id_recipient, Note, NoteAssets, NoteMetadata, NoteType},
+    store::TransactionFilter,
+    transaction::TransactionRequestBuilder,
+};
+use std::{env, path::Path, sync::Arc};
+
+/// Denominator for the bank's withdrawal fee, in basis points
+const FEE_BPS_DENOMINATOR: u64 = 10_000;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    println!("=== Miden Bank Withdraw ===\n");
+
+    // Parse command line arguments
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 5 {
+        bail!(
+            "Usage: {} <BANK_ACCOUNT_ID> <DEPOSITOR_ID> <FAUCET_ID> <AMOUNT>\n\n\
+             Example: {} 0x1234567890abcdef... 0xabcdef... 0x0123... 400",
+            args[0],
+            args[0]
+        );
+    }
+
+    let parse_account_id = |label: &str, hex: &String| {
+        AccountId::from_hex(hex).context(format!("Invalid {}: {}", label, hex))
+    };
+    let bank_account_id = parse_account_id("bank account ID", &args[1])?;
+    let depositor_id = parse_account_id("depositor ID", &args[2])?;
+    let faucet_id = parse_account_id("faucet ID", &args[3])?;
+    let amount: u64 = args[4]
+        .parse()
+        .context(format!("Invalid amount: {}", args[4]))?;
+
+    println!("Target bank account: {}", bank_account_id.to_hex());
+
+    // Initialize client
+    let ClientSetup { mut client, .. } = setup_client_with(ClientSetupConfig::from_env()?).await?;
+
+    let sync_summary = client.sync_state().await?;
+    println!("Connected to network. Latest block: {}", sync_summary.block_num);
+
+    // Verify the bank and depositor accounts exist in our client
+    let bank_account_record = client
+        .get_account(bank_account_id)
+        .await
+        .context("Failed to fetch bank account")?
+        .with_context(|| {
+            format!(
+                "Bank account {} not found in client.\n\
+                 Make sure you've run 'cargo run --bin initialize' first.",
+                bank_account_id.to_hex()
+            )
+        })?;
+    let bank_account =
+        Account::try_from(bank_account_record).context("Bank account state is not fully known")?;
+    println!("  ✓ Bank account found: {}", bank_account.id().to_hex());
+
+    if client
+        .get_account(depositor_id)
+        .await
+        .context("Failed to fetch depositor account")?
+        .is_none()
+    {
+        bail!(
+            "Depositor account {} not found in client.\n\
+             The depositor must be a wallet created by this client.",
+            depositor_id.to_hex()
+        );
+    }
+    println!("  ✓ Depositor account found: {}", depositor_id.to_hex());
+
+    // Build contracts
+    println!("\nBuilding withdraw request note contract...");
+    let withdraw_request_note_package = Arc::new(
+        build_project_in_dir(Path::new("../contracts/withdraw-request-note"), true)
+            .context("Failed to build withdraw request note contract")?,
+    );
+    println!("  ✓ Withdraw request note contract built");
+
+    // P2ID payout parameters: a fresh serial number and the depositor's tag
+    let serial_num = client.rng().draw_word();
+    let p2id_tag = p2id_tag_for_account(depositor_id);
+
+    println!("\nCreating withdraw request note...");
+    println!("  Withdraw amount: {} tokens", amount);
+
+    let withdraw_request_note = create_note_from_package(
+        &mut client,
+        withdraw_request_note_package.clone(),
+        depositor_id,
+        NoteCreationConfig {
+            inputs: build_withdraw_note_inputs(
+                FungibleAsset::new(faucet_id, amount)?,
+                serial_num,
+                p2id_tag,
+                NoteType::Public,
+            ),
+            ..Default::default()
+        },
+    )
+    .context("Failed to create withdraw request note")?;
+
+    println!("  ✓ Withdraw request note created: {}", withdraw_request_note.id().to_hex());
+
+    // Publish the withdraw request note from the depositor
+    println!("\nPublishing withdraw request note...");
+    let note_publish_request = publish_note_request(withdraw_request_note.clone())?;
+
+    let note_publish_tx_id = client
+        .submit_new_transaction(depositor_id, note_publish_request)
+        .await
+        .context("Failed to publish withdraw request note")?;
+
+    println!("  ✓ Note published: {}", note_publish_tx_id.to_hex());
+
+    // Sync state
+    client
+        .sync_state()
+        .await
+        .context("Failed to sync state after publishing note")?;
+
+    // The payout note can only be predicted for the standard P2ID script; a bank with a
+    // custom `p2id_root` pays to a recipient this client cannot rebuild
+    let bank_config = read_bank_config(&bank_account)?;
+    let p2id_note = match bank_config.p2id_root {
+        Some(_) => None,
+        None => {
+            let fee = amount * bank_config.fee_bps / FEE_BPS_DENOMINATOR;
+            Some(Note::new(
+                NoteAssets::new(vec![FungibleAsset::new(faucet_id, amount - fee)?.into()])?,
+                NoteMetadata::new(bank_account_id, NoteType::Public, p2id_tag),
+                build_p2id_recipient(depositor_id, serial_num)?,
+            ))
+        }
+    };
+
+    // Consume the withdraw request note with the bank account
+    println!("\nExecuting withdrawal (bank consuming the note)...");
+    let consume_note_request = match &p2id_note {
+        Some(p2id_note) => TransactionRequestBuilder::new()
+            .input_notes([(withdraw_request_note.clone(), None)])
+            .expected_output_recipients(vec![p2id_note.recipient().clone()])
+            .build()
+            .context("Failed to build consume note transaction request")?,
+        None => bank_consume_request(vec![withdraw_request_note.clone()])?,
+    };
+
+    let consume_tx_id = client
+        .submit_new_transaction(bank_account_id, consume_note_request)
+        .await
+        .context("Failed to execute withdraw transaction")?;
+
+    println!("  ✓ Withdraw transaction: {}", consume_tx_id.to_hex());
+
+    // Final sync
+    client
+        .sync_state()
+        .await
+        .context("Failed to sync state after withdrawal")?;
+
+    // `withdraw` reports the P2ID note's output index; the request note is the
+    // transaction's only input, so its payout is the only output note, at index 0
+    let withdraw_tx = client
+        .get_transactions(TransactionFilter::Ids(vec![consume_tx_id]))
+        .await
+        .context("Failed to fetch withdraw transaction")?
+        .pop()
+        .context("Withdraw transaction not found in client")?;
+    let p2id_note_id = withdraw_tx.details.output_notes.get_note(0).id();
+
+    if let Some(p2id_note) = &p2id_note {
+        if p2id_note.id() != p2id_note_id {
+            println!(
+                "  ⚠ P2ID note {} differs from the predicted note {}",
+                p2id_note_id.to_hex(),
+                p2id_note.id().to_hex()
+            );
+        }
+    }
+
+    println!("\n=== Withdrawal Complete ===");
+    println!("\nDepositor: {}", depositor_id.to_hex());
+    println!("Bank Account: {}", bank_account_id.to_hex());
+    println!("Withdraw Request Note ID: {}", withdraw_request_note.id().to_hex());
+    println!("P2ID Note ID: {}", p2id_note_id.to_hex());
+    println!("Transaction ID: {}", consume_tx_id.to_hex());
+
+    Ok(())
+}
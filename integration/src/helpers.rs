@@ -1,27 +1,44 @@
 //! Common helper functions for scripts and tests
 
-use std::{borrow::Borrow, collections::BTreeSet, path::Path, sync::Arc};
+use std::{
+    borrow::Borrow,
+    collections::{BTreeSet, HashMap},
+    path::{Path, PathBuf},
+    sync::{Arc, LazyLock, Mutex, PoisonError},
+};
 
 use anyhow::{bail, Context, Result};
 use cargo_miden::{run, OutputType};
 use miden_client::{
     account::{
-        component::{AccountComponentMetadata, AuthFalcon512Rpo, BasicWallet, NoAuth},
-        Account, AccountBuilder, AccountComponent, AccountId, AccountStorageMode, AccountType,
-        StorageSlot,
+        component::{
+            AccountComponentMetadata, AuthFalcon512Rpo, BasicFungibleFaucet, BasicWallet, NoAuth,
+        },
+        Account, AccountBuilder, AccountComponent, AccountDelta, AccountId, AccountInterfaceExt,
+        AccountStorageMode, AccountType, StorageMap, StorageSlot, StorageSlotName,
+        StorageSlotType,
     },
     auth::{AuthSecretKey, PublicKeyCommitment},
     builder::ClientBuilder,
     crypto::{rpo_falcon512::SecretKey, FeltRng},
     keystore::FilesystemKeyStore,
-    note::{Note, NoteInputs, NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType},
+    asset::{Asset, FungibleAsset, TokenSymbol},
+    note::{
+        build_p2id_recipient, Note, NoteAssets, NoteId, NoteInputs, NoteMetadata, NoteRecipient,
+        NoteScript, NoteTag, NoteType, PartialNote,
+    },
     rpc::{Endpoint, GrpcClient},
+    transaction::{
+        AccountInterface, ExecutedTransaction, OutputNote, TransactionId, TransactionRequest,
+        TransactionRequestBuilder, TransactionScript,
+    },
     utils::Deserializable,
     Client, Word,
 };
 use miden_client_sqlite_store::ClientBuilderSqliteExt;
 use miden_core::Felt;
 use miden_mast_package::{Package, SectionId};
+use miden_testing::{Auth, MockChain, MockChainBuilder};
 use rand::RngCore;
 
 /// Test setup configuration containing initialized client and keystore
@@ -30,8 +47,85 @@ pub struct ClientSetup {
     pub keystore: Arc<FilesystemKeyStore>,
 }
 
+/// Environment variable naming the RPC endpoint: `testnet`, `devnet`, `localhost` or a
+/// node URL such as `http://localhost:57291`
+pub const RPC_ENDPOINT_ENV: &str = "MIDEN_RPC_ENDPOINT";
+
+/// Environment variable naming the sqlite store file
+pub const STORE_PATH_ENV: &str = "MIDEN_STORE_PATH";
+
+/// Environment variable naming the keystore directory
+pub const KEYSTORE_PATH_ENV: &str = "MIDEN_KEYSTORE_PATH";
+
+/// Where the client connects and keeps its state
+///
+/// The default targets testnet with the store at `../store.sqlite3` and the keystore
+/// at `../keystore`, as `setup_client()` always has.
+pub struct ClientSetupConfig {
+    pub endpoint: Endpoint,
+    pub store_path: PathBuf,
+    pub keystore_path: PathBuf,
+}
+
+impl Default for ClientSetupConfig {
+    fn default() -> Self {
+        Self {
+            endpoint: Endpoint::testnet(),
+            store_path: PathBuf::from("../store.sqlite3"),
+            keystore_path: PathBuf::from("../keystore"),
+        }
+    }
+}
+
+impl ClientSetupConfig {
+    /// Reads the configuration from the environment
+    ///
+    /// Each of `MIDEN_RPC_ENDPOINT`, `MIDEN_STORE_PATH` and `MIDEN_KEYSTORE_PATH`
+    /// overrides its default when set, so the binaries can be pointed at another
+    /// network or state directory without editing source.
+    ///
+    /// # Errors
+    /// Returns an error if `MIDEN_RPC_ENDPOINT` is neither a known network nor a valid URL
+    pub fn from_env() -> Result<Self> {
+        let mut config = Self::default();
+
+        if let Ok(endpoint) = std::env::var(RPC_ENDPOINT_ENV) {
+            config.endpoint = parse_endpoint(&endpoint)?;
+        }
+        if let Ok(store_path) = std::env::var(STORE_PATH_ENV) {
+            config.store_path = PathBuf::from(store_path);
+        }
+        if let Ok(keystore_path) = std::env::var(KEYSTORE_PATH_ENV) {
+            config.keystore_path = PathBuf::from(keystore_path);
+        }
+
+        Ok(config)
+    }
+}
+
+/// Parses an RPC endpoint given as a network name or a node URL
+///
+/// # Arguments
+/// * `endpoint` - `testnet`, `devnet`, `localhost` or a URL such as `https://rpc.testnet.miden.io`
+///
+/// # Errors
+/// Returns an error if `endpoint` is not a known network and cannot be parsed as a URL
+pub fn parse_endpoint(endpoint: &str) -> Result<Endpoint> {
+    match endpoint {
+        "testnet" => Ok(Endpoint::testnet()),
+        "devnet" => Ok(Endpoint::devnet()),
+        "localhost" => Ok(Endpoint::localhost()),
+        url => Endpoint::try_from(url)
+            .map_err(anyhow::Error::msg)
+            .with_context(|| format!("Invalid RPC endpoint: {}", url)),
+    }
+}
+
 /// Initializes test infrastructure with client and keystore
 ///
+/// Connects to testnet with the default store and keystore paths; see
+/// [`setup_client_with`] to choose them.
+///
 /// # Returns
 /// A `ClientSetup` containing the initialized client and keystore
 ///
@@ -39,22 +133,33 @@ pub struct ClientSetup {
 /// Returns an error if RPC connection fails, keystore initialization fails,
 /// or client building fails
 pub async fn setup_client() -> Result<ClientSetup> {
+    setup_client_with(ClientSetupConfig::default()).await
+}
+
+/// Initializes a client and keystore for the given endpoint and paths
+///
+/// # Arguments
+/// * `config` - The RPC endpoint and the store and keystore locations
+///
+/// # Returns
+/// A `ClientSetup` containing the initialized client and keystore
+///
+/// # Errors
+/// Returns an error if RPC connection fails, keystore initialization fails,
+/// or client building fails
+pub async fn setup_client_with(config: ClientSetupConfig) -> Result<ClientSetup> {
     // Initialize RPC connection
-    let endpoint = Endpoint::testnet();
     let timeout_ms = 10_000;
-    let rpc_client = Arc::new(GrpcClient::new(&endpoint, timeout_ms));
+    let rpc_client = Arc::new(GrpcClient::new(&config.endpoint, timeout_ms));
 
     // Initialize keystore
-    let keystore_path = std::path::PathBuf::from("../keystore");
-
-    let keystore =
-        Arc::new(FilesystemKeyStore::new(keystore_path).context("Failed to initialize keystore")?);
-
-    let store_path = std::path::PathBuf::from("../store.sqlite3");
+    let keystore = Arc::new(
+        FilesystemKeyStore::new(config.keystore_path).context("Failed to initialize keystore")?,
+    );
 
     let client = ClientBuilder::new()
         .rpc(rpc_client)
-        .sqlite_store(store_path)
+        .sqlite_store(config.store_path)
         .authenticator(keystore.clone())
         .in_debug_mode(true.into())
         .build()
@@ -109,6 +214,53 @@ pub fn build_project_in_dir(dir: &Path, release: bool) -> Result<Package> {
     Package::read_from_bytes(&package_bytes).context("Failed to deserialize package from bytes")
 }
 
+/// Packages built by [`build_project_cached`], keyed by canonical project directory
+/// and release flag
+type PackageCache = HashMap<(PathBuf, bool), Arc<Package>>;
+
+static PACKAGE_CACHE: LazyLock<Mutex<PackageCache>> = LazyLock::new(Default::default);
+
+/// Builds a Miden project at most once per process
+///
+/// The first call for a project builds it with [`build_project_in_dir`]; later calls
+/// with the same directory and profile return the same package. Directories are
+/// canonicalized, so different relative paths to one project share an entry. The
+/// cache lock is held during the build, so concurrent tests asking for a contract
+/// wait for a single build instead of compiling it side by side.
+///
+/// Entries are never invalidated: a contract edited while the process runs is not
+/// rebuilt. Tests pick up source changes on their next run; code that needs a fresh
+/// build within the process (e.g. the `build-contract` binary) should call
+/// [`build_project_in_dir`] directly.
+///
+/// # Arguments
+/// * `dir` - Path to the directory containing the Cargo.toml
+/// * `release` - Whether to build in release mode
+///
+/// # Returns
+/// The compiled `Package`, shared with every other caller for the same project
+///
+/// # Errors
+/// Returns an error if the directory does not exist or compilation fails; failed
+/// builds are not cached
+pub fn build_project_cached(dir: &Path, release: bool) -> Result<Arc<Package>> {
+    let key = (
+        dir.canonicalize()
+            .with_context(|| format!("Failed to resolve project directory {}", dir.display()))?,
+        release,
+    );
+
+    // Entries are only inserted after a successful build, so a poisoned map is still valid
+    let mut cache = PACKAGE_CACHE.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(package) = cache.get(&key) {
+        return Ok(package.clone());
+    }
+
+    let package = Arc::new(build_project_in_dir(&key.0, release)?);
+    cache.insert(key, package.clone());
+    Ok(package)
+}
+
 /// Configuration for creating an account with a custom component
 #[derive(Clone)]
 pub struct AccountCreationConfig {
@@ -116,6 +268,9 @@ pub struct AccountCreationConfig {
     pub storage_mode: AccountStorageMode,
     pub storage_slots: Vec<StorageSlot>,
     pub supported_types: Option<Vec<AccountType>>,
+    /// Assets the account's vault starts with. Only mock-chain accounts can start
+    /// funded, so `create_account_from_package` rejects a non-empty list.
+    pub vault_assets: Vec<Asset>,
 }
 
 impl Default for AccountCreationConfig {
@@ -125,10 +280,27 @@ impl Default for AccountCreationConfig {
             storage_mode: AccountStorageMode::Public,
             storage_slots: vec![],
             supported_types: None,
+            vault_assets: vec![],
         }
     }
 }
 
+impl AccountCreationConfig {
+    /// Configuration for a bank account with the canonical storage layout
+    ///
+    /// Every slot from `BANK_STORAGE_SLOTS` is created in order, value slots at zero
+    /// and maps empty. Use `bank_storage_slots()` to set value slots at creation.
+    ///
+    /// # Errors
+    /// Returns an error if a slot name or storage map cannot be created
+    pub fn bank_default() -> Result<Self> {
+        Ok(Self {
+            storage_slots: bank_storage_slots(&[])?,
+            ..Default::default()
+        })
+    }
+}
+
 /// Creates an account component from a compiled package
 ///
 /// # Arguments
@@ -197,6 +369,10 @@ pub async fn create_account_from_package(
     package: Arc<Package>,
     config: AccountCreationConfig,
 ) -> Result<Account> {
+    if !config.vault_assets.is_empty() {
+        bail!("A new account cannot start with vault assets");
+    }
+
     let account_component = account_component_from_package(package, &config)
         .context("Failed to create account component from package")?;
 
@@ -233,6 +409,7 @@ pub async fn create_testing_account_from_package(
         .storage_mode(config.storage_mode)
         .with_component(account_component)
         .with_auth_component(NoAuth)
+        .with_assets(config.vault_assets)
         .build_existing()
         .context("Failed to build account")?;
 
@@ -316,6 +493,104 @@ pub fn create_testing_note_from_package(
     Ok(Note::new(config.assets, metadata, recipient))
 }
 
+/// Builds a transaction request in which the bank consumes the given notes
+///
+/// The notes are passed as unauthenticated input notes, without note arguments,
+/// in the order given. Notes that depend on each other (e.g. a deposit followed by
+/// a withdrawal for the same depositor) must be listed in execution order.
+///
+/// # Arguments
+/// * `notes` - The bank notes (deposit, withdraw request, ...) to consume
+///
+/// # Returns
+/// A `TransactionRequest` to submit from the bank account
+///
+/// # Errors
+/// Returns an error if the transaction request cannot be built
+pub fn bank_consume_request(notes: Vec<Note>) -> Result<TransactionRequest> {
+    TransactionRequestBuilder::new()
+        .input_notes(notes.into_iter().map(|note| (note, None)))
+        .build()
+        .context("Failed to build consume note transaction request")
+}
+
+/// Builds a transaction request that publishes a note as an output note
+///
+/// # Arguments
+/// * `note` - The note to publish (e.g. a deposit note created by the depositor)
+///
+/// # Returns
+/// A `TransactionRequest` to submit from the note sender's account
+///
+/// # Errors
+/// Returns an error if the transaction request cannot be built
+pub fn publish_note_request(note: Note) -> Result<TransactionRequest> {
+    TransactionRequestBuilder::new()
+        .own_output_notes(vec![OutputNote::Full(note)])
+        .build()
+        .context("Failed to build note publish transaction request")
+}
+
+/// Number of account ID prefix bits a P2ID note tag carries for a local account
+///
+/// This is the protocol's default length for accounts with local execution (public or
+/// private storage); network accounts carry 30 bits instead.
+pub const P2ID_TAG_ACCOUNT_BITS: u8 = NoteTag::DEFAULT_LOCAL_ACCOUNT_TARGET_TAG_LENGTH;
+
+/// Computes the note tag that routes a P2ID note to its target account
+///
+/// For a local account the tag's two most significant bits are `0b00` (there is no
+/// longer a separate "local any" prefix), the next `P2ID_TAG_ACCOUNT_BITS` (14) bits are
+/// the top bits of the account ID prefix, and the low 16 bits are zero. This is the tag
+/// the bank's withdrawal, refund and receipt notes should be given.
+///
+/// # Arguments
+/// * `account_id` - The account the P2ID note pays
+///
+/// # Returns
+/// The `NoteTag` targeting that account
+pub fn p2id_tag_for_account(account_id: AccountId) -> NoteTag {
+    NoteTag::with_account_target(account_id)
+}
+
+/// Builds the note inputs of a withdraw request note
+///
+/// Produces the layout the `withdraw-request-note` script reads:
+/// `[amount, 0, faucet_suffix, faucet_prefix, serial_num[0..4], tag, note_type]`.
+/// A deadline block, if any, is pushed after these 10 Felts.
+///
+/// # Arguments
+/// * `asset` - The fungible asset to withdraw
+/// * `serial_num` - Serial number of the P2ID note the bank pays out
+/// * `tag` - Tag of the P2ID note, usually [`p2id_tag_for_account`] of the depositor
+/// * `note_type` - Note type of the P2ID note
+///
+/// # Returns
+/// The 10 note inputs of the withdraw request note
+pub fn build_withdraw_note_inputs(
+    asset: FungibleAsset,
+    serial_num: Word,
+    tag: NoteTag,
+    note_type: NoteType,
+) -> Vec<Felt> {
+    vec![
+        // WITHDRAW ASSET WORD
+        Felt::new(asset.amount()),
+        Felt::new(0),
+        asset.faucet_id().suffix(),
+        asset.faucet_id().prefix().as_felt(),
+        // P2ID OUTPUT NOTE SERIAL NUMBER
+        serial_num[0],
+        serial_num[1],
+        serial_num[2],
+        serial_num[3],
+        // TAG
+        Felt::new(tag.as_u32() as u64),
+        // NOTE TYPE (1 = Public, 2 = Private)
+        Felt::new(note_type as u64),
+    ]
+}
+
 /// Creates a basic wallet account with authentication
 ///
 /// # Arguments
@@ -361,3 +636,1297 @@ pub async fn create_basic_wallet_account(
 
     Ok(account)
 }
+
+/// Creates a basic fungible faucet account with authentication
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `keystore` - The keystore for storing the faucet's authentication key
+/// * `symbol` - The token symbol, e.g. `"DEMO"`
+/// * `max_supply` - The most tokens the faucet can ever mint
+///
+/// # Returns
+/// The created public faucet `Account`
+///
+/// # Errors
+/// Returns an error if the symbol is invalid, or account creation, key generation or
+/// keystore operations fail
+pub async fn create_basic_faucet_account(
+    client: &mut Client<FilesystemKeyStore>,
+    keystore: Arc<FilesystemKeyStore>,
+    symbol: &str,
+    max_supply: u64,
+) -> Result<Account> {
+    let mut init_seed = [0_u8; 32];
+    client.rng().fill_bytes(&mut init_seed);
+
+    let key_pair = SecretKey::with_rng(client.rng());
+
+    let token_symbol = TokenSymbol::new(symbol).context("Invalid token symbol")?;
+    let faucet_component = BasicFungibleFaucet::new(token_symbol, 8, Felt::new(max_supply))
+        .context("Failed to create fungible faucet component")?;
+
+    let account = AccountBuilder::new(init_seed)
+        .account_type(AccountType::FungibleFaucet)
+        .storage_mode(AccountStorageMode::Public)
+        .with_auth_component(AuthFalcon512Rpo::new(PublicKeyCommitment::from(
+            key_pair.public_key().to_commitment(),
+        )))
+        .with_component(faucet_component)
+        .build()
+        .context("Failed to build fungible faucet account")?;
+
+    client
+        .add_account(&account, false)
+        .await
+        .context("Failed to add account to client")?;
+
+    keystore
+        .add_key(&AuthSecretKey::Falcon512Rpo(key_pair))
+        .context("Failed to add key to keystore")?;
+
+    Ok(account)
+}
+
+/// Mints a faucet's asset into a wallet tracked by the client
+///
+/// The faucet mints a public P2ID note to the wallet, which the wallet then consumes,
+/// so the asset ends up in the wallet's vault. The client is synced after each step.
+///
+/// # Arguments
+/// * `client` - The client tracking both the faucet and the wallet
+/// * `faucet_id` - The fungible faucet to mint from
+/// * `wallet_id` - The wallet that receives the asset
+/// * `amount` - The amount to mint (in base units)
+///
+/// # Returns
+/// The minted asset
+///
+/// # Errors
+/// Returns an error if the asset is invalid or either transaction fails
+pub async fn mint_to_wallet(
+    client: &mut Client<FilesystemKeyStore>,
+    faucet_id: AccountId,
+    wallet_id: AccountId,
+    amount: u64,
+) -> Result<FungibleAsset> {
+    let asset = FungibleAsset::new(faucet_id, amount).context("Failed to create asset to mint")?;
+    let mint_request = TransactionRequestBuilder::new()
+        .build_mint_fungible_asset(asset, wallet_id, NoteType::Public, client.rng())
+        .context("Failed to build mint transaction request")?;
+    let mint_notes = mint_request.expected_output_own_notes();
+
+    client
+        .submit_new_transaction(faucet_id, mint_request)
+        .await
+        .context("Failed to mint asset")?;
+    client
+        .sync_state()
+        .await
+        .context("Failed to sync state after minting")?;
+
+    // Consume the minted note so the asset lands in the wallet's vault
+    let receive_request = TransactionRequestBuilder::new()
+        .build_consume_notes(mint_notes)
+        .context("Failed to build mint note consume request")?;
+
+    client
+        .submit_new_transaction(wallet_id, receive_request)
+        .await
+        .context("Failed to consume minted note")?;
+    client
+        .sync_state()
+        .await
+        .context("Failed to sync state after receiving the minted asset")?;
+
+    Ok(asset)
+}
+
+/// Initializes a bank account tracked by the client with the init transaction script
+///
+/// The script runs without a default faucet, so the bank accepts any faucet, and
+/// records `owner` as the bank's owner. The client is synced afterwards.
+///
+/// # Arguments
+/// * `client` - The client tracking the bank account
+/// * `init_tx_script_package` - The compiled `init-tx-script` package
+/// * `bank_id` - The bank account to initialize
+/// * `owner` - The account recorded as the bank's owner
+///
+/// # Returns
+/// The ID of the init transaction
+///
+/// # Errors
+/// Returns an error if the transaction request cannot be built or the transaction fails
+pub async fn initialize_bank_account(
+    client: &mut Client<FilesystemKeyStore>,
+    init_tx_script_package: Arc<Package>,
+    bank_id: AccountId,
+    owner: AccountId,
+) -> Result<TransactionId> {
+    let init_program = init_tx_script_package.unwrap_program();
+    let init_tx_script = TransactionScript::new((*init_program).clone());
+
+    // Script argument: [faucet_prefix, faucet_suffix, owner_prefix, owner_suffix]
+    let init_arg = Word::from([Felt::new(0), Felt::new(0), owner.prefix().as_felt(), owner.suffix()]);
+    let init_request = TransactionRequestBuilder::new()
+        .custom_script(init_tx_script)
+        .script_arg(init_arg)
+        .build()
+        .context("Failed to build init transaction request")?;
+
+    let init_tx_id = client
+        .submit_new_transaction(bank_id, init_request)
+        .await
+        .context("Failed to submit init transaction")?;
+
+    client
+        .sync_state()
+        .await
+        .context("Failed to sync state after initialization")?;
+
+    Ok(init_tx_id)
+}
+
+/// Asserts that two Words are equal, reporting a field-by-field diff on mismatch
+///
+/// # Arguments
+/// * `actual` - The Word produced by the code under test
+/// * `expected` - The Word the test expects
+/// * `context` - A short description included in the failure message
+///
+/// # Panics
+/// Panics if the Words differ, naming every index whose felts do not match
+/// along with the actual and expected values at that index
+pub fn assert_word_eq(actual: Word, expected: Word, context: &str) {
+    let diffs: Vec<String> = (0..4)
+        .filter(|&i| actual[i] != expected[i])
+        .map(|i| {
+            format!(
+                "index {}: actual {}, expected {}",
+                i,
+                actual[i].as_int(),
+                expected[i].as_int()
+            )
+        })
+        .collect();
+
+    if !diffs.is_empty() {
+        panic!("{}: words differ at {}", context, diffs.join("; "));
+    }
+}
+
+/// Asserts that a P2ID note pays exactly `expected_asset` to `expected_id`
+///
+/// The bank's `create_p2id_note` packs the target's `[suffix, prefix]` into the recipient
+/// inputs. This recomputes the recipient for `expected_id` with the note's own serial
+/// number via `build_p2id_recipient` and compares digests, so tests state the intended
+/// target instead of comparing opaque recipient digests.
+///
+/// # Arguments
+/// * `note` - The P2ID note produced by the bank
+/// * `expected_id` - The account the note should be spendable by
+/// * `expected_asset` - The single asset the note should carry
+///
+/// # Panics
+/// Panics if the recipient does not target `expected_id`, or if the note's assets
+/// are not exactly `[expected_asset]`
+pub fn assert_p2id_recipient_targets(note: &Note, expected_id: AccountId, expected_asset: Asset) {
+    let expected_recipient = build_p2id_recipient(expected_id, note.recipient().serial_num())
+        .expect("P2ID recipient builds");
+
+    assert_eq!(
+        note.recipient().digest(),
+        expected_recipient.digest(),
+        "P2ID note recipient does not target account {}",
+        expected_id
+    );
+
+    let assets: Vec<Asset> = note.assets().iter().copied().collect();
+    assert_eq!(
+        assets,
+        vec![expected_asset],
+        "P2ID note carries unexpected assets"
+    );
+}
+
+/// A single storage write expected in an account delta
+#[derive(Debug, Clone, PartialEq)]
+pub enum StorageChange {
+    /// A value slot set to `value`
+    Value { slot: StorageSlotName, value: Word },
+    /// A map slot entry at `key` set to `value`
+    MapEntry {
+        slot: StorageSlotName,
+        key: Word,
+        value: Word,
+    },
+}
+
+/// The complete set of changes a transaction is expected to make to an account
+#[derive(Debug, Clone, Default)]
+pub struct ExpectedDelta {
+    /// Every storage write, in any order
+    pub storage_changes: Vec<StorageChange>,
+    /// Every fungible vault change as (faucet, signed amount), in any order
+    pub vault_changes: Vec<(AccountId, i64)>,
+}
+
+/// Asserts that a transaction changed the account by exactly the expected delta
+///
+/// Unlike checking the post-state, this catches transactions that end in the right
+/// state but also wrote slots or moved assets they should not have touched.
+///
+/// # Arguments
+/// * `executed_tx` - The executed transaction whose account delta to check
+/// * `expected` - Every storage write and fungible vault change the transaction should make
+///
+/// # Panics
+/// Panics if an expected change is missing, if the delta contains a change that is not
+/// expected, or if any non-fungible asset changed
+pub fn assert_delta_matches(executed_tx: &ExecutedTransaction, expected: &ExpectedDelta) {
+    let delta = executed_tx.account_delta();
+
+    let mut storage_changes: Vec<StorageChange> = delta
+        .storage()
+        .values()
+        .map(|(slot, value)| StorageChange::Value {
+            slot: slot.clone(),
+            value: *value,
+        })
+        .collect();
+    for (slot, map_delta) in delta.storage().maps() {
+        storage_changes.extend(map_delta.entries().iter().map(|(key, value)| {
+            StorageChange::MapEntry {
+                slot: slot.clone(),
+                key: Word::from(*key),
+                value: *value,
+            }
+        }));
+    }
+
+    let missing: Vec<_> = expected
+        .storage_changes
+        .iter()
+        .filter(|change| !storage_changes.contains(change))
+        .collect();
+    let unexpected: Vec<_> = storage_changes
+        .iter()
+        .filter(|change| !expected.storage_changes.contains(change))
+        .collect();
+    assert!(
+        missing.is_empty() && unexpected.is_empty(),
+        "Storage delta mismatch: missing {:?}, unexpected {:?}",
+        missing,
+        unexpected
+    );
+
+    let mut vault_changes: Vec<(AccountId, i64)> = delta
+        .vault()
+        .fungible()
+        .iter()
+        .map(|(faucet, amount)| (*faucet, *amount))
+        .collect();
+    let mut expected_vault_changes = expected.vault_changes.clone();
+    vault_changes.sort();
+    expected_vault_changes.sort();
+    assert_eq!(
+        vault_changes, expected_vault_changes,
+        "Fungible vault delta mismatch"
+    );
+    assert!(
+        delta.vault().non_fungible().is_empty(),
+        "Unexpected non-fungible vault changes"
+    );
+}
+
+/// Storage slot namespace of the bank account component
+pub const BANK_SLOT_NAMESPACE: &str = "miden::component::miden_bank_account::";
+
+/// Expected storage layout of the bank account component: (slot field name, slot kind)
+///
+/// Keep this in sync with the `#[storage]` fields of the `Bank` struct in
+/// `contracts/bank-account`.
+pub const BANK_STORAGE_SLOTS: &[(&str, StorageSlotType)] = &[
+    ("initialized", StorageSlotType::Value),
+    ("balances", StorageSlotType::Map),
+    ("default_faucet", StorageSlotType::Value),
+    ("refund_mode", StorageSlotType::Value),
+    ("refunds", StorageSlotType::Map),
+    ("auto_initialize", StorageSlotType::Value),
+    ("depositor_faucet_counts", StorageSlotType::Map),
+    ("depositor_faucets", StorageSlotType::Map),
+    ("min_account_age", StorageSlotType::Value),
+    ("first_deposit_blocks", StorageSlotType::Map),
+    ("exchange_rates", StorageSlotType::Map),
+    ("max_deposit_per_block", StorageSlotType::Value),
+    ("block_deposits", StorageSlotType::Value),
+    ("withdraw_cooldown", StorageSlotType::Value),
+    ("last_withdraw_blocks", StorageSlotType::Map),
+    ("max_total_balance", StorageSlotType::Value),
+    ("max_deposit", StorageSlotType::Value),
+    ("paused", StorageSlotType::Value),
+    ("totals", StorageSlotType::Map),
+    ("owner", StorageSlotType::Value),
+    ("withdraw_limits", StorageSlotType::Map),
+    ("fee_bps", StorageSlotType::Value),
+    ("fees", StorageSlotType::Map),
+    ("funded_faucets", StorageSlotType::Value),
+    ("p2id_root", StorageSlotType::Value),
+    ("deposit_events", StorageSlotType::Value),
+    ("locks", StorageSlotType::Map),
+    ("blacklist", StorageSlotType::Map),
+    ("allowed_assets", StorageSlotType::Map),
+    ("allowed_asset_count", StorageSlotType::Value),
+];
+
+/// Name of one of the bank's storage slots
+///
+/// # Arguments
+/// * `field` - The slot's field name in `BANK_STORAGE_SLOTS`, e.g. `"balances"`
+///
+/// # Errors
+/// Returns an error if `field` is not a bank storage slot
+pub fn bank_slot_name(field: &str) -> Result<StorageSlotName> {
+    if !BANK_STORAGE_SLOTS.iter().any(|(name, _)| *name == field) {
+        bail!("`{}` is not a bank storage slot", field);
+    }
+    Ok(StorageSlotName::new(format!("{}{}", BANK_SLOT_NAMESPACE, field))?)
+}
+
+/// Builds the bank's storage slots in `BANK_STORAGE_SLOTS` order
+///
+/// Value slots listed in `values` are created with the given word, every other value
+/// slot at zero; maps are always empty.
+///
+/// # Arguments
+/// * `values` - (slot field name, value) pairs for value slots to set at creation
+///
+/// # Errors
+/// Returns an error if `values` names a field that is not a bank value slot, or if a
+/// slot name or storage map cannot be created
+pub fn bank_storage_slots(values: &[(&str, Word)]) -> Result<Vec<StorageSlot>> {
+    for (field, _) in values {
+        if !BANK_STORAGE_SLOTS
+            .iter()
+            .any(|(name, slot_type)| name == field && *slot_type == StorageSlotType::Value)
+        {
+            bail!("`{}` is not a bank value slot", field);
+        }
+    }
+
+    BANK_STORAGE_SLOTS
+        .iter()
+        .map(|(field, slot_type)| {
+            let name = bank_slot_name(field)?;
+            Ok(match slot_type {
+                StorageSlotType::Value => {
+                    let value = values
+                        .iter()
+                        .find(|(value_field, _)| value_field == field)
+                        .map(|(_, value)| *value)
+                        .unwrap_or_default();
+                    StorageSlot::with_value(name, value)
+                }
+                StorageSlotType::Map => StorageSlot::with_map(
+                    name,
+                    StorageMap::with_entries([]).context("Failed to create empty storage map")?,
+                ),
+            })
+        })
+        .collect()
+}
+
+/// Reads a depositor's balance in one faucet's asset from a bank account
+///
+/// Mirrors `get_balance_for_asset()` on the bank component, reading the
+/// `[depositor_prefix, depositor_suffix, faucet_prefix, faucet_suffix]` key of the
+/// balances map directly from account storage.
+///
+/// # Arguments
+/// * `bank_account` - The bank account to read
+/// * `depositor` - The AccountId whose balance to read
+/// * `faucet` - The faucet whose asset the balance is held in
+///
+/// # Returns
+/// The depositor's balance, or 0 if they never deposited that asset
+///
+/// # Errors
+/// Returns an error if the balances slot is missing
+pub fn get_balance_for_asset(
+    bank_account: &Account,
+    depositor: AccountId,
+    faucet: AccountId,
+) -> Result<u64> {
+    let balances_slot = bank_slot_name("balances")?;
+    let key = Word::from([
+        depositor.prefix().as_felt(),
+        depositor.suffix(),
+        faucet.prefix().as_felt(),
+        faucet.suffix(),
+    ]);
+    // A Felt stored in a map reads back as [0, 0, 0, value]
+    let balance = bank_account
+        .storage()
+        .get_map_item(&balances_slot, key)
+        .context("Failed to read depositor balance")?[3]
+        .as_int();
+    Ok(balance)
+}
+
+/// Asserts a depositor's balance in one faucet's asset
+///
+/// Reads the balance with [`get_balance_for_asset`], which remains available for
+/// checks that need the raw value.
+///
+/// # Arguments
+/// * `bank_account` - The bank account to read
+/// * `depositor` - The AccountId whose balance to check
+/// * `faucet` - The faucet whose asset the balance is held in
+/// * `expected` - The balance the test expects
+///
+/// # Panics
+/// Panics if the balances slot is missing or the balance differs from `expected`
+pub fn assert_balance(bank_account: &Account, depositor: AccountId, faucet: AccountId, expected: u64) {
+    let balance = get_balance_for_asset(bank_account, depositor, faucet)
+        .expect("Bank account should have a balances slot");
+    assert_eq!(
+        balance,
+        expected,
+        "Balance of depositor {} in faucet {}",
+        depositor.to_hex(),
+        faucet.to_hex()
+    );
+}
+
+/// Reads a bank account's total liability in one faucet's asset
+///
+/// Mirrors `get_total()` on the bank component: the sum of every depositor's balance
+/// in that faucet, read from the `[faucet_prefix, faucet_suffix, 0, 0]` key of the
+/// totals map.
+///
+/// # Arguments
+/// * `bank_account` - The bank account to read
+/// * `faucet` - The faucet whose total to read
+///
+/// # Returns
+/// The total of all depositor balances in that faucet, or 0 if none was deposited
+///
+/// # Errors
+/// Returns an error if the totals slot is missing
+pub fn get_total(bank_account: &Account, faucet: AccountId) -> Result<u64> {
+    let totals_slot = bank_slot_name("totals")?;
+    let key = Word::from([faucet.prefix().as_felt(), faucet.suffix(), Felt::new(0), Felt::new(0)]);
+    // A Felt stored in a map reads back as [0, 0, 0, value]
+    let total = bank_account
+        .storage()
+        .get_map_item(&totals_slot, key)
+        .context("Failed to read faucet total")?[3]
+        .as_int();
+    Ok(total)
+}
+
+/// Reads the withdrawal fees a bank account has retained in one faucet's asset
+///
+/// Mirrors `get_fees()` on the bank component, reading the
+/// `[faucet_prefix, faucet_suffix, 0, 0]` key of the fees map.
+///
+/// # Arguments
+/// * `bank_account` - The bank account to read
+/// * `faucet` - The faucet whose fees to read
+///
+/// # Returns
+/// The retained fees in that faucet, or 0 if none were charged
+///
+/// # Errors
+/// Returns an error if the fees slot is missing
+pub fn get_fees(bank_account: &Account, faucet: AccountId) -> Result<u64> {
+    let fees_slot = bank_slot_name("fees")?;
+    let key = Word::from([faucet.prefix().as_felt(), faucet.suffix(), Felt::new(0), Felt::new(0)]);
+    // A Felt stored in a map reads back as [0, 0, 0, value]
+    let fees = bank_account
+        .storage()
+        .get_map_item(&fees_slot, key)
+        .context("Failed to read faucet fees")?[3]
+        .as_int();
+    Ok(fees)
+}
+
+/// Reads the faucets a depositor has ever deposited from a bank account's faucet index
+///
+/// Mirrors `get_depositor_faucet_count()` / `get_depositor_faucet()` on the bank
+/// component, reading the two index maps directly from account storage.
+///
+/// # Arguments
+/// * `bank_account` - The bank account to read
+/// * `depositor` - The AccountId whose faucets to list
+///
+/// # Returns
+/// The faucet AccountIds in order of first deposit
+///
+/// # Errors
+/// Returns an error if the index slots are missing or contain an invalid AccountId
+pub fn get_depositor_faucets(bank_account: &Account, depositor: AccountId) -> Result<Vec<AccountId>> {
+    let counts_slot = bank_slot_name("depositor_faucet_counts")?;
+    let faucets_slot = bank_slot_name("depositor_faucets")?;
+
+    let count_key = Word::from([
+        depositor.prefix().as_felt(),
+        depositor.suffix(),
+        Felt::new(0),
+        Felt::new(0),
+    ]);
+    // A Felt stored in a map reads back as [0, 0, 0, value]
+    let count = bank_account
+        .storage()
+        .get_map_item(&counts_slot, count_key)
+        .context("Failed to read depositor faucet count")?[3]
+        .as_int();
+
+    (0..count)
+        .map(|position| {
+            let entry_key = Word::from([
+                depositor.prefix().as_felt(),
+                depositor.suffix(),
+                Felt::new(position),
+                Felt::new(0),
+            ]);
+            let entry = bank_account
+                .storage()
+                .get_map_item(&faucets_slot, entry_key)
+                .context("Failed to read depositor faucet entry")?;
+            AccountId::try_from([entry[0], entry[1]])
+                .with_context(|| format!("Invalid faucet id at position {}", position))
+        })
+        .collect()
+}
+
+/// Reads every balance a depositor holds in a bank account, one entry per faucet
+///
+/// A `StorageMap` offers no way to iterate its entries, on-chain or through the
+/// account's storage view, so the balances are found through the depositor's faucet
+/// index (see [`get_depositor_faucets`]) and read with [`get_balance_for_asset`].
+///
+/// # Arguments
+/// * `bank_account` - The bank account to read
+/// * `depositor` - The AccountId whose balances to list
+///
+/// # Returns
+/// `(balances map key, balance)` pairs in order of the depositor's first deposit of
+/// each faucet; a faucet stays listed after its balance is fully withdrawn
+///
+/// # Errors
+/// Returns an error if the index or balances slots are missing or malformed
+pub fn get_all_balances(bank_account: &Account, depositor: AccountId) -> Result<Vec<(Word, u64)>> {
+    get_depositor_faucets(bank_account, depositor)?
+        .into_iter()
+        .map(|faucet| {
+            let key = Word::from([
+                depositor.prefix().as_felt(),
+                depositor.suffix(),
+                faucet.prefix().as_felt(),
+                faucet.suffix(),
+            ]);
+            Ok((key, get_balance_for_asset(bank_account, depositor, faucet)?))
+        })
+        .collect()
+}
+
+/// Per-deposit cap compiled into the bank contract (`MAX_DEPOSIT_AMOUNT`),
+/// used when the bank was created without a `max_deposit` override
+pub const MAX_DEPOSIT_AMOUNT: u64 = 1_000_000;
+
+/// Minimum amount of a single deposit, compiled into the bank contract
+/// (`MIN_DEPOSIT_AMOUNT`)
+pub const MIN_DEPOSIT_AMOUNT: u64 = 10;
+
+/// Per-block deposit limit compiled into the bank contract (`MAX_DEPOSIT_PER_BLOCK`),
+/// used when the bank was created without a `max_deposit_per_block` override
+pub const MAX_DEPOSIT_PER_BLOCK: u64 = 10_000_000;
+
+/// Per-depositor balance ceiling compiled into the bank contract (`MAX_TOTAL_BALANCE`),
+/// used when the bank was created without a `max_total_balance` override
+pub const MAX_TOTAL_BALANCE: u64 = (1 << 63) - 1;
+
+/// A bank's configuration, decoded from its config slots by [`read_bank_config`]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BankConfig {
+    /// The only faucet the bank accepts deposits from, if one is configured
+    pub default_faucet: Option<AccountId>,
+    /// Rejected deposits are refunded instead of failing the transaction
+    pub refund_mode: bool,
+    /// In refund mode, over-cap deposits are credited up to the cap and only the excess is refunded
+    pub clamp_over_cap: bool,
+    /// The first deposit initializes the bank
+    pub auto_initialize: bool,
+    /// Minimum blocks between a depositor's first deposit and any withdrawal (0 = disabled)
+    pub min_account_age: u64,
+    /// Configured per-block deposit limit (0 = the compiled `MAX_DEPOSIT_PER_BLOCK`)
+    pub max_deposit_per_block: u64,
+    /// Minimum blocks between two withdrawals by the same depositor (0 = disabled)
+    pub withdraw_cooldown: u64,
+    /// Configured per-depositor balance ceiling (0 = the compiled `MAX_TOTAL_BALANCE`)
+    pub max_total_balance: u64,
+    /// Configured per-deposit cap (0 = the compiled `MAX_DEPOSIT_AMOUNT`)
+    pub max_deposit: u64,
+    /// The account allowed to pause, unpause and transfer the bank, if it has an owner
+    pub owner: Option<AccountId>,
+    /// Fee retained on each withdrawal, in basis points (0 = no fee)
+    pub fee_bps: u64,
+    /// Script root the bank's P2ID output notes use, if one was set at creation
+    /// (`None` = the root compiled into the contract)
+    pub p2id_root: Option<Word>,
+    /// Tag of the indexer that deposit event notes are sent to, if deposit events are
+    /// enabled
+    pub deposit_event_tag: Option<NoteTag>,
+}
+
+impl BankConfig {
+    /// The per-deposit cap the bank enforces, resolving the compiled default
+    pub fn effective_max_deposit(&self) -> u64 {
+        match self.max_deposit {
+            0 => MAX_DEPOSIT_AMOUNT,
+            cap => cap,
+        }
+    }
+
+    /// The per-block deposit limit the bank enforces, resolving the compiled default
+    pub fn effective_max_deposit_per_block(&self) -> u64 {
+        match self.max_deposit_per_block {
+            0 => MAX_DEPOSIT_PER_BLOCK,
+            limit => limit,
+        }
+    }
+
+    /// The per-depositor balance ceiling the bank enforces, resolving the compiled default
+    pub fn effective_max_total_balance(&self) -> u64 {
+        match self.max_total_balance {
+            0 => MAX_TOTAL_BALANCE,
+            ceiling => ceiling,
+        }
+    }
+}
+
+/// Reads a bank value slot, treating a slot the account does not have as all zeros
+///
+/// Banks created before a config slot existed behave as if it was left at zero.
+fn bank_value_or_default(bank_account: &Account, field: &str) -> Result<Word> {
+    let slot = bank_slot_name(field)?;
+    if !bank_account.storage().slots().iter().any(|s| s.name() == &slot) {
+        return Ok(Word::default());
+    }
+    bank_value(bank_account, field)
+}
+
+/// Decodes all of a bank account's config slots into one [`BankConfig`]
+///
+/// Config slots the account does not have (older layouts) decode to their disabled
+/// defaults, matching how the contract treats a zero word.
+///
+/// # Arguments
+/// * `bank_account` - The bank account to read
+///
+/// # Returns
+/// The bank's configuration
+///
+/// # Errors
+/// Returns an error if the default faucet or owner slot holds an invalid AccountId
+pub fn read_bank_config(bank_account: &Account) -> Result<BankConfig> {
+    let default_faucet = bank_value_or_default(bank_account, "default_faucet")?;
+    let refund_mode = bank_value_or_default(bank_account, "refund_mode")?;
+    let auto_initialize = bank_value_or_default(bank_account, "auto_initialize")?;
+    let min_account_age = bank_value_or_default(bank_account, "min_account_age")?;
+    let max_deposit_per_block = bank_value_or_default(bank_account, "max_deposit_per_block")?;
+    let withdraw_cooldown = bank_value_or_default(bank_account, "withdraw_cooldown")?;
+    let max_total_balance = bank_value_or_default(bank_account, "max_total_balance")?;
+    let max_deposit = bank_value_or_default(bank_account, "max_deposit")?;
+    let owner = bank_value_or_default(bank_account, "owner")?;
+    let fee_bps = bank_value_or_default(bank_account, "fee_bps")?;
+    let p2id_root = bank_value_or_default(bank_account, "p2id_root")?;
+    let deposit_events = bank_value_or_default(bank_account, "deposit_events")?;
+
+    // An all-zero default faucet means any faucet is accepted
+    let default_faucet = if default_faucet[0].as_int() == 0 && default_faucet[1].as_int() == 0 {
+        None
+    } else {
+        Some(
+            AccountId::try_from([default_faucet[0], default_faucet[1]])
+                .context("Invalid default faucet id")?,
+        )
+    };
+
+    // An all-zero owner means the bank has no owner
+    let owner = if owner[0].as_int() == 0 && owner[1].as_int() == 0 {
+        None
+    } else {
+        Some(AccountId::try_from([owner[0], owner[1]]).context("Invalid owner id")?)
+    };
+
+    Ok(BankConfig {
+        default_faucet,
+        refund_mode: refund_mode[0].as_int() == 1,
+        clamp_over_cap: refund_mode[1].as_int() == 1,
+        auto_initialize: auto_initialize[0].as_int() == 1,
+        min_account_age: min_account_age[0].as_int(),
+        max_deposit_per_block: max_deposit_per_block[0].as_int(),
+        withdraw_cooldown: withdraw_cooldown[0].as_int(),
+        max_total_balance: max_total_balance[0].as_int(),
+        max_deposit: max_deposit[0].as_int(),
+        owner,
+        fee_bps: fee_bps[0].as_int(),
+        // An all-zero root means the compiled-in P2ID root is used
+        p2id_root: (p2id_root != Word::default()).then_some(p2id_root),
+        deposit_event_tag: (deposit_events[0].as_int() == 1)
+            .then(|| NoteTag::new(deposit_events[1].as_int() as u32)),
+    })
+}
+
+/// Whether a bank is ready to accept a deposit, as reported by [`preflight_bank`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreflightReport {
+    /// The bank's initialized flag is set
+    pub initialized: bool,
+    /// The bank initializes itself on its first deposit
+    pub auto_initialize: bool,
+    /// The bank's pause switch is set, so it rejects every deposit
+    pub paused: bool,
+    /// The requested faucet passes both the default faucet restriction and, once the
+    /// owner has allowed a first faucet, the asset allowlist
+    pub faucet_accepted: bool,
+    /// The depositor is on the bank's blacklist, so it rejects their deposits
+    pub depositor_blacklisted: bool,
+    /// The largest amount a single deposit may carry: the per-deposit cap, further
+    /// limited by what is left of the per-block throttle
+    pub deposit_limit: u64,
+}
+
+impl PreflightReport {
+    /// Returns true if a deposit of `MIN_DEPOSIT_AMOUNT` would pass the bank's checks
+    pub fn is_go(&self) -> bool {
+        (self.initialized || self.auto_initialize)
+            && !self.paused
+            && self.faucet_accepted
+            && !self.depositor_blacklisted
+            && self.deposit_limit >= MIN_DEPOSIT_AMOUNT
+    }
+}
+
+/// Reads a bank value slot by field name
+fn bank_value(bank_account: &Account, field: &str) -> Result<Word> {
+    let slot = bank_slot_name(field)?;
+    bank_account
+        .storage()
+        .get_item(&slot)
+        .with_context(|| format!("Failed to read bank slot `{}`", field))
+}
+
+/// Reads an entry of a bank map slot, treating a slot the account does not have as empty
+fn bank_map_item_or_default(bank_account: &Account, field: &str, key: Word) -> Result<Word> {
+    let slot = bank_slot_name(field)?;
+    if !bank_account.storage().slots().iter().any(|s| s.name() == &slot) {
+        return Ok(Word::default());
+    }
+    bank_account
+        .storage()
+        .get_map_item(&slot, key)
+        .with_context(|| format!("Failed to read bank map `{}`", field))
+}
+
+/// Checks whether a bank account would accept a deposit of a faucet's asset
+///
+/// Mirrors the checks `deposit()` performs, reading the bank's slots directly from
+/// account storage.
+///
+/// # Arguments
+/// * `bank_account` - The bank account to check
+/// * `depositor` - The account that would make the deposit
+/// * `faucet` - The faucet whose asset would be deposited
+/// * `block_num` - The reference block the deposit would execute against
+///
+/// # Returns
+/// A `PreflightReport` describing each check
+///
+/// # Errors
+/// Returns an error if the bank's state slots are missing or its config is invalid
+pub fn preflight_bank_account(
+    bank_account: &Account,
+    depositor: AccountId,
+    faucet: AccountId,
+    block_num: u32,
+) -> Result<PreflightReport> {
+    let config = read_bank_config(bank_account)?;
+    let initialized = bank_value(bank_account, "initialized")?;
+    let block_deposits = bank_value(bank_account, "block_deposits")?;
+    let paused = bank_value_or_default(bank_account, "paused")?;
+    let allowed_asset_count = bank_value_or_default(bank_account, "allowed_asset_count")?;
+
+    // Like `matches_allowlist()`, an empty allowlist accepts every faucet.
+    // A Felt stored in a map reads back as [0, 0, 0, value]
+    let faucet_key = Word::from([
+        faucet.prefix().as_felt(),
+        faucet.suffix(),
+        Felt::new(0),
+        Felt::new(0),
+    ]);
+    let faucet_allowed = allowed_asset_count[0].as_int() == 0
+        || bank_map_item_or_default(bank_account, "allowed_assets", faucet_key)?[3].as_int() == 1;
+    let faucet_accepted = faucet_allowed
+        && config
+            .default_faucet
+            .is_none_or(|default_faucet| default_faucet == faucet);
+
+    let depositor_key = Word::from([
+        depositor.prefix().as_felt(),
+        depositor.suffix(),
+        Felt::new(0),
+        Felt::new(0),
+    ]);
+    let blacklisted = bank_map_item_or_default(bank_account, "blacklist", depositor_key)?;
+
+    // The per-block total restarts once the block advances
+    let used_this_block = if block_deposits[1].as_int() == block_num as u64 {
+        block_deposits[0].as_int()
+    } else {
+        0
+    };
+
+    Ok(PreflightReport {
+        initialized: initialized[0].as_int() == 1,
+        auto_initialize: config.auto_initialize,
+        paused: paused[0].as_int() == 1,
+        faucet_accepted,
+        depositor_blacklisted: blacklisted[3].as_int() == 1,
+        deposit_limit: config
+            .effective_max_deposit()
+            .min(config.effective_max_deposit_per_block().saturating_sub(used_this_block)),
+    })
+}
+
+/// Checks that a bank account is initialized before a deposit is attempted against it
+///
+/// Mirrors the bank's `require_initialized()` on the client side, so a deposit into
+/// an uninitialized bank is refused with a clear message instead of failing deep in
+/// transaction execution. A bank that initializes itself on its first deposit passes.
+///
+/// # Arguments
+/// * `bank_account` - The bank account to check
+///
+/// # Errors
+/// Returns an error if the bank is neither initialized nor set to auto-initialize, or
+/// if its `initialized` slot is missing
+pub fn ensure_bank_initialized(bank_account: &Account) -> Result<()> {
+    let initialized = bank_value(bank_account, "initialized")?;
+    if initialized[0].as_int() == 1 || read_bank_config(bank_account)?.auto_initialize {
+        return Ok(());
+    }
+
+    bail!(
+        "Bank account {} is not initialized.\n\
+         Run 'cargo run --bin initialize' first.",
+        bank_account.id().to_hex()
+    );
+}
+
+/// Checks whether a bank tracked by the client would accept a deposit of a faucet's asset
+///
+/// # Arguments
+/// * `client` - The client tracking the bank account
+/// * `bank_id` - The bank account to check
+/// * `depositor` - The account that would make the deposit
+/// * `faucet` - The faucet whose asset would be deposited
+///
+/// # Returns
+/// A `PreflightReport` for the client's latest synced state of the bank
+///
+/// # Errors
+/// Returns an error if the client does not track the full bank account state
+pub async fn preflight_bank(
+    client: &mut Client<FilesystemKeyStore>,
+    bank_id: AccountId,
+    depositor: AccountId,
+    faucet: AccountId,
+) -> Result<PreflightReport> {
+    let record = client
+        .get_account(bank_id)
+        .await
+        .context("Failed to fetch bank account")?
+        .with_context(|| format!("Bank account {} not found in client", bank_id.to_hex()))?;
+    let bank_account = Account::try_from(record).context("Bank account state is not fully known")?;
+    let block_num = client
+        .get_sync_height()
+        .await
+        .context("Failed to read sync height")?;
+
+    preflight_bank_account(&bank_account, depositor, faucet, block_num.as_u32())
+}
+
+/// Asserts that a bank account has exactly the expected storage slots of the expected kinds
+///
+/// # Arguments
+/// * `account` - The deployed bank account to check
+///
+/// # Panics
+/// Panics if a bank slot is missing, has the wrong kind (Value vs Map), or if the
+/// account has bank slots that are not part of `BANK_STORAGE_SLOTS`
+pub fn assert_bank_slots(account: &Account) {
+    let bank_slots: Vec<&StorageSlot> = account
+        .storage()
+        .slots()
+        .iter()
+        .filter(|slot| slot.name().as_str().starts_with(BANK_SLOT_NAMESPACE))
+        .collect();
+
+    for (field, expected_type) in BANK_STORAGE_SLOTS {
+        let name = format!("{}{}", BANK_SLOT_NAMESPACE, field);
+        let slot = bank_slots
+            .iter()
+            .find(|slot| slot.name().as_str() == name)
+            .unwrap_or_else(|| panic!("Bank account is missing storage slot `{}`", name));
+
+        assert_eq!(
+            slot.slot_type(),
+            *expected_type,
+            "Bank storage slot `{}` has the wrong kind",
+            name
+        );
+    }
+
+    assert_eq!(
+        bank_slots.len(),
+        BANK_STORAGE_SLOTS.len(),
+        "Bank account has {} storage slots, expected {}: {:?}",
+        bank_slots.len(),
+        BANK_STORAGE_SLOTS.len(),
+        bank_slots.iter().map(|slot| slot.name().as_str()).collect::<Vec<_>>()
+    );
+}
+
+/// A bank created and initialized on a fresh mock chain by [`create_initialized_bank`]
+pub struct InitializedBank {
+    /// The bank account with the init transaction applied
+    pub bank_account: Account,
+    /// The mock chain, with the init transaction proven in its latest block
+    pub mock_chain: MockChain,
+    /// The delta of the init transaction
+    pub init_delta: AccountDelta,
+}
+
+/// Creates the bank account, builds the mock chain and initializes the bank
+///
+/// Replaces the create / add / build / init boilerplate of a test: the account is
+/// created from the bank contract with `bank_cfg`, its slots are checked with
+/// [`assert_bank_slots`], it is added to `builder`, and the chain is built. The
+/// init transaction script then runs with an all-zero argument (no default faucet,
+/// no owner); its delta is applied to the account and proven in a new block, so the
+/// returned account and chain are in sync.
+///
+/// Notes and other accounts the test needs must be added to `builder` first.
+///
+/// # Arguments
+/// * `builder` - The mock chain builder, with the test's other accounts and notes added
+/// * `bank_cfg` - The bank's account configuration, including its storage slots
+///
+/// # Returns
+/// The initialized bank account, the mock chain and the init transaction's delta
+///
+/// # Errors
+/// Returns an error if a contract fails to build, the chain cannot be built, or the
+/// init transaction fails
+pub async fn create_initialized_bank(
+    mut builder: MockChainBuilder,
+    bank_cfg: AccountCreationConfig,
+) -> Result<InitializedBank> {
+    let bank_package = build_project_cached(Path::new("../contracts/bank-account"), true)?;
+    let init_tx_script_package =
+        build_project_cached(Path::new("../contracts/init-tx-script"), true)?;
+
+    let mut bank_account = create_testing_account_from_package(bank_package, bank_cfg).await?;
+    assert_bank_slots(&bank_account);
+    builder.add_account(bank_account.clone())?;
+
+    let mut mock_chain = builder.build()?;
+
+    let init_program = init_tx_script_package.unwrap_program();
+    let init_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[], &[])?
+        .tx_script(TransactionScript::new((*init_program).clone()))
+        .build()?;
+
+    let executed_init = init_tx_context
+        .execute()
+        .await
+        .context("Failed to initialize the bank")?;
+    bank_account.apply_delta(executed_init.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_init)?;
+    mock_chain.prove_next_block()?;
+
+    Ok(InitializedBank {
+        bank_account,
+        mock_chain,
+        init_delta: executed_init.account_delta().clone(),
+    })
+}
+
+/// Builds a [`BankFixture`]
+///
+/// By default the faucet's supply is 1000 tokens, all held by the depositor wallet,
+/// and the bank is initialized with every storage slot at its default.
+pub struct BankFixtureBuilder {
+    faucet_supply: u64,
+    initialize: bool,
+}
+
+impl Default for BankFixtureBuilder {
+    fn default() -> Self {
+        Self {
+            faucet_supply: 1000,
+            initialize: true,
+        }
+    }
+}
+
+impl BankFixtureBuilder {
+    /// Sets the faucet's maximum supply, all of which the depositor wallet holds
+    pub fn faucet_supply(mut self, faucet_supply: u64) -> Self {
+        self.faucet_supply = faucet_supply;
+        self
+    }
+
+    /// Leaves the bank uninitialized, for tests of what it rejects before initialization
+    pub fn uninitialized(mut self) -> Self {
+        self.initialize = false;
+        self
+    }
+
+    /// Builds the contracts, deploys the bank on a fresh mock chain and, unless
+    /// [`Self::uninitialized`] was called, runs the init transaction script
+    ///
+    /// # Errors
+    /// Returns an error if a contract fails to build or the init transaction fails
+    pub async fn build(self) -> Result<BankFixture> {
+        let mut builder = MockChain::builder();
+
+        let faucet =
+            builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", self.faucet_supply, Some(10))?;
+        let sender = builder.add_existing_wallet_with_assets(
+            Auth::BasicAuth,
+            [FungibleAsset::new(faucet.id(), self.faucet_supply)?.into()],
+        )?;
+
+        // Build contracts
+        let bank_package = build_project_cached(Path::new("../contracts/bank-account"), true)?;
+        let deposit_note_package =
+            build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+        let withdraw_request_note_package =
+            build_project_cached(Path::new("../contracts/withdraw-request-note"), true)?;
+
+        // Create the bank account with every slot at its default
+        let bank_cfg = AccountCreationConfig::bank_default()?;
+
+        let (bank_account, mock_chain) = if self.initialize {
+            let InitializedBank {
+                bank_account,
+                mock_chain,
+                ..
+            } = create_initialized_bank(builder, bank_cfg).await?;
+            (bank_account, mock_chain)
+        } else {
+            let bank_account = create_testing_account_from_package(bank_package, bank_cfg).await?;
+            assert_bank_slots(&bank_account);
+            builder.add_account(bank_account.clone())?;
+            (bank_account, builder.build()?)
+        };
+
+        Ok(BankFixture {
+            bank_account,
+            mock_chain,
+            faucet,
+            sender,
+            deposit_note_package,
+            withdraw_request_note_package,
+            notes_created: 0,
+        })
+    }
+}
+
+/// A bank deployed on a mock chain together with a faucet and a funded depositor
+///
+/// Tests start from this known good state instead of building contracts, creating the
+/// bank and running the init transaction by hand. The depositor publishes each note on
+/// chain before the bank consumes it, so every transaction can be proven in a block.
+pub struct BankFixture {
+    pub bank_account: Account,
+    pub mock_chain: MockChain,
+    pub faucet: Account,
+    pub sender: Account,
+    deposit_note_package: Arc<Package>,
+    withdraw_request_note_package: Arc<Package>,
+    notes_created: u64,
+}
+
+impl BankFixture {
+    /// Starts building a fixture with the default settings
+    pub fn builder() -> BankFixtureBuilder {
+        BankFixtureBuilder::default()
+    }
+
+    /// Creates a deposit note from the depositor carrying `amount` of the faucet's asset
+    ///
+    /// # Errors
+    /// Returns an error if the asset or note cannot be created
+    pub fn deposit_note(&mut self, amount: u64) -> Result<Note> {
+        let assets = NoteAssets::new(vec![FungibleAsset::new(self.faucet.id(), amount)?.into()])?;
+        self.create_note(
+            self.deposit_note_package.clone(),
+            NoteCreationConfig {
+                assets,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Creates a withdraw request note from the depositor for `amount` of the faucet's asset
+    ///
+    /// # Returns
+    /// The withdraw request note and the P2ID note the bank is expected to pay out for it
+    ///
+    /// # Errors
+    /// Returns an error if either note cannot be created
+    pub fn withdraw_request_note(&mut self, amount: u64) -> Result<(Note, Note)> {
+        let p2id_tag = p2id_tag_for_account(self.sender.id());
+        let p2id_serial_num = self.next_serial_num();
+
+        let withdraw_asset = FungibleAsset::new(self.faucet.id(), amount)?;
+
+        let withdraw_request_note = self.create_note(
+            self.withdraw_request_note_package.clone(),
+            NoteCreationConfig {
+                inputs: build_withdraw_note_inputs(
+                    withdraw_asset,
+                    p2id_serial_num,
+                    p2id_tag,
+                    NoteType::Public,
+                ),
+                ..Default::default()
+            },
+        )?;
+        let p2id_note = Note::new(
+            NoteAssets::new(vec![withdraw_asset.into()])?,
+            NoteMetadata::new(self.bank_account.id(), NoteType::Public, p2id_tag),
+            build_p2id_recipient(self.sender.id(), p2id_serial_num)?,
+        );
+
+        Ok((withdraw_request_note, p2id_note))
+    }
+
+    /// Has the depositor publish the given notes on chain
+    ///
+    /// The depositor sends the notes with the standard send-notes script, moving any
+    /// assets they carry out of its vault, and the transaction is proven in a new block.
+    ///
+    /// # Errors
+    /// Returns an error if the send script cannot be built or the transaction fails
+    pub async fn publish(&mut self, notes: &[Note]) -> Result<()> {
+        let partial_notes: Vec<PartialNote> = notes.iter().cloned().map(PartialNote::from).collect();
+        let send_notes_script = AccountInterface::from_account(&self.sender)
+            .build_send_notes_script(&partial_notes, None)
+            .context("Failed to build send notes script")?;
+
+        let tx_context = self
+            .mock_chain
+            .build_tx_context(self.sender.id(), &[], &[])?
+            .tx_script(send_notes_script)
+            .extend_expected_output_notes(notes.iter().cloned().map(OutputNote::Full).collect())
+            .build()?;
+
+        let executed = tx_context.execute().await.context("Failed to publish notes")?;
+        self.sender.apply_delta(executed.account_delta())?;
+        self.mock_chain.add_pending_executed_transaction(&executed)?;
+        self.mock_chain.prove_next_block()?;
+
+        Ok(())
+    }
+
+    /// Has the bank consume the given published notes, in order, in a single transaction
+    ///
+    /// On success the transaction is applied to the bank and proven in a new block; on
+    /// failure the fixture is left unchanged and the notes can be consumed again.
+    ///
+    /// # Arguments
+    /// * `notes` - The notes to consume, in execution order; see [`Self::publish`]
+    /// * `expected_output_notes` - The notes the transaction must create (e.g. P2ID payouts)
+    ///
+    /// # Errors
+    /// Returns an error if the transaction fails to execute
+    pub async fn consume(
+        &mut self,
+        notes: &[Note],
+        expected_output_notes: Vec<Note>,
+    ) -> Result<ExecutedTransaction> {
+        let note_ids: Vec<NoteId> = notes.iter().map(Note::id).collect();
+        let tx_context = self
+            .mock_chain
+            .build_tx_context(self.bank_account.id(), &note_ids, &[])?
+            .extend_expected_output_notes(expected_output_notes.into_iter().map(OutputNote::Full).collect())
+            .build()?;
+
+        let executed = tx_context.execute().await?;
+        self.bank_account.apply_delta(executed.account_delta())?;
+        self.mock_chain.add_pending_executed_transaction(&executed)?;
+        self.mock_chain.prove_next_block()?;
+
+        Ok(executed)
+    }
+
+    /// Publishes a deposit note carrying `amount` of the faucet's asset and has the bank
+    /// consume it
+    ///
+    /// # Errors
+    /// Returns an error if the depositor cannot send the note or the bank rejects the deposit
+    pub async fn deposit(&mut self, amount: u64) -> Result<ExecutedTransaction> {
+        let deposit_note = self.deposit_note(amount)?;
+        self.publish(std::slice::from_ref(&deposit_note)).await?;
+        self.consume(&[deposit_note], vec![]).await
+    }
+
+    /// Publishes a withdraw request note for `amount` of the faucet's asset and has the
+    /// bank consume it, checking the P2ID payout
+    ///
+    /// # Errors
+    /// Returns an error if the depositor cannot send the note or the bank rejects the withdrawal
+    pub async fn withdraw(&mut self, amount: u64) -> Result<ExecutedTransaction> {
+        let (withdraw_request_note, p2id_note) = self.withdraw_request_note(amount)?;
+        self.publish(std::slice::from_ref(&withdraw_request_note)).await?;
+        self.consume(&[withdraw_request_note], vec![p2id_note]).await
+    }
+
+    /// Reads a depositor's balance in the fixture's faucet
+    ///
+    /// # Errors
+    /// Returns an error if the balances slot is missing
+    pub fn balance_of(&self, depositor: AccountId) -> Result<u64> {
+        get_balance_for_asset(&self.bank_account, depositor, self.faucet.id())
+    }
+
+    /// Creates a note from the depositor with a serial number unique within the fixture,
+    /// so notes with the same script, inputs and assets do not share a nullifier
+    fn create_note(&mut self, package: Arc<Package>, config: NoteCreationConfig) -> Result<Note> {
+        let note_program = package.unwrap_program();
+        let note_script = NoteScript::from_parts(
+            note_program.mast_forest().clone(),
+            note_program.entrypoint(),
+        );
+
+        let note_inputs = NoteInputs::new(config.inputs).context("Failed to create note inputs")?;
+        let recipient = NoteRecipient::new(self.next_serial_num(), note_script, note_inputs);
+        let metadata = NoteMetadata::new(self.sender.id(), config.note_type, config.tag);
+
+        Ok(Note::new(config.assets, metadata, recipient))
+    }
+
+    /// The next serial number in the fixture's sequence
+    fn next_serial_num(&mut self) -> Word {
+        self.notes_created += 1;
+        Word::from([Felt::new(self.notes_created), Felt::new(0), Felt::new(0), Felt::new(0)])
+    }
+}
@@ -0,0 +1,709 @@
+//! Declarative MockChain scenarios for bank tests
+//!
+//! A [`Scenario`] lists the faucets, the depositors and their starting assets, any
+//! bank configuration, and the operations to run. [`run_scenario`] builds the mock
+//! chain, deploys and initializes the bank, executes each operation in its own
+//! transaction and returns the final balances.
+//!
+//! Scenarios are usually put together with [`Scenario::builder`], which adds faucets
+//! and depositors in index order.
+
+use std::{collections::BTreeMap, fmt, path::Path, sync::Arc};
+
+use anyhow::{Context, Result};
+use miden_client::{
+    account::{Account, AccountId},
+    asset::FungibleAsset,
+    note::{
+        build_p2id_recipient, Note, NoteAssets, NoteInputs, NoteMetadata, NoteRecipient,
+        NoteScript, NoteTag, NoteType,
+    },
+    transaction::{OutputNote, TransactionScript},
+    Felt, Word,
+};
+use miden_mast_package::Package;
+use miden_testing::{Auth, MockChain};
+
+use crate::helpers::{
+    bank_storage_slots, build_project_cached, create_testing_account_from_package,
+    get_balance_for_asset, p2id_tag_for_account, AccountCreationConfig,
+};
+
+/// A faucet to create on the mock chain
+pub struct FaucetSpec {
+    pub symbol: &'static str,
+    pub max_supply: u64,
+}
+
+/// A depositor wallet and the assets it starts with, as (faucet index, amount)
+pub struct DepositorSpec {
+    pub assets: Vec<(usize, u64)>,
+}
+
+/// A single bank operation; depositors and faucets are referenced by index
+pub enum Operation {
+    /// The depositor sends a deposit note carrying `amount` of the faucet's asset
+    Deposit {
+        depositor: usize,
+        faucet: usize,
+        amount: u64,
+    },
+    /// The depositor sends a deposit note carrying `amount` of the faucet's asset that
+    /// names `beneficiary` as the account to credit
+    DepositFor {
+        depositor: usize,
+        beneficiary: usize,
+        faucet: usize,
+        amount: u64,
+    },
+    /// The depositor sends a withdraw request note for `amount` of the faucet's asset
+    Withdraw {
+        depositor: usize,
+        faucet: usize,
+        amount: u64,
+    },
+    /// The depositor `from` sends a transfer note moving `amount` of their balance in
+    /// the faucet to the depositor `to`
+    Transfer {
+        from: usize,
+        to: usize,
+        faucet: usize,
+        amount: u64,
+    },
+    /// The depositor `from` sends a migrate note moving their whole balance in the
+    /// faucet to the depositor `to`
+    Migrate { from: usize, to: usize, faucet: usize },
+    /// The depositor `sender` sends an admin note moving the depositor `from`'s whole
+    /// balance in the faucet to the depositor `to`
+    AdminMigrate {
+        sender: usize,
+        from: usize,
+        to: usize,
+        faucet: usize,
+    },
+    /// The depositor sends an admin note pausing the bank
+    Pause { sender: usize },
+    /// The depositor sends an admin note unpausing the bank
+    Unpause { sender: usize },
+    /// The depositor sends an admin note handing the bank's ownership to `new_owner`
+    TransferOwnership { sender: usize, new_owner: usize },
+    /// The depositor sends an admin note capping `depositor`'s withdrawals from the
+    /// faucet at `limit` per window
+    SetWithdrawLimit {
+        sender: usize,
+        depositor: usize,
+        faucet: usize,
+        limit: u64,
+    },
+    /// The depositor sends an admin note closing the bank
+    Close { sender: usize },
+    /// The depositor sends an admin note adding the depositor `account` to the
+    /// blacklist, or removing it
+    SetBlacklisted {
+        sender: usize,
+        account: usize,
+        blacklisted: bool,
+    },
+    /// The depositor sends an admin note adding the faucet to the bank's asset allowlist
+    AllowAsset { sender: usize, faucet: usize },
+    /// The depositor sends an admin note sweeping the fees retained in the faucet to
+    /// itself; `amount` is the fee total the P2ID payout is expected to carry
+    SweepFees {
+        sender: usize,
+        faucet: usize,
+        amount: u64,
+    },
+}
+
+/// A complete bank test scenario
+#[derive(Default)]
+pub struct Scenario {
+    pub faucets: Vec<FaucetSpec>,
+    pub depositors: Vec<DepositorSpec>,
+    /// Initial values for bank value slots, as (slot field name, value); other slots start empty
+    pub bank_config: Vec<(&'static str, Word)>,
+    /// The depositor the bank is initialized with as its owner; `None` leaves it ownerless
+    pub owner: Option<usize>,
+    pub operations: Vec<Operation>,
+}
+
+/// The state after a scenario ran
+pub struct ScenarioResult {
+    pub bank_account: Account,
+    pub faucet_ids: Vec<AccountId>,
+    pub depositor_ids: Vec<AccountId>,
+    /// Final bank balance per (depositor index, faucet index)
+    pub balances: BTreeMap<(usize, usize), u64>,
+}
+
+impl ScenarioResult {
+    /// Returns the final bank balance of a depositor for a faucet
+    pub fn balance(&self, depositor: usize, faucet: usize) -> u64 {
+        self.balances
+            .get(&(depositor, faucet))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// An operation whose transaction the bank rejected, returned by [`run_scenario`]
+///
+/// A rejected transaction changes nothing, so `state` is also the state the scenario
+/// ends in. Tests can check it to confirm why the operation was rejected, e.g. that
+/// the bank was paused or the balance was too small.
+pub struct ScenarioFailure {
+    /// Index of the failed operation in `Scenario::operations`
+    pub operation: usize,
+    /// The transaction execution error
+    pub error: anyhow::Error,
+    /// The bank and balances the failed operation ran against
+    pub state: ScenarioResult,
+}
+
+impl fmt::Display for ScenarioFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Scenario operation {} failed: {:#}", self.operation, self.error)
+    }
+}
+
+impl fmt::Debug for ScenarioFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScenarioFailure")
+            .field("operation", &self.operation)
+            .field("error", &self.error)
+            .field("balances", &self.state.balances)
+            .finish_non_exhaustive()
+    }
+}
+
+impl std::error::Error for ScenarioFailure {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.error.as_ref())
+    }
+}
+
+impl Scenario {
+    /// Starts building a scenario with no faucets, depositors, config or owner
+    pub fn builder() -> ScenarioBuilder {
+        ScenarioBuilder::default()
+    }
+}
+
+/// Builds a [`Scenario`] one faucet, depositor and config value at a time
+///
+/// Faucets and depositors are indexed in the order they are added, so the first
+/// faucet is faucet 0 and the first depositor is depositor 0.
+#[derive(Default)]
+pub struct ScenarioBuilder {
+    scenario: Scenario,
+}
+
+impl ScenarioBuilder {
+    /// Adds a faucet with the given symbol and maximum supply
+    pub fn faucet(mut self, symbol: &'static str, max_supply: u64) -> Self {
+        self.scenario.faucets.push(FaucetSpec { symbol, max_supply });
+        self
+    }
+
+    /// Adds a depositor starting with the given assets, as (faucet index, amount)
+    pub fn depositor(mut self, assets: &[(usize, u64)]) -> Self {
+        self.scenario.depositors.push(DepositorSpec {
+            assets: assets.to_vec(),
+        });
+        self
+    }
+
+    /// Initializes the bank with the depositor at `depositor` as its owner
+    pub fn owner(mut self, depositor: usize) -> Self {
+        self.scenario.owner = Some(depositor);
+        self
+    }
+
+    /// Sets the first element of a bank value slot, leaving the others zero
+    pub fn config(mut self, field: &'static str, value: u64) -> Self {
+        self.scenario.bank_config.push((
+            field,
+            Word::from([Felt::new(value), Felt::new(0), Felt::new(0), Felt::new(0)]),
+        ));
+        self
+    }
+
+    /// Finishes the scenario with the operations to run
+    pub fn operations(mut self, operations: Vec<Operation>) -> Scenario {
+        self.scenario.operations = operations;
+        self.scenario
+    }
+}
+
+/// A single depositor, who also owns the bank, holding 1000 tokens of one faucet and
+/// running the given operations
+pub fn single_depositor_scenario(operations: Vec<Operation>) -> Scenario {
+    Scenario::builder()
+        .faucet("TEST", 1000)
+        .depositor(&[(0, 1000)])
+        .owner(0)
+        .operations(operations)
+}
+
+/// Builds a note from a compiled package with a caller-chosen serial number, so a
+/// scenario can contain several otherwise identical notes
+fn scenario_note(
+    package: &Arc<Package>,
+    sender_id: AccountId,
+    serial_num: Word,
+    assets: NoteAssets,
+    inputs: Vec<Felt>,
+) -> Result<Note> {
+    let note_program = package.unwrap_program();
+    let note_script = NoteScript::from_parts(
+        note_program.mast_forest().clone(),
+        note_program.entrypoint(),
+    );
+
+    let note_inputs = NoteInputs::new(inputs).context("Failed to create note inputs")?;
+    let recipient = NoteRecipient::new(serial_num, note_script, note_inputs);
+    let metadata = NoteMetadata::new(sender_id, NoteType::Public, NoteTag::new(0));
+
+    Ok(Note::new(assets, metadata, recipient))
+}
+
+/// Builds an admin note from `sender` calling one of the bank's privileged methods
+///
+/// Note inputs layout: [action, params...], where action is 1 = pause, 2 = unpause,
+/// 3 = transfer ownership (params: new owner prefix, suffix), 4 = set a withdraw
+/// limit (params: depositor prefix, suffix, faucet prefix, suffix, limit), 5 = close
+/// 6 = set the blacklist flag (params: account prefix, suffix, flag), 7 = allow an
+/// asset (params: faucet prefix, suffix), 8 = sweep fees (params: faucet prefix,
+/// suffix, P2ID serial_num, tag, note_type) and 9 = migrate a depositor (params: old
+/// depositor prefix, suffix, new depositor prefix, suffix, faucet prefix, suffix).
+fn admin_note(
+    package: &Arc<Package>,
+    sender: AccountId,
+    serial_num: Word,
+    action: u64,
+    params: Vec<Felt>,
+) -> Result<Note> {
+    let mut inputs = vec![Felt::new(action)];
+    inputs.extend(params);
+    scenario_note(package, sender, serial_num, NoteAssets::default(), inputs)
+}
+
+/// Runs a scenario on a fresh mock chain
+///
+/// The bank is deployed with every slot from `BANK_STORAGE_SLOTS` (value slots taken
+/// from `bank_config` or zero, maps empty) and initialized via the init transaction
+/// script. Each operation then runs in its own transaction, withdrawals are paid out
+/// to the depositor as P2ID notes, transfers and migrations are sent as transfer and
+/// migrate notes, and admin operations are sent as admin notes. Swept fees are paid out
+/// to the sender as P2ID notes.
+///
+/// # Arguments
+/// * `scenario` - The scenario to run
+///
+/// # Returns
+/// The final bank account and balances for every depositor and faucet
+///
+/// # Errors
+/// Returns an error if the scenario references an unknown slot, depositor or faucet,
+/// or if any contract build or transaction fails. If an operation's transaction fails,
+/// the error is a [`ScenarioFailure`] carrying the execution error and the state the
+/// operation ran against.
+pub async fn run_scenario(scenario: Scenario) -> Result<ScenarioResult> {
+    let mut builder = MockChain::builder();
+
+    let faucet_ids = scenario
+        .faucets
+        .iter()
+        .map(|faucet| {
+            builder
+                .add_existing_basic_faucet(Auth::BasicAuth, faucet.symbol, faucet.max_supply, Some(10))
+                .map(|account| account.id())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let faucet_id = |index: usize| {
+        faucet_ids
+            .get(index)
+            .copied()
+            .with_context(|| format!("Unknown faucet {}", index))
+    };
+
+    let mut depositor_ids = Vec::new();
+    for depositor in &scenario.depositors {
+        let assets = depositor
+            .assets
+            .iter()
+            .map(|&(faucet, amount)| Ok(FungibleAsset::new(faucet_id(faucet)?, amount)?.into()))
+            .collect::<Result<Vec<_>>>()?;
+        depositor_ids.push(
+            builder
+                .add_existing_wallet_with_assets(Auth::BasicAuth, assets)?
+                .id(),
+        );
+    }
+    let depositor_id = |index: usize| {
+        depositor_ids
+            .get(index)
+            .copied()
+            .with_context(|| format!("Unknown depositor {}", index))
+    };
+
+    // Build contracts
+    let bank_package = build_project_cached(Path::new("../contracts/bank-account"), true)?;
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+    let init_tx_script_package =
+        build_project_cached(Path::new("../contracts/init-tx-script"), true)?;
+    let withdraw_request_note_package =
+        build_project_cached(Path::new("../contracts/withdraw-request-note"), true)?;
+    let admin_note_package = build_project_cached(Path::new("../contracts/admin-note"), true)?;
+    let transfer_note_package =
+        build_project_cached(Path::new("../contracts/transfer-note"), true)?;
+    let migrate_note_package = build_project_cached(Path::new("../contracts/migrate-note"), true)?;
+
+    // Create the bank account with the full storage layout
+    let storage_slots = bank_storage_slots(&scenario.bank_config)?;
+
+    let mut bank_account = create_testing_account_from_package(
+        bank_package.clone(),
+        AccountCreationConfig {
+            storage_slots,
+            ..Default::default()
+        },
+    )
+    .await?;
+    builder.add_account(bank_account.clone())?;
+
+    // Craft every operation's note up front; notes must exist before the chain is built
+    let mut steps = Vec::new();
+    for (index, operation) in scenario.operations.iter().enumerate() {
+        // Unique per operation so repeated identical operations get distinct notes
+        let serial_num = Word::from([Felt::new(index as u64 + 1), Felt::new(0), Felt::new(0), Felt::new(0)]);
+
+        let step = match *operation {
+            Operation::Deposit {
+                depositor,
+                faucet,
+                amount,
+            } => {
+                let note = scenario_note(
+                    &deposit_note_package,
+                    depositor_id(depositor)?,
+                    serial_num,
+                    NoteAssets::new(vec![FungibleAsset::new(faucet_id(faucet)?, amount)?.into()])?,
+                    vec![],
+                )?;
+                (note, None)
+            }
+            Operation::DepositFor {
+                depositor,
+                beneficiary,
+                faucet,
+                amount,
+            } => {
+                let beneficiary = depositor_id(beneficiary)?;
+
+                // Note inputs layout (2 Felts): [beneficiary (prefix, suffix)]
+                let note = scenario_note(
+                    &deposit_note_package,
+                    depositor_id(depositor)?,
+                    serial_num,
+                    NoteAssets::new(vec![FungibleAsset::new(faucet_id(faucet)?, amount)?.into()])?,
+                    vec![beneficiary.prefix().as_felt(), beneficiary.suffix()],
+                )?;
+                (note, None)
+            }
+            Operation::Withdraw {
+                depositor,
+                faucet,
+                amount,
+            } => {
+                let depositor = depositor_id(depositor)?;
+                let faucet = faucet_id(faucet)?;
+                let p2id_tag = p2id_tag_for_account(depositor);
+
+                // Note inputs layout (10 Felts):
+                // [0-3]: withdraw asset (amount, 0, faucet_suffix, faucet_prefix)
+                // [4-7]: serial_num of the P2ID output note
+                // [8]: tag
+                // [9]: note_type (1 = Public)
+                let note = scenario_note(
+                    &withdraw_request_note_package,
+                    depositor,
+                    serial_num,
+                    NoteAssets::default(),
+                    vec![
+                        Felt::new(amount),
+                        Felt::new(0),
+                        faucet.suffix(),
+                        faucet.prefix().as_felt(),
+                        serial_num[0],
+                        serial_num[1],
+                        serial_num[2],
+                        serial_num[3],
+                        Felt::new(p2id_tag.as_u32() as u64),
+                        Felt::new(1),
+                    ],
+                )?;
+
+                let p2id_output_note = Note::new(
+                    NoteAssets::new(vec![FungibleAsset::new(faucet, amount)?.into()])?,
+                    NoteMetadata::new(bank_account.id(), NoteType::Public, p2id_tag),
+                    build_p2id_recipient(depositor, serial_num)?,
+                );
+                (note, Some(p2id_output_note))
+            }
+            Operation::Transfer {
+                from,
+                to,
+                faucet,
+                amount,
+            } => {
+                let to = depositor_id(to)?;
+                let faucet = faucet_id(faucet)?;
+
+                // Note inputs layout (5 Felts): [to (prefix, suffix), faucet (prefix, suffix), amount]
+                let note = scenario_note(
+                    &transfer_note_package,
+                    depositor_id(from)?,
+                    serial_num,
+                    NoteAssets::default(),
+                    vec![
+                        to.prefix().as_felt(),
+                        to.suffix(),
+                        faucet.prefix().as_felt(),
+                        faucet.suffix(),
+                        Felt::new(amount),
+                    ],
+                )?;
+                (note, None)
+            }
+            Operation::Migrate { from, to, faucet } => {
+                let to = depositor_id(to)?;
+                let faucet = faucet_id(faucet)?;
+
+                // Note inputs layout (4 Felts): [to (prefix, suffix), faucet (prefix, suffix)]
+                let note = scenario_note(
+                    &migrate_note_package,
+                    depositor_id(from)?,
+                    serial_num,
+                    NoteAssets::default(),
+                    vec![
+                        to.prefix().as_felt(),
+                        to.suffix(),
+                        faucet.prefix().as_felt(),
+                        faucet.suffix(),
+                    ],
+                )?;
+                (note, None)
+            }
+            Operation::AdminMigrate {
+                sender,
+                from,
+                to,
+                faucet,
+            } => {
+                let from = depositor_id(from)?;
+                let to = depositor_id(to)?;
+                let faucet = faucet_id(faucet)?;
+                let note = admin_note(
+                    &admin_note_package,
+                    depositor_id(sender)?,
+                    serial_num,
+                    9,
+                    vec![
+                        from.prefix().as_felt(),
+                        from.suffix(),
+                        to.prefix().as_felt(),
+                        to.suffix(),
+                        faucet.prefix().as_felt(),
+                        faucet.suffix(),
+                    ],
+                )?;
+                (note, None)
+            }
+            Operation::Pause { sender } => {
+                let note = admin_note(&admin_note_package, depositor_id(sender)?, serial_num, 1, vec![])?;
+                (note, None)
+            }
+            Operation::Unpause { sender } => {
+                let note = admin_note(&admin_note_package, depositor_id(sender)?, serial_num, 2, vec![])?;
+                (note, None)
+            }
+            Operation::TransferOwnership { sender, new_owner } => {
+                let new_owner = depositor_id(new_owner)?;
+                let note = admin_note(
+                    &admin_note_package,
+                    depositor_id(sender)?,
+                    serial_num,
+                    3,
+                    vec![new_owner.prefix().as_felt(), new_owner.suffix()],
+                )?;
+                (note, None)
+            }
+            Operation::SetWithdrawLimit {
+                sender,
+                depositor,
+                faucet,
+                limit,
+            } => {
+                let depositor = depositor_id(depositor)?;
+                let faucet = faucet_id(faucet)?;
+                let note = admin_note(
+                    &admin_note_package,
+                    depositor_id(sender)?,
+                    serial_num,
+                    4,
+                    vec![
+                        depositor.prefix().as_felt(),
+                        depositor.suffix(),
+                        faucet.prefix().as_felt(),
+                        faucet.suffix(),
+                        Felt::new(limit),
+                    ],
+                )?;
+                (note, None)
+            }
+            Operation::Close { sender } => {
+                let note = admin_note(&admin_note_package, depositor_id(sender)?, serial_num, 5, vec![])?;
+                (note, None)
+            }
+            Operation::SetBlacklisted {
+                sender,
+                account,
+                blacklisted,
+            } => {
+                let account = depositor_id(account)?;
+                let note = admin_note(
+                    &admin_note_package,
+                    depositor_id(sender)?,
+                    serial_num,
+                    6,
+                    vec![
+                        account.prefix().as_felt(),
+                        account.suffix(),
+                        Felt::new(blacklisted as u64),
+                    ],
+                )?;
+                (note, None)
+            }
+            Operation::AllowAsset { sender, faucet } => {
+                let faucet = faucet_id(faucet)?;
+                let note = admin_note(
+                    &admin_note_package,
+                    depositor_id(sender)?,
+                    serial_num,
+                    7,
+                    vec![faucet.prefix().as_felt(), faucet.suffix()],
+                )?;
+                (note, None)
+            }
+            Operation::SweepFees {
+                sender,
+                faucet,
+                amount,
+            } => {
+                let sender = depositor_id(sender)?;
+                let faucet = faucet_id(faucet)?;
+                let p2id_tag = p2id_tag_for_account(sender);
+                let note = admin_note(
+                    &admin_note_package,
+                    sender,
+                    serial_num,
+                    8,
+                    vec![
+                        faucet.prefix().as_felt(),
+                        faucet.suffix(),
+                        serial_num[0],
+                        serial_num[1],
+                        serial_num[2],
+                        serial_num[3],
+                        Felt::new(p2id_tag.as_u32() as u64),
+                        Felt::new(1),
+                    ],
+                )?;
+
+                let p2id_output_note = Note::new(
+                    NoteAssets::new(vec![FungibleAsset::new(faucet, amount)?.into()])?,
+                    NoteMetadata::new(bank_account.id(), NoteType::Public, p2id_tag),
+                    build_p2id_recipient(sender, serial_num)?,
+                );
+                (note, Some(p2id_output_note))
+            }
+        };
+
+        builder.add_output_note(OutputNote::Full(step.0.clone()));
+        steps.push(step);
+    }
+
+    let mut mock_chain = builder.build()?;
+
+    // Initialize the bank
+    let init_program = init_tx_script_package.unwrap_program();
+    let init_tx_script = TransactionScript::new((*init_program).clone());
+
+    // Init script argument: [faucet_prefix, faucet_suffix, owner_prefix, owner_suffix]
+    let init_arg = match scenario.owner {
+        Some(owner) => {
+            let owner = depositor_id(owner)?;
+            Word::from([Felt::new(0), Felt::new(0), owner.prefix().as_felt(), owner.suffix()])
+        }
+        None => Word::default(),
+    };
+
+    let init_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[], &[])?
+        .tx_script(init_tx_script)
+        .tx_script_args(init_arg)
+        .build()?;
+
+    let executed_init = init_tx_context.execute().await?;
+    bank_account.apply_delta(executed_init.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_init)?;
+    mock_chain.prove_next_block()?;
+
+    // Run each operation in its own transaction
+    for (index, (note, expected_output)) in steps.into_iter().enumerate() {
+        let tx_context = mock_chain
+            .build_tx_context(bank_account.id(), &[note.id()], &[])?
+            .extend_expected_output_notes(expected_output.into_iter().map(OutputNote::Full).collect())
+            .build()?;
+
+        let executed = match tx_context.execute().await {
+            Ok(executed) => executed,
+            Err(error) => {
+                return Err(ScenarioFailure {
+                    operation: index,
+                    error: error.into(),
+                    state: scenario_state(bank_account, faucet_ids, depositor_ids)?,
+                }
+                .into())
+            }
+        };
+        bank_account.apply_delta(executed.account_delta())?;
+        mock_chain.add_pending_executed_transaction(&executed)?;
+        mock_chain.prove_next_block()?;
+    }
+
+    scenario_state(bank_account, faucet_ids, depositor_ids)
+}
+
+/// Reads the balance of every depositor for every faucet from the bank account
+fn scenario_state(
+    bank_account: Account,
+    faucet_ids: Vec<AccountId>,
+    depositor_ids: Vec<AccountId>,
+) -> Result<ScenarioResult> {
+    let mut balances = BTreeMap::new();
+    for (depositor_index, depositor) in depositor_ids.iter().enumerate() {
+        for (faucet_index, faucet) in faucet_ids.iter().enumerate() {
+            let balance = get_balance_for_asset(&bank_account, *depositor, *faucet)?;
+            balances.insert((depositor_index, faucet_index), balance);
+        }
+    }
+
+    Ok(ScenarioResult {
+        bank_account,
+        faucet_ids,
+        depositor_ids,
+        balances,
+    })
+}
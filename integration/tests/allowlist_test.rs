@@ -0,0 +1,72 @@
+use integration::helpers::preflight_bank_account;
+use integration::scenario::{run_scenario, Operation, Scenario, ScenarioFailure};
+
+/// Test that a deposit from an allowed faucet is credited.
+#[tokio::test]
+async fn deposit_allowed_asset_test() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("AAA", 1000)
+        .faucet("BBB", 1000)
+        .depositor(&[(0, 1000), (1, 1000)])
+        .owner(0);
+    let result = run_scenario(scenario.operations(vec![
+        Operation::AllowAsset { sender: 0, faucet: 0 },
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: 300,
+        },
+    ]))
+    .await?;
+
+    assert_eq!(result.balance(0, 0), 300, "Allowed deposit should be credited");
+
+    Ok(())
+}
+
+/// Test that once the allowlist is non-empty, a deposit from a faucet not on it is
+/// rejected, even though the same faucet was accepted before.
+#[tokio::test]
+async fn deposit_disallowed_asset_should_fail() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("AAA", 1000)
+        .faucet("BBB", 1000)
+        .depositor(&[(0, 1000), (1, 1000)])
+        .owner(0);
+    let result = run_scenario(scenario.operations(vec![
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 1,
+            amount: 300,
+        },
+        Operation::AllowAsset { sender: 0, faucet: 0 },
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 1,
+            amount: 400,
+        },
+    ]))
+    .await;
+
+    // The deposit before the allowlist existed and the allowlisting succeed
+    let failure = result
+        .err()
+        .expect("Expected the disallowed deposit to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 2, "{}", failure);
+    let state = &failure.state;
+    let report = preflight_bank_account(
+        &state.bank_account,
+        state.depositor_ids[0],
+        state.faucet_ids[1],
+        0,
+    )?;
+    assert!(!report.faucet_accepted, "Faucet 1 should not be on the allowlist");
+    assert_eq!(
+        state.balance(0, 1),
+        300,
+        "Only the deposit before the allowlist should be credited"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use integration::helpers::{get_total, BankFixture, MAX_DEPOSIT_AMOUNT};
+
+/// Test that repeated deposits of exactly MAX_DEPOSIT_AMOUNT are each accepted and
+/// add up exactly in the depositor's balance and the bank's total.
+#[tokio::test]
+async fn repeated_max_deposits_test() -> anyhow::Result<()> {
+    let deposits: u64 = 3;
+    let mut fixture = BankFixture::builder()
+        .faucet_supply(deposits * MAX_DEPOSIT_AMOUNT)
+        .build()
+        .await?;
+
+    for step in 1..=deposits {
+        fixture.deposit(MAX_DEPOSIT_AMOUNT).await?;
+
+        assert_eq!(
+            fixture.balance_of(fixture.sender.id())?,
+            step * MAX_DEPOSIT_AMOUNT,
+            "Balance after {} maximum deposits",
+            step
+        );
+        assert_eq!(
+            get_total(&fixture.bank_account, fixture.faucet.id())?,
+            step * MAX_DEPOSIT_AMOUNT,
+            "Total after {} maximum deposits",
+            step
+        );
+    }
+
+    // A single deposit one token above the maximum is rejected
+    let mut fixture = BankFixture::builder()
+        .faucet_supply(MAX_DEPOSIT_AMOUNT + 1)
+        .build()
+        .await?;
+    assert!(
+        fixture.deposit(MAX_DEPOSIT_AMOUNT + 1).await.is_err(),
+        "Expected a deposit one above the maximum to fail, but it succeeded"
+    );
+
+    Ok(())
+}
+
+/// Test that a balance can be withdrawn to exactly zero, after which even a one-token
+/// withdrawal is rejected instead of wrapping below zero.
+#[tokio::test]
+async fn withdraw_exactly_to_zero_test() -> anyhow::Result<()> {
+    let mut fixture = BankFixture::builder().build().await?;
+
+    fixture.deposit(1000).await?;
+    fixture.withdraw(400).await?;
+    fixture.withdraw(600).await?;
+
+    assert_eq!(
+        fixture.balance_of(fixture.sender.id())?,
+        0,
+        "Balance after withdrawing everything"
+    );
+    assert_eq!(
+        get_total(&fixture.bank_account, fixture.faucet.id())?,
+        0,
+        "Total after withdrawing everything"
+    );
+
+    assert!(
+        fixture.withdraw(1).await.is_err(),
+        "Expected a withdrawal from an empty balance to fail, but it succeeded"
+    );
+    assert_eq!(
+        fixture.balance_of(fixture.sender.id())?,
+        0,
+        "Balance after the rejected withdrawal"
+    );
+
+    Ok(())
+}
+
+/// Test that withdrawing one token more than the balance is rejected and leaves the
+/// balance untouched, while withdrawing exactly the balance succeeds.
+#[tokio::test]
+async fn withdraw_one_more_than_balance_should_fail() -> anyhow::Result<()> {
+    let deposit_amount: u64 = 500;
+    let mut fixture = BankFixture::builder().build().await?;
+
+    fixture.deposit(deposit_amount).await?;
+
+    assert!(
+        fixture.withdraw(deposit_amount + 1).await.is_err(),
+        "Expected a withdrawal one above the balance to fail, but it succeeded"
+    );
+    assert_eq!(
+        fixture.balance_of(fixture.sender.id())?,
+        deposit_amount,
+        "Balance after the rejected withdrawal"
+    );
+
+    fixture.withdraw(deposit_amount).await?;
+    assert_eq!(
+        fixture.balance_of(fixture.sender.id())?,
+        0,
+        "Balance after withdrawing exactly the balance"
+    );
+
+    Ok(())
+}
+
+/// Test that a zero-amount deposit is rejected and changes neither the balance nor
+/// the bank's total.
+#[tokio::test]
+async fn deposit_zero_should_fail() -> anyhow::Result<()> {
+    let mut fixture = BankFixture::builder().build().await?;
+
+    fixture.deposit(300).await?;
+
+    assert!(
+        fixture.deposit(0).await.is_err(),
+        "Expected the zero-amount deposit to fail, but it succeeded"
+    );
+    assert_eq!(
+        fixture.balance_of(fixture.sender.id())?,
+        300,
+        "Balance after the rejected deposit"
+    );
+    assert_eq!(
+        get_total(&fixture.bank_account, fixture.faucet.id())?,
+        300,
+        "Total after the rejected deposit"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,427 @@
+use integration::helpers::{
+    build_project_cached, create_initialized_bank, create_testing_note_from_package,
+    AccountCreationConfig, InitializedBank, NoteCreationConfig,
+};
+
+use miden_client::{
+    note::{Note, NoteAssets, NoteInputs, NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType},
+    transaction::OutputNote,
+    Felt, Word,
+};
+use miden_client::asset::FungibleAsset;
+use miden_testing::{Auth, MockChain};
+use std::path::Path;
+
+/// Test that a depositor can have the bank emit a note attesting to their balance.
+///
+/// The proof note's recipient commits to its inputs, so matching the produced note's id
+/// against one built from the expected inputs shows the note encodes the depositor, the
+/// faucet, the balance and the block it was read at.
+#[tokio::test]
+async fn emit_balance_proof_test() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let deposit_amount: u64 = 1000;
+
+    // Create a faucet to mint test assets
+    let faucet =
+        builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", deposit_amount, Some(10))?;
+
+    // Create note sender account (the depositor)
+    let sender = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet.id(), deposit_amount)?.into()],
+    )?;
+
+    // Build contracts
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+    let balance_proof_note_package =
+        build_project_cached(Path::new("../contracts/balance-proof-note"), true)?;
+
+    // Create the bank account with named storage slots
+    let bank_cfg = AccountCreationConfig::bank_default()?;
+
+    // Craft the deposit note
+    let deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount)?.into()])?,
+            ..Default::default()
+        },
+    )?;
+
+    // The bank doesn't interpret the proof note's script; any script will do here
+    let deposit_program = deposit_note_package.unwrap_program();
+    let proof_script = NoteScript::from_parts(
+        deposit_program.mast_forest().clone(),
+        deposit_program.entrypoint(),
+    );
+    let proof_serial_num = Word::from([
+        Felt::new(0x9999999999999999),
+        Felt::new(0xaaaaaaaaaaaaaaaa),
+        Felt::new(0xbbbbbbbbbbbbbbbb),
+        Felt::new(0xcccccccccccccccc),
+    ]);
+    let proof_tag = NoteTag::with_account_target(sender.id());
+    let proof_script_root = proof_script.root();
+
+    // Note inputs layout (12 Felts):
+    // [0-1]: faucet AccountId (prefix, suffix)
+    // [2-5]: serial_num for the proof note
+    // [6-9]: script root of the proof note
+    // [10]: tag for the proof note
+    // [11]: note_type (1 = Public)
+    let balance_proof_note = create_testing_note_from_package(
+        balance_proof_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            inputs: vec![
+                faucet.id().prefix().as_felt(),
+                faucet.id().suffix(),
+                proof_serial_num[0],
+                proof_serial_num[1],
+                proof_serial_num[2],
+                proof_serial_num[3],
+                proof_script_root[0],
+                proof_script_root[1],
+                proof_script_root[2],
+                proof_script_root[3],
+                Felt::new(proof_tag.as_u32() as u64),
+                Felt::new(1),
+            ],
+            ..Default::default()
+        },
+    )?;
+
+    builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+    builder.add_output_note(OutputNote::Full(balance_proof_note.clone()));
+
+    // Create and initialize the bank
+    let InitializedBank {
+        mut bank_account,
+        mut mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
+
+    // Deposit
+    let deposit_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+        .build()?;
+
+    let executed_deposit = deposit_tx_context.execute().await?;
+    bank_account.apply_delta(executed_deposit.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_deposit)?;
+    mock_chain.prove_next_block()?;
+
+    // The proof records the balance at the transaction's reference block
+    let block_number = mock_chain.latest_block_header().block_num().as_u32();
+    let expected_proof_note = Note::new(
+        NoteAssets::default(),
+        NoteMetadata::new(bank_account.id(), NoteType::Public, proof_tag),
+        NoteRecipient::new(
+            proof_serial_num,
+            proof_script,
+            NoteInputs::new(vec![
+                sender.id().prefix().as_felt(),
+                sender.id().suffix(),
+                faucet.id().prefix().as_felt(),
+                faucet.id().suffix(),
+                Felt::new(deposit_amount),
+                Felt::new(block_number as u64),
+            ])?,
+        ),
+    );
+
+    let proof_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[balance_proof_note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(expected_proof_note.clone())])
+        .build()?;
+
+    let executed_proof = proof_tx_context.execute().await?;
+
+    assert_eq!(
+        executed_proof.output_notes().num_notes(),
+        1,
+        "Expected exactly one proof note"
+    );
+    assert_eq!(
+        executed_proof.output_notes().get_note(0).id(),
+        expected_proof_note.id(),
+        "Proof note does not encode the expected depositor, faucet, balance and block"
+    );
+
+    // Emitting a proof doesn't move any funds
+    assert!(
+        executed_proof.account_delta().vault().is_empty(),
+        "Balance proof must not change the bank's vault"
+    );
+
+    Ok(())
+}
+
+/// Test that the bank refuses to attest a balance for anyone but its depositor.
+///
+/// A deposits; B then sends a note asking for a proof of A's balance, which the bank
+/// rejects. The same request sent by A is accepted.
+#[tokio::test]
+async fn emit_balance_proof_for_another_depositor_should_fail() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let deposit_amount: u64 = 1000;
+
+    // Create a faucet to mint test assets
+    let faucet =
+        builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", deposit_amount, Some(10))?;
+
+    // Account A deposits; account B holds nothing in the bank
+    let depositor = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet.id(), deposit_amount)?.into()],
+    )?;
+    let attacker = builder.add_existing_wallet(Auth::BasicAuth)?;
+
+    // Build contracts
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+    // Test fixture: a balance proof note that names the depositor in its inputs
+    let spoofed_balance_proof_note_package =
+        build_project_cached(Path::new("tests/fixtures/spoofed-balance-proof-note"), true)?;
+
+    // Create the bank account with named storage slots
+    let bank_cfg = AccountCreationConfig::bank_default()?;
+
+    // Craft the deposit note
+    let deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        depositor.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount)?.into()])?,
+            ..Default::default()
+        },
+    )?;
+
+    // Craft a proof request for A's balance sent by B, and one sent by A; each uses its
+    // own proof serial number so the two notes are distinct
+    // The bank doesn't interpret the proof note's script; any script will do here
+    let deposit_program = deposit_note_package.unwrap_program();
+    let proof_script = NoteScript::from_parts(
+        deposit_program.mast_forest().clone(),
+        deposit_program.entrypoint(),
+    );
+    let proof_tag = NoteTag::with_account_target(depositor.id());
+    let proof_script_root = proof_script.root();
+    let proof_serial_num =
+        |serial: u64| Word::from([Felt::new(serial), Felt::new(0), Felt::new(0), Felt::new(0)]);
+    let spoofed_inputs = |serial: u64| {
+        let serial_num = proof_serial_num(serial);
+        vec![
+            depositor.id().prefix().as_felt(),
+            depositor.id().suffix(),
+            faucet.id().prefix().as_felt(),
+            faucet.id().suffix(),
+            serial_num[0],
+            serial_num[1],
+            serial_num[2],
+            serial_num[3],
+            proof_script_root[0],
+            proof_script_root[1],
+            proof_script_root[2],
+            proof_script_root[3],
+            Felt::new(proof_tag.as_u32() as u64),
+            Felt::new(1),
+        ]
+    };
+    let attacker_note = create_testing_note_from_package(
+        spoofed_balance_proof_note_package.clone(),
+        attacker.id(),
+        NoteCreationConfig {
+            inputs: spoofed_inputs(1),
+            ..Default::default()
+        },
+    )?;
+    let depositor_note = create_testing_note_from_package(
+        spoofed_balance_proof_note_package.clone(),
+        depositor.id(),
+        NoteCreationConfig {
+            inputs: spoofed_inputs(2),
+            ..Default::default()
+        },
+    )?;
+
+    builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+    builder.add_output_note(OutputNote::Full(attacker_note.clone()));
+    builder.add_output_note(OutputNote::Full(depositor_note.clone()));
+
+    // Create and initialize the bank
+    let InitializedBank {
+        mut bank_account,
+        mut mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
+
+    // A deposits
+    let deposit_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+        .build()?;
+
+    let executed_deposit = deposit_tx_context.execute().await?;
+    bank_account.apply_delta(executed_deposit.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_deposit)?;
+    mock_chain.prove_next_block()?;
+
+    // Expected proof notes; either request would attest A's balance
+    let block_number = mock_chain.latest_block_header().block_num().as_u32();
+    let expected_proof_note = |serial: u64| -> anyhow::Result<Note> {
+        Ok(Note::new(
+            NoteAssets::default(),
+            NoteMetadata::new(bank_account.id(), NoteType::Public, proof_tag),
+            NoteRecipient::new(
+                proof_serial_num(serial),
+                proof_script.clone(),
+                NoteInputs::new(vec![
+                    depositor.id().prefix().as_felt(),
+                    depositor.id().suffix(),
+                    faucet.id().prefix().as_felt(),
+                    faucet.id().suffix(),
+                    Felt::new(deposit_amount),
+                    Felt::new(block_number as u64),
+                ])?,
+            ),
+        ))
+    };
+
+    // B's request for A's balance is rejected
+    let attacker_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[attacker_note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(expected_proof_note(1)?)])
+        .build()?;
+
+    assert!(
+        attacker_tx_context.execute().await.is_err(),
+        "Expected a balance proof requested by another account to fail, but it succeeded"
+    );
+
+    // The same request sent by A is accepted
+    let depositor_proof_note = expected_proof_note(2)?;
+    let depositor_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[depositor_note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(depositor_proof_note.clone())])
+        .build()?;
+
+    let executed_proof = depositor_tx_context.execute().await?;
+    assert_eq!(
+        executed_proof.output_notes().get_note(0).id(),
+        depositor_proof_note.id(),
+        "Proof note does not encode the depositor's balance"
+    );
+
+    Ok(())
+}
+
+/// Test that a balance proof requesting a note type other than Public (1) or Private (2)
+/// is rejected instead of creating a proof note with undefined routing.
+#[tokio::test]
+async fn emit_balance_proof_invalid_note_type_should_fail() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let deposit_amount: u64 = 1000;
+
+    // Create a faucet to mint test assets
+    let faucet =
+        builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", deposit_amount, Some(10))?;
+
+    // Create note sender account (the depositor)
+    let sender = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet.id(), deposit_amount)?.into()],
+    )?;
+
+    // Build contracts
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+    let balance_proof_note_package =
+        build_project_cached(Path::new("../contracts/balance-proof-note"), true)?;
+
+    // Create the bank account with named storage slots
+    let bank_cfg = AccountCreationConfig::bank_default()?;
+
+    // Craft the deposit note
+    let deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount)?.into()])?,
+            ..Default::default()
+        },
+    )?;
+
+    // Craft a balance proof note asking for note type 3
+    let deposit_program = deposit_note_package.unwrap_program();
+    let proof_script_root = NoteScript::from_parts(
+        deposit_program.mast_forest().clone(),
+        deposit_program.entrypoint(),
+    )
+    .root();
+    let proof_tag = NoteTag::with_account_target(sender.id());
+
+    // Note inputs layout (12 Felts):
+    // [0-1]: faucet AccountId (prefix, suffix)
+    // [2-5]: serial_num for the proof note
+    // [6-9]: script root of the proof note
+    // [10]: tag for the proof note
+    // [11]: note_type (3 = invalid)
+    let balance_proof_note = create_testing_note_from_package(
+        balance_proof_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            inputs: vec![
+                faucet.id().prefix().as_felt(),
+                faucet.id().suffix(),
+                Felt::new(0x3333333333333333),
+                Felt::new(0x1),
+                Felt::new(0x2),
+                Felt::new(0x3),
+                proof_script_root[0],
+                proof_script_root[1],
+                proof_script_root[2],
+                proof_script_root[3],
+                Felt::new(proof_tag.as_u32() as u64),
+                Felt::new(3),
+            ],
+            ..Default::default()
+        },
+    )?;
+
+    builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+    builder.add_output_note(OutputNote::Full(balance_proof_note.clone()));
+
+    // Create and initialize the bank
+    let InitializedBank {
+        mut bank_account,
+        mut mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
+
+    // Deposit
+    let deposit_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+        .build()?;
+
+    let executed_deposit = deposit_tx_context.execute().await?;
+    bank_account.apply_delta(executed_deposit.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_deposit)?;
+    mock_chain.prove_next_block()?;
+
+    // The proof names an undefined note type and must be rejected
+    let tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[balance_proof_note.id()], &[])?
+        .build()?;
+
+    let result = tx_context.execute().await;
+
+    assert!(
+        result.is_err(),
+        "Expected a balance proof with note type 3 to fail, but it succeeded"
+    );
+
+    Ok(())
+}
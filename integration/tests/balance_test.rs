@@ -0,0 +1,124 @@
+use integration::scenario::{run_scenario, Operation, Scenario, ScenarioFailure};
+
+/// Balance ceiling configured for the boundary scenarios
+const MAX_TOTAL_BALANCE: u64 = 1000;
+
+/// Test that deposits adding up to exactly the balance ceiling are accepted.
+#[tokio::test]
+async fn balance_add_up_to_ceiling_test() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("TEST", 2000)
+        .depositor(&[(0, 2000)])
+        .config("max_total_balance", MAX_TOTAL_BALANCE);
+    let result = run_scenario(scenario.operations(vec![
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: 600,
+        },
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: MAX_TOTAL_BALANCE - 600,
+        },
+    ]))
+    .await?;
+
+    assert_eq!(result.balance(0, 0), MAX_TOTAL_BALANCE);
+
+    Ok(())
+}
+
+/// Test that a deposit taking the balance one token past the ceiling is rejected.
+#[tokio::test]
+async fn balance_add_past_ceiling_should_fail() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("TEST", 2000)
+        .depositor(&[(0, 2000)])
+        .config("max_total_balance", MAX_TOTAL_BALANCE);
+    let result = run_scenario(scenario.operations(vec![
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: 600,
+        },
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: MAX_TOTAL_BALANCE - 600 + 1,
+        },
+    ]))
+    .await;
+
+    let failure = result
+        .err()
+        .expect("Expected the over-ceiling deposit to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 1, "{}", failure);
+    assert_eq!(failure.state.balance(0, 0), 600, "The balance should stay below the ceiling");
+
+    Ok(())
+}
+
+/// Test that withdrawing exactly the balance leaves it at zero.
+#[tokio::test]
+async fn balance_sub_to_zero_test() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("TEST", 2000)
+        .depositor(&[(0, 2000)])
+        .config("max_total_balance", MAX_TOTAL_BALANCE);
+    let result = run_scenario(scenario.operations(vec![
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: 600,
+        },
+        Operation::Withdraw {
+            depositor: 0,
+            faucet: 0,
+            amount: 600,
+        },
+    ]))
+    .await?;
+
+    assert_eq!(result.balance(0, 0), 0);
+
+    Ok(())
+}
+
+/// Test that withdrawing one token more than the balance is rejected instead of wrapping.
+#[tokio::test]
+async fn balance_sub_past_zero_should_fail() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("TEST", 2000)
+        .depositor(&[(0, 2000)])
+        .config("max_total_balance", MAX_TOTAL_BALANCE);
+    let result = run_scenario(scenario.operations(vec![
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: 600,
+        },
+        Operation::Withdraw {
+            depositor: 0,
+            faucet: 0,
+            amount: 601,
+        },
+    ]))
+    .await;
+
+    let failure = result
+        .err()
+        .expect("Expected the over-withdrawal to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 1, "{}", failure);
+    let state = &failure.state;
+    assert_eq!(state.balance(0, 0), 600, "The balance should not wrap");
+    assert_eq!(
+        state.bank_account.vault().get_balance(state.faucet_ids[0])?,
+        600,
+        "Nothing should leave the vault"
+    );
+
+    Ok(())
+}
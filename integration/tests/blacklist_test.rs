@@ -0,0 +1,207 @@
+use integration::helpers::{preflight_bank_account, read_bank_config};
+use integration::scenario::{run_scenario, Operation, Scenario, ScenarioFailure};
+
+/// Test that the bank rejects a deposit note from a blacklisted depositor.
+#[tokio::test]
+async fn deposit_while_blacklisted_should_fail() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("TEST", 2000)
+        .depositor(&[(0, 1000)])
+        .depositor(&[(0, 1000)])
+        .owner(0);
+    let result = run_scenario(scenario.operations(vec![
+        Operation::Deposit {
+            depositor: 1,
+            faucet: 0,
+            amount: 300,
+        },
+        Operation::SetBlacklisted {
+            sender: 0,
+            account: 1,
+            blacklisted: true,
+        },
+        Operation::Deposit {
+            depositor: 1,
+            faucet: 0,
+            amount: 400,
+        },
+    ]))
+    .await;
+
+    // The first deposit and the blacklisting succeed; the deposit after it is rejected
+    let failure = result
+        .err()
+        .expect("Expected the blacklisted deposit to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 2, "{}", failure);
+    let state = &failure.state;
+    let report = preflight_bank_account(
+        &state.bank_account,
+        state.depositor_ids[1],
+        state.faucet_ids[0],
+        0,
+    )?;
+    assert!(report.depositor_blacklisted, "Depositor 1 should be blacklisted");
+    assert_eq!(
+        state.balance(1, 0),
+        300,
+        "Only the deposit before the blacklisting should be credited"
+    );
+
+    Ok(())
+}
+
+/// Test that a blacklisted sender can't deposit by naming someone else as beneficiary.
+#[tokio::test]
+async fn deposit_for_beneficiary_while_blacklisted_should_fail() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("TEST", 2000)
+        .depositor(&[(0, 1000)])
+        .depositor(&[(0, 1000)])
+        .owner(0);
+    let result = run_scenario(scenario.operations(vec![
+        Operation::SetBlacklisted {
+            sender: 0,
+            account: 1,
+            blacklisted: true,
+        },
+        Operation::DepositFor {
+            depositor: 1,
+            beneficiary: 0,
+            faucet: 0,
+            amount: 400,
+        },
+    ]))
+    .await;
+
+    let failure = result
+        .err()
+        .expect("Expected the blacklisted sender's deposit to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 1, "{}", failure);
+    let state = &failure.state;
+    let report = preflight_bank_account(
+        &state.bank_account,
+        state.depositor_ids[1],
+        state.faucet_ids[0],
+        0,
+    )?;
+    assert!(report.depositor_blacklisted, "The sender should be blacklisted");
+    assert_eq!(state.balance(0, 0), 0, "The beneficiary should not be credited");
+
+    Ok(())
+}
+
+/// Test that a blacklisted depositor's balance is frozen, and that it can be withdrawn
+/// again once the owner removes them from the blacklist.
+#[tokio::test]
+async fn withdraw_while_blacklisted_should_fail() -> anyhow::Result<()> {
+    let blacklisted_withdraw = vec![
+        Operation::Deposit {
+            depositor: 1,
+            faucet: 0,
+            amount: 1000,
+        },
+        Operation::SetBlacklisted {
+            sender: 0,
+            account: 1,
+            blacklisted: true,
+        },
+        Operation::Withdraw {
+            depositor: 1,
+            faucet: 0,
+            amount: 400,
+        },
+    ];
+
+    let scenario = Scenario::builder()
+        .faucet("TEST", 2000)
+        .depositor(&[(0, 1000)])
+        .depositor(&[(0, 1000)])
+        .owner(0);
+    let result = run_scenario(scenario.operations(blacklisted_withdraw)).await;
+    let failure = result
+        .err()
+        .expect("Expected the blacklisted withdrawal to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 2, "{}", failure);
+    let state = &failure.state;
+    let report = preflight_bank_account(
+        &state.bank_account,
+        state.depositor_ids[1],
+        state.faucet_ids[0],
+        0,
+    )?;
+    assert!(report.depositor_blacklisted, "Depositor 1 should be blacklisted");
+    assert_eq!(state.balance(1, 0), 1000, "The blacklisted balance should stay frozen");
+
+    let scenario = Scenario::builder()
+        .faucet("TEST", 2000)
+        .depositor(&[(0, 1000)])
+        .depositor(&[(0, 1000)])
+        .owner(0);
+    let result = run_scenario(scenario.operations(vec![
+        Operation::Deposit {
+            depositor: 1,
+            faucet: 0,
+            amount: 1000,
+        },
+        Operation::SetBlacklisted {
+            sender: 0,
+            account: 1,
+            blacklisted: true,
+        },
+        Operation::SetBlacklisted {
+            sender: 0,
+            account: 1,
+            blacklisted: false,
+        },
+        Operation::Withdraw {
+            depositor: 1,
+            faucet: 0,
+            amount: 400,
+        },
+    ]))
+    .await?;
+
+    assert_eq!(result.balance(1, 0), 600, "Withdrawal should succeed once unblocked");
+
+    Ok(())
+}
+
+/// Test that only the owner can blacklist an account.
+#[tokio::test]
+async fn set_blacklisted_non_owner_should_fail() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("TEST", 2000)
+        .depositor(&[(0, 1000)])
+        .depositor(&[(0, 1000)])
+        .owner(0);
+    let result = run_scenario(scenario.operations(vec![Operation::SetBlacklisted {
+        sender: 1,
+        account: 0,
+        blacklisted: true,
+    }]))
+    .await;
+
+    let failure = result
+        .err()
+        .expect("Expected a non-owner blacklisting to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 0, "{}", failure);
+    let state = &failure.state;
+    let report = preflight_bank_account(
+        &state.bank_account,
+        state.depositor_ids[0],
+        state.faucet_ids[0],
+        0,
+    )?;
+    assert!(!report.depositor_blacklisted, "The owner should not be blacklisted");
+    assert_eq!(
+        read_bank_config(&state.bank_account)?.owner,
+        Some(state.depositor_ids[0]),
+        "Depositor 1 should not own the bank"
+    );
+
+    Ok(())
+}
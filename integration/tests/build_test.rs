@@ -0,0 +1,60 @@
+use std::{path::Path, process::Command};
+
+/// Path to the contracts directory, relative to the integration crate
+const CONTRACTS_DIR: &str = "../contracts";
+
+/// Builds every contract in `contracts/`, one after another, via the `build-contract`
+/// binary.
+///
+/// # Returns
+/// The name and compiler output of each contract that failed to build
+fn build_all_contracts() -> anyhow::Result<Vec<(String, String)>> {
+    let mut contract_dirs = std::fs::read_dir(Path::new(CONTRACTS_DIR))?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    contract_dirs.retain(|dir| dir.join("Cargo.toml").is_file());
+    contract_dirs.sort();
+
+    let mut failures = Vec::new();
+    for dir in contract_dirs {
+        let output = Command::new(env!("CARGO_BIN_EXE_build-contract"))
+            .arg(&dir)
+            .output()?;
+
+        if !output.status.success() {
+            let name = dir
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            failures.push((
+                name,
+                format!(
+                    "{}{}",
+                    String::from_utf8_lossy(&output.stdout),
+                    String::from_utf8_lossy(&output.stderr)
+                ),
+            ));
+        }
+    }
+
+    Ok(failures)
+}
+
+/// Test that the bank and every note and script contract build together, so a bank
+/// API change that breaks a note's generated bindings is caught here.
+#[test]
+fn build_all_contracts_test() -> anyhow::Result<()> {
+    let failures = build_all_contracts()?;
+
+    assert!(
+        failures.is_empty(),
+        "Failed to build contracts:\n{}",
+        failures
+            .iter()
+            .map(|(name, output)| format!("--- {} ---\n{}", name, output))
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use integration::helpers::preflight_bank_account;
+use integration::scenario::{run_scenario, single_depositor_scenario, Operation, ScenarioFailure};
+
+/// Test that the owner can close a bank once every balance has been withdrawn, leaving
+/// it uninitialized.
+#[tokio::test]
+async fn close_test() -> anyhow::Result<()> {
+    let result = run_scenario(single_depositor_scenario(vec![
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: 1000,
+        },
+        Operation::Withdraw {
+            depositor: 0,
+            faucet: 0,
+            amount: 1000,
+        },
+        Operation::Close { sender: 0 },
+    ]))
+    .await?;
+
+    let report = preflight_bank_account(
+        &result.bank_account,
+        result.depositor_ids[0],
+        result.faucet_ids[0],
+        0,
+    )?;
+    assert!(!report.initialized, "The closed bank should be uninitialized");
+
+    Ok(())
+}
+
+/// Test that a bank still holding a depositor balance refuses to close.
+#[tokio::test]
+async fn close_with_balance_should_fail() -> anyhow::Result<()> {
+    let result = run_scenario(single_depositor_scenario(vec![
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: 1000,
+        },
+        Operation::Withdraw {
+            depositor: 0,
+            faucet: 0,
+            amount: 400,
+        },
+        Operation::Close { sender: 0 },
+    ]))
+    .await;
+
+    // The deposit and the partial withdrawal succeed; the close is rejected
+    let failure = result
+        .err()
+        .expect("Expected closing a funded bank to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 2, "{}", failure);
+    let state = &failure.state;
+    let report = preflight_bank_account(
+        &state.bank_account,
+        state.depositor_ids[0],
+        state.faucet_ids[0],
+        0,
+    )?;
+    assert!(report.initialized, "The bank should stay open");
+    assert_eq!(state.balance(0, 0), 600, "The depositor should still hold a balance");
+
+    Ok(())
+}
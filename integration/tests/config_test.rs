@@ -0,0 +1,309 @@
+use integration::helpers::{
+    assert_bank_slots, assert_word_eq, bank_slot_name, bank_storage_slots, build_project_cached,
+    create_testing_account_from_package, BankFixture,
+    read_bank_config, AccountCreationConfig, BankConfig,
+    MAX_DEPOSIT_AMOUNT, MAX_DEPOSIT_PER_BLOCK, MAX_TOTAL_BALANCE,
+};
+
+use miden_client::{
+    account::{Account, AccountId, StorageMap, StorageSlot},
+    note::NoteTag,
+    testing::account_id::ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+    transaction::TransactionScript,
+    Felt, Word,
+};
+use miden_testing::{Auth, MockChain};
+use std::path::Path;
+
+/// Test that every field of a fully configured bank decodes to the value it was
+/// configured with, including the default faucet and owner set by the init transaction.
+#[tokio::test]
+async fn read_bank_config_test() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let faucet = builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", 1000, Some(10))?;
+    let owner = builder.add_existing_wallet(Auth::BasicAuth)?;
+
+    // Build contracts
+    let bank_package = build_project_cached(Path::new("../contracts/bank-account"), true)?;
+    let init_tx_script_package =
+        build_project_cached(Path::new("../contracts/init-tx-script"), true)?;
+
+    // Create the bank account with every config slot set at creation
+    let config_values = [
+        ("refund_mode", [1, 1]),
+        ("auto_initialize", [1, 0]),
+        ("min_account_age", [5, 0]),
+        ("max_deposit_per_block", [2500, 0]),
+        ("withdraw_cooldown", [3, 0]),
+        ("max_total_balance", [50_000, 0]),
+        ("max_deposit", [800, 0]),
+        ("fee_bps", [100, 0]),
+        ("p2id_root", [7, 9]),
+        ("deposit_events", [1, 0x4000_0000]),
+    ];
+    let config_values = config_values
+        .map(|(field, [first, second])| {
+            (field, Word::from([Felt::new(first), Felt::new(second), Felt::new(0), Felt::new(0)]))
+        });
+    let storage_slots = bank_storage_slots(&config_values)?;
+    let bank_cfg = AccountCreationConfig {
+        storage_slots,
+        ..Default::default()
+    };
+
+    let mut bank_account =
+        create_testing_account_from_package(bank_package.clone(), bank_cfg).await?;
+    assert_bank_slots(&bank_account);
+
+    builder.add_account(bank_account.clone())?;
+    let mock_chain = builder.build()?;
+
+    // Initialize the bank with the default faucet and owner passed as the script argument
+    let init_program = init_tx_script_package.unwrap_program();
+    let init_tx_script = TransactionScript::new((*init_program).clone());
+    let init_arg = Word::from([
+        faucet.id().prefix().as_felt(),
+        faucet.id().suffix(),
+        owner.id().prefix().as_felt(),
+        owner.id().suffix(),
+    ]);
+
+    let init_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[], &[])?
+        .tx_script(init_tx_script)
+        .tx_script_args(init_arg)
+        .build()?;
+
+    let executed_init = init_tx_context.execute().await?;
+    bank_account.apply_delta(executed_init.account_delta())?;
+
+    let config = read_bank_config(&bank_account)?;
+    assert_eq!(
+        config,
+        BankConfig {
+            default_faucet: Some(faucet.id()),
+            refund_mode: true,
+            clamp_over_cap: true,
+            auto_initialize: true,
+            min_account_age: 5,
+            max_deposit_per_block: 2500,
+            withdraw_cooldown: 3,
+            max_total_balance: 50_000,
+            max_deposit: 800,
+            owner: Some(owner.id()),
+            fee_bps: 100,
+            p2id_root: Some(Word::from([Felt::new(7), Felt::new(9), Felt::new(0), Felt::new(0)])),
+            deposit_event_tag: Some(NoteTag::new(0x4000_0000)),
+        },
+        "Decoded bank config"
+    );
+    assert_eq!(config.effective_max_deposit_per_block(), 2500);
+    assert_eq!(config.effective_max_total_balance(), 50_000);
+    assert_eq!(config.effective_max_deposit(), 800);
+
+    Ok(())
+}
+
+/// A bank created before the newer config slots existed decodes with those
+/// settings disabled instead of failing.
+#[tokio::test]
+async fn read_bank_config_tolerates_older_layout() -> anyhow::Result<()> {
+    let bank_package = build_project_cached(Path::new("../contracts/bank-account"), true)?;
+
+    let storage_slots = vec![
+        StorageSlot::with_value(bank_slot_name("initialized")?, Word::default()),
+        StorageSlot::with_map(bank_slot_name("balances")?, StorageMap::with_entries([])?),
+        StorageSlot::with_value(bank_slot_name("default_faucet")?, Word::default()),
+    ];
+
+    let bank_account = create_testing_account_from_package(
+        bank_package,
+        AccountCreationConfig {
+            storage_slots,
+            ..Default::default()
+        },
+    )
+    .await?;
+
+    let config = read_bank_config(&bank_account)?;
+    assert_eq!(config, BankConfig::default(), "Older layout should decode to defaults");
+    assert_eq!(config.effective_max_deposit_per_block(), MAX_DEPOSIT_PER_BLOCK);
+    assert_eq!(config.effective_max_total_balance(), MAX_TOTAL_BALANCE);
+    assert_eq!(config.effective_max_deposit(), MAX_DEPOSIT_AMOUNT);
+
+    Ok(())
+}
+
+/// Creates a bank with every slot at its default and runs the init-config transaction
+/// script with the given argument, returning the bank after the transaction.
+async fn initialize_with_config(init_arg: Word) -> anyhow::Result<Account> {
+    let mut builder = MockChain::builder();
+
+    // Build contracts
+    let bank_package = build_project_cached(Path::new("../contracts/bank-account"), true)?;
+    let init_config_tx_script_package =
+        build_project_cached(Path::new("../contracts/init-config-tx-script"), true)?;
+
+    // Create the bank account with nothing configured at creation
+    let bank_cfg = AccountCreationConfig::bank_default()?;
+
+    let mut bank_account =
+        create_testing_account_from_package(bank_package.clone(), bank_cfg).await?;
+    assert_bank_slots(&bank_account);
+
+    builder.add_account(bank_account.clone())?;
+    let mock_chain = builder.build()?;
+
+    let init_program = init_config_tx_script_package.unwrap_program();
+    let init_tx_script = TransactionScript::new((*init_program).clone());
+
+    let init_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[], &[])?
+        .tx_script(init_tx_script)
+        .tx_script_args(init_arg)
+        .build()?;
+
+    let executed_init = init_tx_context.execute().await?;
+    bank_account.apply_delta(executed_init.account_delta())?;
+
+    Ok(bank_account)
+}
+
+/// Test that the init-config transaction script writes the per-deposit cap, fee and
+/// owner slots and initializes the bank, all in one transaction.
+#[tokio::test]
+async fn initialize_with_config_test() -> anyhow::Result<()> {
+    let owner = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE)?;
+
+    // Init script argument: [max_deposit, fee_bps, owner_prefix, owner_suffix]
+    let bank_account = initialize_with_config(Word::from([
+        Felt::new(800),
+        Felt::new(250),
+        owner.prefix().as_felt(),
+        owner.suffix(),
+    ]))
+    .await?;
+
+    let expected_slots = [
+        ("initialized", Word::from([Felt::new(1), Felt::new(0), Felt::new(0), Felt::new(0)])),
+        ("max_deposit", Word::from([Felt::new(800), Felt::new(0), Felt::new(0), Felt::new(0)])),
+        ("fee_bps", Word::from([Felt::new(250), Felt::new(0), Felt::new(0), Felt::new(0)])),
+        (
+            "owner",
+            Word::from([owner.prefix().as_felt(), owner.suffix(), Felt::new(0), Felt::new(0)]),
+        ),
+    ];
+    for (field, expected) in expected_slots {
+        assert_word_eq(
+            bank_account.storage().get_item(&bank_slot_name(field)?)?,
+            expected,
+            &format!("`{}` slot after initialize_with_config", field),
+        );
+    }
+
+    Ok(())
+}
+
+/// Test that the init-config transaction script rejects a zero per-deposit cap and a
+/// fee above 10,000 basis points.
+#[tokio::test]
+async fn initialize_with_invalid_config_should_fail() -> anyhow::Result<()> {
+    let zero_max_deposit =
+        initialize_with_config(Word::from([Felt::new(0), Felt::new(250), Felt::new(0), Felt::new(0)]))
+            .await;
+    assert!(
+        zero_max_deposit.is_err(),
+        "Expected a zero maximum deposit to be rejected, but it was accepted"
+    );
+
+    let excessive_fee =
+        initialize_with_config(Word::from([Felt::new(800), Felt::new(10_001), Felt::new(0), Felt::new(0)]))
+            .await;
+    assert!(
+        excessive_fee.is_err(),
+        "Expected a fee above 10,000 basis points to be rejected, but it was accepted"
+    );
+
+    Ok(())
+}
+
+/// Test that `try_initialize` initializes a fresh bank once and then reports it as
+/// already initialized instead of failing.
+///
+/// The fixture script calls `try_initialize` twice in one transaction and checks the
+/// returned flags against `[expected_first_flag, 0]`, so the transaction succeeds only
+/// if both flags match.
+#[tokio::test]
+async fn try_initialize_test() -> anyhow::Result<()> {
+    let mut fixture = BankFixture::builder().uninitialized().build().await?;
+    let owner = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE)?;
+
+    let try_init_tx_script_package =
+        build_project_cached(Path::new("tests/fixtures/try-init-tx-script"), true)?;
+    let try_init_program = try_init_tx_script_package.unwrap_program();
+    let try_init_tx_script = TransactionScript::new((*try_init_program).clone());
+
+    // Script argument: [expected_first_flag, 0, owner_prefix, owner_suffix]
+    let try_init_arg = |expected_first_flag: u64| {
+        Word::from([
+            Felt::new(expected_first_flag),
+            Felt::new(0),
+            owner.prefix().as_felt(),
+            owner.suffix(),
+        ])
+    };
+
+    // On a fresh bank the first call initializes (1) and the second is a no-op (0)
+    let tx_context = fixture
+        .mock_chain
+        .build_tx_context(fixture.bank_account.id(), &[], &[])?
+        .tx_script(try_init_tx_script.clone())
+        .tx_script_args(try_init_arg(1))
+        .build()?;
+
+    let executed = tx_context.execute().await?;
+    fixture.bank_account.apply_delta(executed.account_delta())?;
+    fixture.mock_chain.add_pending_executed_transaction(&executed)?;
+    fixture.mock_chain.prove_next_block()?;
+
+    assert_word_eq(
+        fixture.bank_account.storage().get_item(&bank_slot_name("initialized")?)?,
+        Word::from([Felt::new(1), Felt::new(0), Felt::new(0), Felt::new(0)]),
+        "Initialized flag after try_initialize",
+    );
+    assert_word_eq(
+        fixture.bank_account.storage().get_item(&bank_slot_name("owner")?)?,
+        Word::from([owner.prefix().as_felt(), owner.suffix(), Felt::new(0), Felt::new(0)]),
+        "Owner after try_initialize",
+    );
+
+    // On the initialized bank both calls return 0. A transaction whose only call is
+    // `try_initialize` would change nothing and be rejected by the kernel, so the script
+    // runs alongside a deposit.
+    let deposit_note = fixture.deposit_note(100)?;
+    fixture.publish(std::slice::from_ref(&deposit_note)).await?;
+
+    let tx_context = fixture
+        .mock_chain
+        .build_tx_context(fixture.bank_account.id(), &[deposit_note.id()], &[])?
+        .tx_script(try_init_tx_script.clone())
+        .tx_script_args(try_init_arg(1))
+        .build()?;
+
+    assert!(
+        tx_context.execute().await.is_err(),
+        "Expected try_initialize on an initialized bank not to return 1"
+    );
+
+    let tx_context = fixture
+        .mock_chain
+        .build_tx_context(fixture.bank_account.id(), &[deposit_note.id()], &[])?
+        .tx_script(try_init_tx_script)
+        .tx_script_args(try_init_arg(0))
+        .build()?;
+
+    tx_context.execute().await?;
+
+    Ok(())
+}
@@ -1,68 +1,1124 @@
 use integration::helpers::{
-    build_project_in_dir, create_testing_account_from_package, create_testing_note_from_package,
-    AccountCreationConfig, NoteCreationConfig,
+    assert_bank_slots, assert_delta_matches, BankFixture, assert_word_eq, bank_slot_name,
+    build_project_cached, create_testing_account_from_package, create_testing_note_from_package,
+    get_all_balances,
+    assert_balance, get_depositor_faucets, get_total, preflight_bank_account,
+    create_initialized_bank, ensure_bank_initialized, AccountCreationConfig, ExpectedDelta, InitializedBank,
+    NoteCreationConfig, PreflightReport, StorageChange, MAX_DEPOSIT_AMOUNT, MIN_DEPOSIT_AMOUNT,
+    read_bank_config,
 };
 
 use miden_client::{
-    account::{StorageMap, StorageSlot, StorageSlotName},
-    note::NoteAssets,
+    account::{AccountId, StorageSlot},
+    note::{
+        build_p2id_recipient, Note, NoteAssets, NoteInputs, NoteMetadata, NoteRecipient, NoteTag,
+        NoteType, WellKnownNote,
+    },
     transaction::{OutputNote, TransactionScript},
     Felt, Word,
 };
-use miden_client::asset::{Asset, FungibleAsset};
+use miden_client::asset::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails};
+use miden_client::testing::account_id::ACCOUNT_ID_PUBLIC_NON_FUNGIBLE_FAUCET;
+use integration::scenario::{
+    run_scenario, DepositorSpec, FaucetSpec, Operation, Scenario, ScenarioFailure,
+};
 use miden_testing::{Auth, MockChain};
-use std::{path::Path, sync::Arc};
-
-/// Helper to create the bank account storage slots with named slot names
-fn bank_storage_slots() -> (StorageSlotName, StorageSlotName, Vec<StorageSlot>) {
-    let initialized_slot =
-        StorageSlotName::new("miden::component::miden_bank_account::initialized")
-            .expect("Valid slot name");
-    let balances_slot =
-        StorageSlotName::new("miden::component::miden_bank_account::balances")
-            .expect("Valid slot name");
-
-    let slots = vec![
-        StorageSlot::with_value(initialized_slot.clone(), Word::default()),
-        StorageSlot::with_map(
-            balances_slot.clone(),
-            StorageMap::with_entries([]).expect("Empty storage map"),
-        ),
-    ];
+use std::{panic, path::Path};
+
+#[tokio::test]
+async fn deposit_test() -> anyhow::Result<()> {
+    // Test that after executing the deposit note, the depositor's balance is updated
+    let mut fixture = BankFixture::builder().build().await?;
+
+    // The sender deposits; it becomes the depositor
+    let deposit_amount: u64 = 1000;
+    fixture.deposit(deposit_amount).await?;
+
+    // The depositor's balance for this faucet should equal the deposited amount
+    assert_eq!(fixture.balance_of(fixture.sender.id())?, deposit_amount);
+
+    println!("Deposit test passed! Deposited {} tokens", deposit_amount);
+    Ok(())
+}
+
+/// Test that the bank consumes a private deposit note handed to it off-chain.
+///
+/// Only the header of a private note is stored on chain, so the bank cannot look the
+/// note up by ID; the depositor passes the full note along and the bank consumes it as
+/// an unauthenticated input note, authenticated against the committed header.
+#[tokio::test]
+async fn deposit_private_note_test() -> anyhow::Result<()> {
+    let mut fixture = BankFixture::builder().build().await?;
+
+    let deposit_amount: u64 = 1000;
+    let public_note = fixture.deposit_note(deposit_amount)?;
+    let metadata = public_note.metadata();
+    let deposit_note = Note::new(
+        public_note.assets().clone(),
+        NoteMetadata::new(metadata.sender(), NoteType::Private, metadata.tag()),
+        public_note.recipient().clone(),
+    );
+    fixture.publish(std::slice::from_ref(&deposit_note)).await?;
+
+    let tx_context = fixture
+        .mock_chain
+        .build_tx_context(fixture.bank_account.id(), &[], std::slice::from_ref(&deposit_note))?
+        .build()?;
+
+    let executed_transaction = tx_context.execute().await?;
+    fixture.bank_account.apply_delta(executed_transaction.account_delta())?;
+    fixture.mock_chain.add_pending_executed_transaction(&executed_transaction)?;
+    fixture.mock_chain.prove_next_block()?;
+
+    assert_eq!(fixture.balance_of(fixture.sender.id())?, deposit_amount);
+
+    println!("Private deposit test passed! Deposited {} tokens", deposit_amount);
+    Ok(())
+}
+
+/// Test that deposits exceeding MAX_DEPOSIT_AMOUNT (1,000,000) are rejected.
+///
+/// The bank account contract enforces a maximum deposit limit. This test verifies
+/// that attempting to deposit more than the allowed maximum causes the transaction
+/// to fail during execution.
+#[tokio::test]
+async fn deposit_exceeds_max_should_fail() -> anyhow::Result<()> {
+    // MAX_DEPOSIT_AMOUNT in the contract is 1,000,000
+    let large_amount: u64 = 2_000_000; // Exceeds MAX_DEPOSIT_AMOUNT
+    let mut fixture = BankFixture::builder().faucet_supply(large_amount).build().await?;
+
+    // Execute should fail due to max deposit constraint
+    let result = fixture.deposit(large_amount).await;
+
+    assert!(
+        result.is_err(),
+        "Expected transaction to fail due to exceeding max deposit amount, but it succeeded"
+    );
+
+    println!(
+        "Max deposit constraint test passed - deposit of {} tokens correctly rejected (max is 1,000,000)",
+        large_amount
+    );
+    Ok(())
+}
+
+/// Test that a bank created with a lower per-deposit cap rejects a deposit the compiled
+/// `MAX_DEPOSIT_AMOUNT` would allow.
+///
+/// A deposit exactly at the configured cap is accepted; a 1000-token deposit, well
+/// under the compiled default, is rejected.
+#[tokio::test]
+async fn deposit_exceeds_configured_max_should_fail() -> anyhow::Result<()> {
+    let max_deposit: u64 = 500;
+    let deposit_amount: u64 = 1000;
+
+    let result = run_scenario(Scenario {
+        faucets: vec![FaucetSpec {
+            symbol: "TEST",
+            max_supply: max_deposit + deposit_amount,
+        }],
+        depositors: vec![DepositorSpec {
+            assets: vec![(0, max_deposit + deposit_amount)],
+        }],
+        bank_config: vec![(
+            "max_deposit",
+            Word::from([Felt::new(max_deposit), Felt::new(0), Felt::new(0), Felt::new(0)]),
+        )],
+        operations: vec![
+            Operation::Deposit {
+                depositor: 0,
+                faucet: 0,
+                amount: max_deposit,
+            },
+            Operation::Deposit {
+                depositor: 0,
+                faucet: 0,
+                amount: deposit_amount,
+            },
+        ],
+        ..Default::default()
+    })
+    .await;
+
+    // The deposit at the cap (operation 0) succeeds and the larger one (operation 1) is rejected
+    let failure = result
+        .err()
+        .expect("Expected the over-cap deposit to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 1, "{}", failure);
+    let state = &failure.state;
+    assert_eq!(read_bank_config(&state.bank_account)?.max_deposit, max_deposit);
+    assert_eq!(state.balance(0, 0), max_deposit, "Only the deposit at the cap should be credited");
+
+    Ok(())
+}
+
+/// Test that a deposit note naming a beneficiary credits the beneficiary, not the sender.
+///
+/// Depositor 0 sends the note and its assets; depositor 1 is named in the note's inputs.
+#[tokio::test]
+async fn deposit_for_beneficiary_test() -> anyhow::Result<()> {
+    let deposit_amount: u64 = 500;
+
+    let result = run_scenario(Scenario {
+        faucets: vec![FaucetSpec {
+            symbol: "TEST",
+            max_supply: 1000,
+        }],
+        depositors: vec![
+            DepositorSpec {
+                assets: vec![(0, 1000)],
+            },
+            DepositorSpec { assets: vec![] },
+        ],
+        operations: vec![Operation::DepositFor {
+            depositor: 0,
+            beneficiary: 1,
+            faucet: 0,
+            amount: deposit_amount,
+        }],
+        ..Default::default()
+    })
+    .await?;
+
+    assert_eq!(result.balance(1, 0), deposit_amount, "Beneficiary balance");
+    assert_eq!(result.balance(0, 0), 0, "Sender balance");
+
+    Ok(())
+}
+
+/// Test that a deposit note carrying a zero-amount asset is rejected.
+#[tokio::test]
+async fn deposit_zero_amount_should_fail() -> anyhow::Result<()> {
+    let result = run_scenario(Scenario {
+        faucets: vec![FaucetSpec {
+            symbol: "TEST",
+            max_supply: 1000,
+        }],
+        depositors: vec![DepositorSpec {
+            assets: vec![(0, 1000)],
+        }],
+        operations: vec![Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: 0,
+        }],
+        ..Default::default()
+    })
+    .await;
+
+    let failure = result
+        .err()
+        .expect("Expected the zero-amount deposit to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 0, "{}", failure);
+    assert_eq!(failure.state.balance(0, 0), 0, "Nothing should be credited");
+
+    Ok(())
+}
+
+/// Test that a deposit just below MIN_DEPOSIT_AMOUNT is rejected as dust, while a
+/// deposit of exactly the minimum is credited.
+#[tokio::test]
+async fn deposit_below_min_should_fail() -> anyhow::Result<()> {
+    let scenario = |amount: u64| Scenario {
+        faucets: vec![FaucetSpec {
+            symbol: "TEST",
+            max_supply: 1000,
+        }],
+        depositors: vec![DepositorSpec {
+            assets: vec![(0, 1000)],
+        }],
+        operations: vec![Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount,
+        }],
+        ..Default::default()
+    };
+
+    let result = run_scenario(scenario(MIN_DEPOSIT_AMOUNT - 1)).await;
+    let failure = result
+        .err()
+        .expect("Expected the dust deposit to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 0, "{}", failure);
+    assert_eq!(failure.state.balance(0, 0), 0, "Nothing should be credited");
+
+    let result = run_scenario(scenario(MIN_DEPOSIT_AMOUNT)).await?;
+    assert_eq!(
+        result.balance(0, 0),
+        MIN_DEPOSIT_AMOUNT,
+        "A deposit of exactly the minimum should be credited"
+    );
+
+    Ok(())
+}
+
+/// Test that a deposit note carrying a non-fungible asset is rejected instead of having
+/// the first word of its data hash credited as an amount.
+#[tokio::test]
+async fn deposit_non_fungible_should_fail() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let sender = builder.add_existing_wallet(Auth::BasicAuth)?;
+
+    // Build contracts
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+
+    // Create a deposit note carrying a non-fungible asset
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_NON_FUNGIBLE_FAUCET)?;
+    let details = NonFungibleAssetDetails::new(faucet_id.prefix(), vec![1, 2, 3, 4])?;
+    let non_fungible_asset = NonFungibleAsset::new(&details)?;
+    let note_assets = NoteAssets::new(vec![Asset::NonFungible(non_fungible_asset)])?;
+
+    let deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            assets: note_assets,
+            ..Default::default()
+        },
+    )?;
+
+    // Add the deposit note to the mockchain, then create and initialize the bank
+    builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+
+    let InitializedBank {
+        bank_account,
+        mock_chain,
+        ..
+    } = create_initialized_bank(builder, AccountCreationConfig::bank_default()?).await?;
+
+    // Build the transaction context
+    let tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+        .build()?;
+
+    // Execute should fail because the asset is not fungible
+    let result = tx_context.execute().await;
+
+    assert!(
+        result.is_err(),
+        "Expected transaction to fail due to a non-fungible deposit asset, but it succeeded"
+    );
+
+    Ok(())
+}
+
+/// Test that a deposit taking the depositor's balance past the bank's balance ceiling fails.
+///
+/// Each deposit is below `MAX_DEPOSIT_AMOUNT`, but together they exceed the ceiling the
+/// bank was created with, so the second deposit must be rejected instead of growing
+/// (and, near the field modulus, wrapping) the balance.
+#[tokio::test]
+async fn deposit_exceeds_total_balance_should_fail() -> anyhow::Result<()> {
+    let deposit_amount: u64 = 1000;
+    let max_total_balance: u64 = 1500;
+
+    let result = run_scenario(Scenario {
+        faucets: vec![FaucetSpec {
+            symbol: "TEST",
+            max_supply: deposit_amount * 2,
+        }],
+        depositors: vec![DepositorSpec {
+            assets: vec![(0, deposit_amount * 2)],
+        }],
+        bank_config: vec![(
+            "max_total_balance",
+            Word::from([Felt::new(max_total_balance), Felt::new(0), Felt::new(0), Felt::new(0)]),
+        )],
+        operations: vec![
+            Operation::Deposit {
+                depositor: 0,
+                faucet: 0,
+                amount: deposit_amount,
+            },
+            Operation::Deposit {
+                depositor: 0,
+                faucet: 0,
+                amount: deposit_amount,
+            },
+        ],
+        ..Default::default()
+    })
+    .await;
+
+    // The first deposit (operation 0) succeeds and the second (operation 1) is rejected
+    let failure = result
+        .err()
+        .expect("Expected the second deposit to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 1, "{}", failure);
+    let state = &failure.state;
+    assert_eq!(read_bank_config(&state.bank_account)?.max_total_balance, max_total_balance);
+    assert_eq!(state.balance(0, 0), deposit_amount, "Only the first deposit should be credited");
+
+    Ok(())
+}
+
+/// Test that a single deposit changes exactly one balance entry (plus the faucet index
+/// and first-deposit block entries for a first deposit, the per-block deposit total, and
+/// the funded faucet count for a faucet's first deposit) and adds exactly the deposited
+/// asset to the vault, and that any extra write is reported.
+#[tokio::test]
+async fn deposit_delta_test() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let deposit_amount: u64 = 1000;
+
+    // Create a faucet to mint test assets
+    let faucet =
+        builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", deposit_amount, Some(10))?;
+
+    // Create note sender account (the depositor)
+    let sender = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet.id(), deposit_amount)?.into()],
+    )?;
+
+    // Build contracts
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+
+    // Create the bank account with named storage slots
+    let balances_slot = bank_slot_name("balances")?;
+    let bank_cfg = AccountCreationConfig::bank_default()?;
+
+    let deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount)?.into()])?,
+            ..Default::default()
+        },
+    )?;
+
+    builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+
+    // Create and initialize the bank
+    let InitializedBank {
+        bank_account,
+        mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
+
+    // Execute the deposit
+    let tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+        .build()?;
+
+    let executed_transaction = tx_context.execute().await?;
+
+    // Key format: [prefix, suffix, faucet_prefix, faucet_suffix]
+    let depositor_key = Word::from([
+        sender.id().prefix().as_felt(),
+        sender.id().suffix(),
+        faucet.id().prefix().as_felt(),
+        faucet.id().suffix(),
+    ]);
+
+    // The first deposit of a faucet also adds it to the depositor's faucet index, and
+    // the depositor's first deposit records the block it happened in; every deposit
+    // counts against the bank-wide per-block throttle and the faucet's total, and the
+    // faucet's first deposit counts it as funded
+    let deposit_block = mock_chain.latest_block_header().block_num().as_u32();
+    let first_deposit_blocks_slot = bank_slot_name("first_deposit_blocks")?;
+    let depositor_faucet_counts_slot = bank_slot_name("depositor_faucet_counts")?;
+    let depositor_faucets_slot = bank_slot_name("depositor_faucets")?;
+    let block_deposits_slot = bank_slot_name("block_deposits")?;
+    let totals_slot = bank_slot_name("totals")?;
+    let funded_faucets_slot = bank_slot_name("funded_faucets")?;
+
+    let expected = ExpectedDelta {
+        storage_changes: vec![
+            StorageChange::MapEntry {
+                slot: balances_slot,
+                key: depositor_key,
+                value: Word::from([
+                    Felt::new(0),
+                    Felt::new(0),
+                    Felt::new(0),
+                    Felt::new(deposit_amount),
+                ]),
+            },
+            StorageChange::MapEntry {
+                slot: depositor_faucet_counts_slot,
+                key: Word::from([
+                    sender.id().prefix().as_felt(),
+                    sender.id().suffix(),
+                    Felt::new(0),
+                    Felt::new(0),
+                ]),
+                value: Word::from([Felt::new(0), Felt::new(0), Felt::new(0), Felt::new(1)]),
+            },
+            StorageChange::MapEntry {
+                slot: depositor_faucets_slot,
+                key: Word::from([
+                    sender.id().prefix().as_felt(),
+                    sender.id().suffix(),
+                    Felt::new(0),
+                    Felt::new(0),
+                ]),
+                value: Word::from([
+                    faucet.id().prefix().as_felt(),
+                    faucet.id().suffix(),
+                    Felt::new(0),
+                    Felt::new(0),
+                ]),
+            },
+            StorageChange::MapEntry {
+                slot: first_deposit_blocks_slot,
+                key: Word::from([
+                    sender.id().prefix().as_felt(),
+                    sender.id().suffix(),
+                    Felt::new(0),
+                    Felt::new(0),
+                ]),
+                value: Word::from([
+                    Felt::new(0),
+                    Felt::new(0),
+                    Felt::new(0),
+                    Felt::new(deposit_block as u64),
+                ]),
+            },
+            StorageChange::Value {
+                slot: block_deposits_slot,
+                value: Word::from([
+                    Felt::new(deposit_amount),
+                    Felt::new(deposit_block as u64),
+                    Felt::new(0),
+                    Felt::new(0),
+                ]),
+            },
+            StorageChange::MapEntry {
+                slot: totals_slot,
+                key: Word::from([
+                    faucet.id().prefix().as_felt(),
+                    faucet.id().suffix(),
+                    Felt::new(0),
+                    Felt::new(0),
+                ]),
+                value: Word::from([
+                    Felt::new(0),
+                    Felt::new(0),
+                    Felt::new(0),
+                    Felt::new(deposit_amount),
+                ]),
+            },
+            StorageChange::Value {
+                slot: funded_faucets_slot,
+                value: Word::from([Felt::new(1), Felt::new(0), Felt::new(0), Felt::new(0)]),
+            },
+        ],
+        vault_changes: vec![(faucet.id(), deposit_amount as i64)],
+    };
+
+    assert_delta_matches(&executed_transaction, &expected);
+
+    // Leaving the balance write out of the expectation makes it an unexpected extra write
+    let incomplete = ExpectedDelta {
+        storage_changes: expected.storage_changes[1..].to_vec(),
+        ..expected
+    };
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        assert_delta_matches(&executed_transaction, &incomplete)
+    }));
+
+    assert!(
+        result.is_err(),
+        "Expected the unlisted balance write to be reported, but the delta matched"
+    );
+
+    Ok(())
+}
+
+/// Test that deposits fail when the bank has not been initialized.
+///
+/// The bank must be initialized via a transaction script before deposits
+/// can be accepted. This test verifies that attempting to deposit before
+/// initialization causes the transaction to fail.
+#[tokio::test]
+async fn deposit_without_init_should_fail() -> anyhow::Result<()> {
+    // Note: We intentionally do NOT initialize the bank
+    let mut fixture = BankFixture::builder().uninitialized().build().await?;
+
+    // Execute should fail because the bank is not initialized
+    let result = fixture.deposit(1000).await;
+
+    assert!(
+        result.is_err(),
+        "Expected deposit to fail when bank not initialized, but it succeeded"
+    );
+
+    println!("Uninitialized deposit correctly rejected - bank must be initialized first");
+    Ok(())
+}
+
+/// Test that the client-side initialization check refuses an uninitialized bank with a
+/// clear message, and passes the same bank once it is initialized.
+#[tokio::test]
+async fn ensure_bank_initialized_test() -> anyhow::Result<()> {
+    let uninitialized = BankFixture::builder().uninitialized().build().await?;
+    let error = ensure_bank_initialized(&uninitialized.bank_account)
+        .err()
+        .expect("Expected an uninitialized bank to be refused, but it passed");
+    assert!(
+        error.to_string().contains("is not initialized"),
+        "Unexpected error for an uninitialized bank: {}",
+        error
+    );
+
+    let initialized = BankFixture::builder().build().await?;
+    ensure_bank_initialized(&initialized.bank_account)?;
+
+    Ok(())
+}
+
+/// Test that initializing an already initialized bank is rejected.
+///
+/// `initialize` asserts the `initialized` flag is still zero, so running the init
+/// transaction script a second time must fail and leave the flag at 1.
+#[tokio::test]
+async fn double_initialize_should_fail() -> anyhow::Result<()> {
+    // The fixture has already run the init tx script once
+    let fixture = BankFixture::builder().build().await?;
+
+    let initialized_slot = bank_slot_name("initialized")?;
+    let initialized_word = Word::from([Felt::new(1), Felt::new(0), Felt::new(0), Felt::new(0)]);
+    assert_word_eq(
+        fixture.bank_account.storage().get_item(&initialized_slot)?,
+        initialized_word,
+        "Initialized flag after first init",
+    );
+
+    // Run the init tx script a second time
+    let init_tx_script_package =
+        build_project_cached(Path::new("../contracts/init-tx-script"), true)?;
+    let init_program = init_tx_script_package.unwrap_program();
+    let init_tx_script = TransactionScript::new((*init_program).clone());
+
+    let init_tx_context = fixture
+        .mock_chain
+        .build_tx_context(fixture.bank_account.id(), &[], &[])?
+        .tx_script(init_tx_script)
+        .build()?;
+
+    let result = init_tx_context.execute().await;
+
+    assert!(
+        result.is_err(),
+        "Expected second initialization to fail, but it succeeded"
+    );
+
+    // The committed bank state still has the flag set exactly once
+    let committed_bank = fixture.mock_chain.committed_account(fixture.bank_account.id())?;
+    assert_word_eq(
+        committed_bank.storage().get_item(&initialized_slot)?,
+        initialized_word,
+        "Initialized flag after rejected second init",
+    );
+
+    println!("Double initialization correctly rejected");
+    Ok(())
+}
+
+/// Test that a bank created with auto-initialize enabled accepts its first deposit
+/// without a separate init transaction, and that the deposit flips the initialized flag.
+#[tokio::test]
+async fn deposit_auto_initialize_test() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let deposit_amount: u64 = 1000;
+
+    // Create a faucet to mint test assets
+    let faucet =
+        builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", deposit_amount, Some(10))?;
+
+    // Create note sender account (the depositor)
+    let sender = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet.id(), deposit_amount)?.into()],
+    )?;
+
+    // Build contracts
+    let bank_package = build_project_cached(Path::new("../contracts/bank-account"), true)?;
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+
+    // Create the bank account with auto-initialize enabled
+    // Note: We intentionally do NOT run the init transaction script
+    let initialized_slot = bank_slot_name("initialized")?;
+    let storage_slots = AccountCreationConfig::bank_default()?.storage_slots;
+    let auto_initialize_slot = bank_slot_name("auto_initialize")?;
+    let storage_slots = storage_slots
+        .into_iter()
+        .map(|slot| {
+            if slot.name() == &auto_initialize_slot {
+                StorageSlot::with_value(
+                    auto_initialize_slot.clone(),
+                    Word::from([Felt::new(1), Felt::new(0), Felt::new(0), Felt::new(0)]),
+                )
+            } else {
+                slot
+            }
+        })
+        .collect();
+    let bank_cfg = AccountCreationConfig {
+        storage_slots,
+        ..Default::default()
+    };
+
+    let mut bank_account =
+        create_testing_account_from_package(bank_package.clone(), bank_cfg).await?;
+    assert_bank_slots(&bank_account);
+
+    let deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount)?.into()])?,
+            ..Default::default()
+        },
+    )?;
+
+    builder.add_account(bank_account.clone())?;
+    builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+
+    let mut mock_chain = builder.build()?;
+
+    // Deposit straight into the never-initialized bank
+    let tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+        .build()?;
+
+    let executed_transaction = tx_context.execute().await?;
+    bank_account.apply_delta(executed_transaction.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_transaction)?;
+    mock_chain.prove_next_block()?;
+
+    // The deposit initialized the bank
+    assert_word_eq(
+        bank_account.storage().get_item(&initialized_slot)?,
+        Word::from([Felt::new(1), Felt::new(0), Felt::new(0), Felt::new(0)]),
+        "Initialized flag after first deposit",
+    );
+
+    // And credited the depositor
+    assert_balance(&bank_account, sender.id(), faucet.id(), deposit_amount);
+
+    Ok(())
+}
+
+/// Test that the per-depositor faucet index lists every faucet a depositor has
+/// deposited, once each, in order of first deposit.
+#[tokio::test]
+async fn deposit_faucet_index_test() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    // Create two faucets to mint test assets
+    let faucet_a = builder.add_existing_basic_faucet(Auth::BasicAuth, "AAA", 1000, Some(10))?;
+    let faucet_b = builder.add_existing_basic_faucet(Auth::BasicAuth, "BBB", 1000, Some(10))?;
+
+    // Create note sender account (the depositor)
+    let sender = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [
+            FungibleAsset::new(faucet_a.id(), 300)?.into(),
+            FungibleAsset::new(faucet_b.id(), 200)?.into(),
+        ],
+    )?;
+
+    // Build contracts
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+
+    // Create the bank account with named storage slots
+    let bank_cfg = AccountCreationConfig::bank_default()?;
+
+    // The first note deposits both faucets, the second deposits faucet A again
+    let first_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![
+                FungibleAsset::new(faucet_a.id(), 100)?.into(),
+                FungibleAsset::new(faucet_b.id(), 200)?.into(),
+            ])?,
+            ..Default::default()
+        },
+    )?;
+    let second_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet_a.id(), 200)?.into()])?,
+            ..Default::default()
+        },
+    )?;
+
+    builder.add_output_note(OutputNote::Full(first_note.clone()));
+    builder.add_output_note(OutputNote::Full(second_note.clone()));
+
+    // Create and initialize the bank
+    let InitializedBank {
+        mut bank_account,
+        mut mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
+
+    // Consume both deposit notes
+    let tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[first_note.id(), second_note.id()], &[])?
+        .build()?;
+
+    let executed_transaction = tx_context.execute().await?;
+    bank_account.apply_delta(executed_transaction.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_transaction)?;
+    mock_chain.prove_next_block()?;
+
+    assert_eq!(
+        get_depositor_faucets(&bank_account, sender.id())?,
+        vec![faucet_a.id(), faucet_b.id()],
+        "Faucet index should list each deposited faucet once, in order of first deposit"
+    );
+
+    Ok(())
+}
+
+/// Test that a note carrying several assets credits each faucet's balance through a
+/// single `deposit_batch` call.
+#[tokio::test]
+async fn deposit_batch_test() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    // Create three faucets to mint test assets
+    let faucet_a = builder.add_existing_basic_faucet(Auth::BasicAuth, "AAA", 1000, Some(10))?;
+    let faucet_b = builder.add_existing_basic_faucet(Auth::BasicAuth, "BBB", 1000, Some(10))?;
+    let faucet_c = builder.add_existing_basic_faucet(Auth::BasicAuth, "CCC", 1000, Some(10))?;
+    let deposits = [(faucet_a.id(), 100), (faucet_b.id(), 200), (faucet_c.id(), 300)];
+
+    // Create note sender account (the depositor)
+    let sender = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        deposits
+            .iter()
+            .map(|&(faucet, amount)| Ok(FungibleAsset::new(faucet, amount)?.into()))
+            .collect::<anyhow::Result<Vec<Asset>>>()?,
+    )?;
+
+    // Build contracts
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+
+    // Create the bank account with named storage slots
+    let bank_cfg = AccountCreationConfig::bank_default()?;
+
+    // One deposit note carries all three assets
+    let deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(
+                deposits
+                    .iter()
+                    .map(|&(faucet, amount)| Ok(FungibleAsset::new(faucet, amount)?.into()))
+                    .collect::<anyhow::Result<Vec<Asset>>>()?,
+            )?,
+            ..Default::default()
+        },
+    )?;
+
+    builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+
+    // Create and initialize the bank
+    let InitializedBank {
+        mut bank_account,
+        mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
+
+    // Consume the deposit note
+    let tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+        .build()?;
+
+    let executed_transaction = tx_context.execute().await?;
+    bank_account.apply_delta(executed_transaction.account_delta())?;
+
+    // Each faucet has its own balance key, credited with that faucet's amount
+    for (faucet, amount) in deposits {
+        assert_balance(&bank_account, sender.id(), faucet, amount);
+    }
+
+    Ok(())
+}
+
+/// Test that a depositor's balances in two different faucets are both listed, keyed the
+/// way the balances map keys them, in order of first deposit.
+#[tokio::test]
+async fn deposit_all_balances_test() -> anyhow::Result<()> {
+    let result = run_scenario(Scenario {
+        faucets: vec![
+            FaucetSpec {
+                symbol: "AAA",
+                max_supply: 10000,
+            },
+            FaucetSpec {
+                symbol: "BBB",
+                max_supply: 10000,
+            },
+        ],
+        depositors: vec![DepositorSpec {
+            assets: vec![(0, 1000), (1, 2000)],
+        }],
+        operations: vec![
+            Operation::Deposit {
+                depositor: 0,
+                faucet: 1,
+                amount: 700,
+            },
+            Operation::Deposit {
+                depositor: 0,
+                faucet: 0,
+                amount: 300,
+            },
+        ],
+        ..Default::default()
+    })
+    .await?;
+
+    let depositor = result.depositor_ids[0];
+    let key_for = |faucet: usize| {
+        let faucet = result.faucet_ids[faucet];
+        Word::from([
+            depositor.prefix().as_felt(),
+            depositor.suffix(),
+            faucet.prefix().as_felt(),
+            faucet.suffix(),
+        ])
+    };
+
+    assert_eq!(
+        get_all_balances(&result.bank_account, depositor)?,
+        vec![(key_for(1), 700), (key_for(0), 300)],
+        "Both faucet balances should be listed in order of first deposit"
+    );
+
+    Ok(())
+}
+
+/// Test that a faucet's total tracks the sum of every depositor's balance in it, through
+/// deposits by two depositors and a partial withdrawal, and matches the vault.
+#[tokio::test]
+async fn deposit_total_test() -> anyhow::Result<()> {
+    let result = run_scenario(Scenario {
+        faucets: vec![FaucetSpec {
+            symbol: "TEST",
+            max_supply: 10000,
+        }],
+        depositors: vec![
+            DepositorSpec {
+                assets: vec![(0, 1000)],
+            },
+            DepositorSpec {
+                assets: vec![(0, 1000)],
+            },
+        ],
+        operations: vec![
+            Operation::Deposit {
+                depositor: 0,
+                faucet: 0,
+                amount: 300,
+            },
+            Operation::Deposit {
+                depositor: 1,
+                faucet: 0,
+                amount: 500,
+            },
+            Operation::Withdraw {
+                depositor: 0,
+                faucet: 0,
+                amount: 100,
+            },
+        ],
+        ..Default::default()
+    })
+    .await?;
+
+    let faucet = result.faucet_ids[0];
+    let total = get_total(&result.bank_account, faucet)?;
+
+    assert_eq!(
+        total,
+        result.balance(0, 0) + result.balance(1, 0),
+        "The faucet total should equal the sum of depositor balances"
+    );
+    assert_eq!(total, 700);
+    assert_eq!(
+        result.bank_account.vault().get_balance(faucet)?,
+        total,
+        "The faucet total should match the vault's holdings"
+    );
+
+    Ok(())
+}
+
+/// Test that a bank configured with a default faucet only accepts that faucet's assets.
+///
+/// The init transaction script receives the default faucet as its argument. Afterwards,
+/// a deposit of an asset issued by a different faucet must be rejected, while a deposit
+/// from the default faucet is credited as usual.
+#[tokio::test]
+async fn deposit_default_faucet_test() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    // Create the default faucet and a second, unrelated faucet
+    let faucet = builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", 1000, Some(10))?;
+    let other_faucet =
+        builder.add_existing_basic_faucet(Auth::BasicAuth, "OTHER", 1000, Some(10))?;
+
+    // Create note sender account (the depositor) holding both assets
+    let sender = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [
+            FungibleAsset::new(faucet.id(), 100)?.into(),
+            FungibleAsset::new(other_faucet.id(), 100)?.into(),
+        ],
+    )?;
+
+    // Build contracts
+    let bank_package = build_project_cached(Path::new("../contracts/bank-account"), true)?;
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+    let init_tx_script_package =
+        build_project_cached(Path::new("../contracts/init-tx-script"), true)?;
+
+    // Create the bank account with named storage slots
+    let bank_cfg = AccountCreationConfig::bank_default()?;
+
+    let mut bank_account =
+        create_testing_account_from_package(bank_package.clone(), bank_cfg).await?;
+    assert_bank_slots(&bank_account);
+
+    // Create one deposit note per faucet
+    let deposit_amount: u64 = 100;
+    let default_deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount)?.into()])?,
+            ..Default::default()
+        },
+    )?;
+    let other_deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![
+                FungibleAsset::new(other_faucet.id(), deposit_amount)?.into(),
+            ])?,
+            ..Default::default()
+        },
+    )?;
+
+    // Add bank account and deposit notes to mockchain
+    builder.add_account(bank_account.clone())?;
+    builder.add_output_note(OutputNote::Full(default_deposit_note.clone()));
+    builder.add_output_note(OutputNote::Full(other_deposit_note.clone()));
+
+    // Build the mock chain
+    let mut mock_chain = builder.build()?;
+
+    // Initialize the bank with the default faucet passed as the script argument
+    let init_program = init_tx_script_package.unwrap_program();
+    let init_tx_script = TransactionScript::new((*init_program).clone());
+    let default_faucet_arg = Word::from([
+        faucet.id().prefix().as_felt(),
+        faucet.id().suffix(),
+        Felt::new(0),
+        Felt::new(0),
+    ]);
+
+    let init_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[], &[])?
+        .tx_script(init_tx_script)
+        .tx_script_args(default_faucet_arg)
+        .build()?;
+
+    let executed_init = init_tx_context.execute().await?;
+    bank_account.apply_delta(executed_init.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_init)?;
+    mock_chain.prove_next_block()?;
+
+    // A deposit from a faucet other than the default must be rejected
+    let other_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[other_deposit_note.id()], &[])?
+        .build()?;
+
+    let result = other_tx_context.execute().await;
+
+    assert!(
+        result.is_err(),
+        "Expected deposit from a non-default faucet to fail, but it succeeded"
+    );
+
+    // A deposit from the default faucet is accepted
+    let default_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[default_deposit_note.id()], &[])?
+        .build()?;
+
+    let executed_deposit = default_tx_context.execute().await?;
+    bank_account.apply_delta(executed_deposit.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_deposit)?;
+    mock_chain.prove_next_block()?;
+
+    // Depositor balance should equal the default-faucet deposit
+    assert_balance(&bank_account, sender.id(), faucet.id(), deposit_amount);
 
-    (initialized_slot, balances_slot, slots)
+    println!("Default faucet test passed - only the default faucet is accepted");
+    Ok(())
 }
 
+/// Test that a bank in refund mode returns a rejected deposit to the sender.
+///
+/// The bank is configured with a default faucet and refund mode enabled at creation.
+/// Depositing an asset from another faucet with refund parameters in the note inputs
+/// must succeed, mint a P2ID refund note back to the sender carrying the asset,
+/// leave the sender's balance untouched and record the refunded amount.
 #[tokio::test]
-async fn deposit_test() -> anyhow::Result<()> {
-    // Test that after executing the deposit note, the depositor's balance is updated
+async fn deposit_refund_mode_test() -> anyhow::Result<()> {
     let mut builder = MockChain::builder();
 
-    // Create a faucet to mint test assets
+    // Create the default faucet and a second, non-accepted faucet
     let faucet = builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", 1000, Some(10))?;
+    let other_faucet =
+        builder.add_existing_basic_faucet(Auth::BasicAuth, "OTHER", 1000, Some(10))?;
 
     // Create note sender account (the depositor)
     let sender = builder.add_existing_wallet_with_assets(
         Auth::BasicAuth,
-        [FungibleAsset::new(faucet.id(), 100)?.into()],
+        [FungibleAsset::new(other_faucet.id(), 100)?.into()],
     )?;
 
     // Build contracts
-    let bank_package = Arc::new(build_project_in_dir(
-        Path::new("../contracts/bank-account"),
-        true,
-    )?);
-    let deposit_note_package = Arc::new(build_project_in_dir(
-        Path::new("../contracts/deposit-note"),
-        true,
-    )?);
-    let init_tx_script_package = Arc::new(build_project_in_dir(
-        Path::new("../contracts/init-tx-script"),
-        true,
-    )?);
+    let bank_package = build_project_cached(Path::new("../contracts/bank-account"), true)?;
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+    let init_tx_script_package =
+        build_project_cached(Path::new("../contracts/init-tx-script"), true)?;
 
-    // Create the bank account with named storage slots
-    let (_initialized_slot, balances_slot, storage_slots) = bank_storage_slots();
+    // Create the bank account with refund mode enabled
+    let storage_slots = AccountCreationConfig::bank_default()?.storage_slots;
+    let refund_mode_slot = bank_slot_name("refund_mode")?;
+    let refunds_slot = bank_slot_name("refunds")?;
+    let storage_slots = storage_slots
+        .into_iter()
+        .map(|slot| {
+            if slot.name() == &refund_mode_slot {
+                StorageSlot::with_value(
+                    refund_mode_slot.clone(),
+                    Word::from([Felt::new(1), Felt::new(0), Felt::new(0), Felt::new(0)]),
+                )
+            } else {
+                slot
+            }
+        })
+        .collect();
     let bank_cfg = AccountCreationConfig {
         storage_slots,
         ..Default::default()
@@ -70,72 +1126,202 @@ async fn deposit_test() -> anyhow::Result<()> {
 
     let mut bank_account =
         create_testing_account_from_package(bank_package.clone(), bank_cfg).await?;
+    assert_bank_slots(&bank_account);
 
-    // Create a fungible asset to deposit
-    let deposit_amount: u64 = 1000;
-    let fungible_asset = FungibleAsset::new(faucet.id(), deposit_amount)?;
-    let note_assets = NoteAssets::new(vec![Asset::Fungible(fungible_asset)])?;
-
-    // Create the deposit note with assets attached
-    // The sender becomes the depositor
+    // Create a deposit note for the non-accepted faucet, carrying refund parameters:
+    // [serial_num (4), tag, note_type]
+    let deposit_amount: u64 = 100;
+    let refund_tag = NoteTag::with_account_target(sender.id());
+    let refund_serial_num = Word::from([
+        Felt::new(0x0badc0ffee0ddf00),
+        Felt::new(0x1),
+        Felt::new(0x2),
+        Felt::new(0x3),
+    ]);
     let deposit_note = create_testing_note_from_package(
         deposit_note_package.clone(),
         sender.id(),
         NoteCreationConfig {
-            assets: note_assets,
+            assets: NoteAssets::new(vec![
+                FungibleAsset::new(other_faucet.id(), deposit_amount)?.into(),
+            ])?,
+            inputs: vec![
+                refund_serial_num[0],
+                refund_serial_num[1],
+                refund_serial_num[2],
+                refund_serial_num[3],
+                Felt::new(refund_tag.as_u32() as u64),
+                Felt::new(1),
+            ],
             ..Default::default()
         },
     )?;
 
-    // Add bank account and deposit note to mockchain
     builder.add_account(bank_account.clone())?;
     builder.add_output_note(OutputNote::Full(deposit_note.clone()));
 
-    // Build the mock chain
     let mut mock_chain = builder.build()?;
 
-    // *********************************************************************************
-    // STEP 1: INITIALIZE THE BANK VIA TX SCRIPT
-    // *********************************************************************************
-    // The bank must be initialized before deposits are accepted.
-    // This is done via a transaction script that calls bank.initialize()
-
+    // Initialize the bank with the default faucet
     let init_program = init_tx_script_package.unwrap_program();
     let init_tx_script = TransactionScript::new((*init_program).clone());
 
     let init_tx_context = mock_chain
         .build_tx_context(bank_account.id(), &[], &[])?
         .tx_script(init_tx_script)
+        .tx_script_args(Word::from([
+            faucet.id().prefix().as_felt(),
+            faucet.id().suffix(),
+            Felt::new(0),
+            Felt::new(0),
+        ]))
         .build()?;
 
     let executed_init = init_tx_context.execute().await?;
-    bank_account.apply_delta(&executed_init.account_delta())?;
+    bank_account.apply_delta(executed_init.account_delta())?;
     mock_chain.add_pending_executed_transaction(&executed_init)?;
     mock_chain.prove_next_block()?;
 
-    println!("Bank initialized successfully");
-
-    // *********************************************************************************
-    // STEP 2: DEPOSIT
-    // *********************************************************************************
+    // Expected P2ID refund note returning the asset to the sender
+    let refund_note = Note::new(
+        NoteAssets::new(vec![FungibleAsset::new(other_faucet.id(), deposit_amount)?.into()])?,
+        NoteMetadata::new(bank_account.id(), NoteType::Public, refund_tag),
+        build_p2id_recipient(sender.id(), refund_serial_num)?,
+    );
 
-    // Build the transaction context where bank consumes the deposit note
     let tx_context = mock_chain
         .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(refund_note)])
         .build()?;
 
-    // Execute the transaction
     let executed_transaction = tx_context.execute().await?;
+    bank_account.apply_delta(executed_transaction.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_transaction)?;
+    mock_chain.prove_next_block()?;
+
+    // The sender was not credited and the refund was recorded
+    let depositor_key = Word::from([
+        sender.id().prefix().as_felt(),
+        sender.id().suffix(),
+        other_faucet.id().prefix().as_felt(),
+        other_faucet.id().suffix(),
+    ]);
+
+    // Rejected deposit must not credit the depositor
+    assert_balance(&bank_account, sender.id(), other_faucet.id(), 0);
+    assert_word_eq(
+        bank_account.storage().get_map_item(&refunds_slot, depositor_key)?,
+        Word::from([Felt::new(0), Felt::new(0), Felt::new(0), Felt::new(deposit_amount)]),
+        "Refunded amount should be recorded",
+    );
+    assert_eq!(
+        bank_account.vault().get_balance(other_faucet.id())?,
+        0,
+        "Refunded asset must not remain in the bank vault"
+    );
+
+    println!("Refund mode test passed - rejected deposit returned to the sender");
+    Ok(())
+}
+
+/// Test that a bank in clamp mode credits an over-cap deposit up to the cap and
+/// refunds only the excess to the sender.
+#[tokio::test]
+async fn deposit_clamp_mode_test() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    // MAX_DEPOSIT_AMOUNT in the bank contract
+    let max_deposit_amount: u64 = 1_000_000;
+    let excess_amount: u64 = 400;
+    let deposit_amount = max_deposit_amount + excess_amount;
+
+    // Create a faucet to mint test assets
+    let faucet =
+        builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", deposit_amount, Some(10))?;
+
+    // Create note sender account (the depositor)
+    let sender = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet.id(), deposit_amount)?.into()],
+    )?;
+
+    // Build contracts
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+
+    // Create the bank account with refund mode and clamping enabled
+    let storage_slots = AccountCreationConfig::bank_default()?.storage_slots;
+    let refund_mode_slot = bank_slot_name("refund_mode")?;
+    let refunds_slot = bank_slot_name("refunds")?;
+    let storage_slots = storage_slots
+        .into_iter()
+        .map(|slot| {
+            if slot.name() == &refund_mode_slot {
+                StorageSlot::with_value(
+                    refund_mode_slot.clone(),
+                    Word::from([Felt::new(1), Felt::new(1), Felt::new(0), Felt::new(0)]),
+                )
+            } else {
+                slot
+            }
+        })
+        .collect();
+    let bank_cfg = AccountCreationConfig {
+        storage_slots,
+        ..Default::default()
+    };
+
+    // Deposit note carrying the refund parameters
+    let refund_tag = NoteTag::with_account_target(sender.id());
+    let refund_serial_num = Word::from([
+        Felt::new(0x0c1a0c1a0c1a0c1a),
+        Felt::new(0x1),
+        Felt::new(0x2),
+        Felt::new(0x3),
+    ]);
+    let deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount)?.into()])?,
+            inputs: vec![
+                refund_serial_num[0],
+                refund_serial_num[1],
+                refund_serial_num[2],
+                refund_serial_num[3],
+                Felt::new(refund_tag.as_u32() as u64),
+                Felt::new(1),
+            ],
+            ..Default::default()
+        },
+    )?;
+
+    builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+
+    // Create and initialize the bank
+    let InitializedBank {
+        mut bank_account,
+        mut mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
 
-    // Apply the account delta to the bank account
-    bank_account.apply_delta(&executed_transaction.account_delta())?;
+    // Expected P2ID refund note returning only the excess
+    let refund_note = Note::new(
+        NoteAssets::new(vec![FungibleAsset::new(faucet.id(), excess_amount)?.into()])?,
+        NoteMetadata::new(bank_account.id(), NoteType::Public, refund_tag),
+        build_p2id_recipient(sender.id(), refund_serial_num)?,
+    );
+
+    let tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(refund_note)])
+        .build()?;
 
-    // Add the executed transaction to the mockchain and prove
+    let executed_transaction = tx_context.execute().await?;
+    bank_account.apply_delta(executed_transaction.account_delta())?;
     mock_chain.add_pending_executed_transaction(&executed_transaction)?;
     mock_chain.prove_next_block()?;
 
-    // Create the key for the depositor (sender) in the storage map
-    // Key format: [prefix, suffix, faucet_prefix, faucet_suffix]
+    // The sender was credited up to the cap and the excess refund was recorded
     let depositor_key = Word::from([
         sender.id().prefix().as_felt(),
         sender.id().suffix(),
@@ -143,63 +1329,191 @@ async fn deposit_test() -> anyhow::Result<()> {
         faucet.id().suffix(),
     ]);
 
-    // Get the depositor's balance from the bank's storage using named slot
-    let balance = bank_account.storage().get_map_item(&balances_slot, depositor_key)?;
+    // Clamped deposit should credit up to the cap
+    assert_balance(&bank_account, sender.id(), faucet.id(), max_deposit_amount);
+    assert_word_eq(
+        bank_account.storage().get_map_item(&refunds_slot, depositor_key)?,
+        Word::from([Felt::new(0), Felt::new(0), Felt::new(0), Felt::new(excess_amount)]),
+        "Only the excess should be recorded as refunded",
+    );
+    assert_eq!(
+        bank_account.vault().get_balance(faucet.id())?,
+        max_deposit_amount,
+        "Only the credited amount should remain in the bank vault"
+    );
 
-    // The balance should be stored as [amount, 0, 0, 0] in the Word
-    // But since we store just the Felt, check the first element
-    let expected_balance = Word::from([
-        Felt::new(0),
-        Felt::new(0),
-        Felt::new(0),
-        Felt::new(deposit_amount),
-    ]);
+    Ok(())
+}
 
-    assert_eq!(
-        balance, expected_balance,
-        "Depositor balance should equal the deposited amount"
+/// Test that the bank-wide per-block throttle rejects deposits whose combined amount in
+/// one block exceeds the limit, and that the allowance resets in the next block.
+#[tokio::test]
+async fn deposit_block_throttle_test() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let max_deposit_per_block: u64 = 1000;
+    // Different amounts keep the two deposit notes distinct
+    let first_amount: u64 = 600;
+    let second_amount: u64 = 500;
+
+    // Create a faucet to mint test assets
+    let faucet = builder.add_existing_basic_faucet(
+        Auth::BasicAuth,
+        "TEST",
+        first_amount + second_amount,
+        Some(10),
+    )?;
+
+    // Two depositors, each depositing less than the throttle on their own
+    let first_sender = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet.id(), first_amount)?.into()],
+    )?;
+    let second_sender = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet.id(), second_amount)?.into()],
+    )?;
+
+    // Build contracts
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+
+    // Create the bank account with a per-block deposit limit
+    let storage_slots = AccountCreationConfig::bank_default()?.storage_slots;
+    let max_deposit_per_block_slot = bank_slot_name("max_deposit_per_block")?;
+    let storage_slots = storage_slots
+        .into_iter()
+        .map(|slot| {
+            if slot.name() == &max_deposit_per_block_slot {
+                StorageSlot::with_value(
+                    max_deposit_per_block_slot.clone(),
+                    Word::from([
+                        Felt::new(max_deposit_per_block),
+                        Felt::new(0),
+                        Felt::new(0),
+                        Felt::new(0),
+                    ]),
+                )
+            } else {
+                slot
+            }
+        })
+        .collect();
+    let bank_cfg = AccountCreationConfig {
+        storage_slots,
+        ..Default::default()
+    };
+
+    let first_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        first_sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), first_amount)?.into()])?,
+            ..Default::default()
+        },
+    )?;
+    let second_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        second_sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), second_amount)?.into()])?,
+            ..Default::default()
+        },
+    )?;
+
+    builder.add_output_note(OutputNote::Full(first_note.clone()));
+    builder.add_output_note(OutputNote::Full(second_note.clone()));
+
+    // Create and initialize the bank
+    let InitializedBank {
+        mut bank_account,
+        mut mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
+
+    // Both deposits in the same block exceed the throttle, so the second one fails
+    let tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[first_note.id(), second_note.id()], &[])?
+        .build()?;
+    let result = tx_context.execute().await;
+
+    assert!(
+        result.is_err(),
+        "Expected the second deposit in the block to exceed the throttle, but it succeeded"
     );
 
-    println!("Deposit test passed! Deposited {} tokens", deposit_amount);
+    // The first deposit alone fits within the block's allowance
+    let tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[first_note.id()], &[])?
+        .build()?;
+    let executed_transaction = tx_context.execute().await?;
+    bank_account.apply_delta(executed_transaction.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_transaction)?;
+    mock_chain.prove_next_block()?;
+
+    // The allowance resets once the block advances, so the second deposit now succeeds
+    let tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[second_note.id()], &[])?
+        .build()?;
+    let executed_transaction = tx_context.execute().await?;
+    bank_account.apply_delta(executed_transaction.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_transaction)?;
+    mock_chain.prove_next_block()?;
+
+    for (sender, amount) in [(&first_sender, first_amount), (&second_sender, second_amount)] {
+        // Each depositor should be credited once the throttle allows it
+        assert_balance(&bank_account, sender.id(), faucet.id(), amount);
+    }
+
     Ok(())
 }
 
-/// Test that deposits exceeding MAX_DEPOSIT_AMOUNT (1,000,000) are rejected.
-///
-/// The bank account contract enforces a maximum deposit limit. This test verifies
-/// that attempting to deposit more than the allowed maximum causes the transaction
-/// to fail during execution.
+/// Test that the deposit preflight flags an uninitialized bank and a non-default faucet,
+/// passes a healthy bank, and reports the deposit limit left in the current block.
 #[tokio::test]
-async fn deposit_exceeds_max_should_fail() -> anyhow::Result<()> {
+async fn deposit_preflight_test() -> anyhow::Result<()> {
     let mut builder = MockChain::builder();
 
-    // Create a faucet with enough capacity for a large deposit
-    // MAX_DEPOSIT_AMOUNT in the contract is 1,000,000
-    let large_amount: u64 = 2_000_000; // Exceeds MAX_DEPOSIT_AMOUNT
-    let faucet = builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", large_amount, Some(10))?;
+    let max_deposit_per_block: u64 = 500;
+    let deposit_amount: u64 = 200;
+
+    // Create the default faucet and a second, non-accepted faucet
+    let faucet = builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", 1000, Some(10))?;
+    let other_faucet =
+        builder.add_existing_basic_faucet(Auth::BasicAuth, "OTHER", 1000, Some(10))?;
 
-    // Create note sender account (the depositor) with large asset balance
+    // Create note sender account (the depositor)
     let sender = builder.add_existing_wallet_with_assets(
         Auth::BasicAuth,
-        [FungibleAsset::new(faucet.id(), large_amount)?.into()],
+        [FungibleAsset::new(faucet.id(), deposit_amount)?.into()],
     )?;
 
     // Build contracts
-    let bank_package = Arc::new(build_project_in_dir(
-        Path::new("../contracts/bank-account"),
-        true,
-    )?);
-    let deposit_note_package = Arc::new(build_project_in_dir(
-        Path::new("../contracts/deposit-note"),
-        true,
-    )?);
-    let init_tx_script_package = Arc::new(build_project_in_dir(
-        Path::new("../contracts/init-tx-script"),
-        true,
-    )?);
+    let bank_package = build_project_cached(Path::new("../contracts/bank-account"), true)?;
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+    let init_tx_script_package =
+        build_project_cached(Path::new("../contracts/init-tx-script"), true)?;
 
-    // Create the bank account with named storage slots
-    let (_initialized_slot, _balances_slot, storage_slots) = bank_storage_slots();
+    // Create the bank account with a per-block deposit limit
+    let storage_slots = AccountCreationConfig::bank_default()?.storage_slots;
+    let max_deposit_per_block_slot = bank_slot_name("max_deposit_per_block")?;
+    let storage_slots = storage_slots
+        .into_iter()
+        .map(|slot| {
+            if slot.name() == &max_deposit_per_block_slot {
+                StorageSlot::with_value(
+                    max_deposit_per_block_slot.clone(),
+                    Word::from([
+                        Felt::new(max_deposit_per_block),
+                        Felt::new(0),
+                        Felt::new(0),
+                        Felt::new(0),
+                    ]),
+                )
+            } else {
+                slot
+            }
+        })
+        .collect();
     let bank_cfg = AccountCreationConfig {
         storage_slots,
         ..Default::default()
@@ -207,134 +1521,447 @@ async fn deposit_exceeds_max_should_fail() -> anyhow::Result<()> {
 
     let mut bank_account =
         create_testing_account_from_package(bank_package.clone(), bank_cfg).await?;
-
-    // Create a deposit note with amount exceeding the max
-    let fungible_asset = FungibleAsset::new(faucet.id(), large_amount)?;
-    let note_assets = NoteAssets::new(vec![Asset::Fungible(fungible_asset)])?;
+    assert_bank_slots(&bank_account);
 
     let deposit_note = create_testing_note_from_package(
         deposit_note_package.clone(),
         sender.id(),
         NoteCreationConfig {
-            assets: note_assets,
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount)?.into()])?,
             ..Default::default()
         },
     )?;
 
-    // Add bank account and deposit note to mockchain
     builder.add_account(bank_account.clone())?;
     builder.add_output_note(OutputNote::Full(deposit_note.clone()));
 
-    // Build the mock chain
     let mut mock_chain = builder.build()?;
 
-    // Initialize the bank first
+    // An uninitialized bank is a no-go
+    let block_num = mock_chain.latest_block_header().block_num().as_u32();
+    let report = preflight_bank_account(&bank_account, sender.id(), faucet.id(), block_num)?;
+    assert_eq!(
+        report,
+        PreflightReport {
+            initialized: false,
+            auto_initialize: false,
+            paused: false,
+            faucet_accepted: true,
+            depositor_blacklisted: false,
+            deposit_limit: max_deposit_per_block,
+        },
+        "Preflight of an uninitialized bank"
+    );
+    assert!(!report.is_go(), "An uninitialized bank should be a no-go");
+
+    // Initialize the bank with the default faucet passed as the script argument
     let init_program = init_tx_script_package.unwrap_program();
     let init_tx_script = TransactionScript::new((*init_program).clone());
+    let default_faucet_arg = Word::from([
+        faucet.id().prefix().as_felt(),
+        faucet.id().suffix(),
+        Felt::new(0),
+        Felt::new(0),
+    ]);
 
     let init_tx_context = mock_chain
         .build_tx_context(bank_account.id(), &[], &[])?
         .tx_script(init_tx_script)
+        .tx_script_args(default_faucet_arg)
         .build()?;
 
     let executed_init = init_tx_context.execute().await?;
-    bank_account.apply_delta(&executed_init.account_delta())?;
+    bank_account.apply_delta(executed_init.account_delta())?;
     mock_chain.add_pending_executed_transaction(&executed_init)?;
     mock_chain.prove_next_block()?;
 
-    // Build the transaction context
+    // The initialized bank is a go for its default faucet only
+    let block_num = mock_chain.latest_block_header().block_num().as_u32();
+    let report = preflight_bank_account(&bank_account, sender.id(), faucet.id(), block_num)?;
+    assert!(report.initialized, "The bank should be initialized");
+    assert!(report.is_go(), "A healthy bank should be a go");
+    assert!(
+        max_deposit_per_block < MAX_DEPOSIT_AMOUNT && report.deposit_limit == max_deposit_per_block,
+        "The per-block limit should bound the deposit limit"
+    );
+
+    let other_report =
+        preflight_bank_account(&bank_account, sender.id(), other_faucet.id(), block_num)?;
+    assert!(
+        !other_report.faucet_accepted && !other_report.is_go(),
+        "A faucet other than the default should be a no-go"
+    );
+
+    // A deposit uses up part of its block's allowance
+    let deposit_block = block_num;
     let tx_context = mock_chain
         .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
         .build()?;
+    let executed_deposit = tx_context.execute().await?;
+    bank_account.apply_delta(executed_deposit.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_deposit)?;
+    mock_chain.prove_next_block()?;
 
-    // Execute should fail due to max deposit constraint
-    let result = tx_context.execute().await;
+    assert_eq!(
+        preflight_bank_account(&bank_account, sender.id(), faucet.id(), deposit_block)?
+            .deposit_limit,
+        max_deposit_per_block - deposit_amount,
+        "Deposit limit left in the deposit's block"
+    );
+    assert_eq!(
+        preflight_bank_account(&bank_account, sender.id(), faucet.id(), deposit_block + 1)?
+            .deposit_limit,
+        max_deposit_per_block,
+        "Deposit limit once the block advances"
+    );
+
+    Ok(())
+}
+
+/// Test that the deposit preflight follows the asset allowlist and the blacklist like
+/// `deposit()` does: an allowed faucet other than the first one allowed is a go, a
+/// faucet left off the allowlist is not, and a blacklisted depositor is a no-go.
+#[tokio::test]
+async fn deposit_preflight_allowlist_blacklist_test() -> anyhow::Result<()> {
+    let result = run_scenario(Scenario {
+        faucets: vec![
+            FaucetSpec {
+                symbol: "AAA",
+                max_supply: 2000,
+            },
+            FaucetSpec {
+                symbol: "BBB",
+                max_supply: 1000,
+            },
+            FaucetSpec {
+                symbol: "CCC",
+                max_supply: 1000,
+            },
+        ],
+        depositors: vec![
+            DepositorSpec {
+                assets: vec![(0, 1000)],
+            },
+            DepositorSpec {
+                assets: vec![(0, 1000)],
+            },
+        ],
+        owner: Some(0),
+        operations: vec![
+            Operation::AllowAsset { sender: 0, faucet: 0 },
+            Operation::AllowAsset { sender: 0, faucet: 1 },
+            Operation::SetBlacklisted {
+                sender: 0,
+                account: 1,
+                blacklisted: true,
+            },
+        ],
+        ..Default::default()
+    })
+    .await?;
+    let preflight = |depositor: usize, faucet: usize| {
+        preflight_bank_account(
+            &result.bank_account,
+            result.depositor_ids[depositor],
+            result.faucet_ids[faucet],
+            0,
+        )
+    };
 
+    let allowed_report = preflight(0, 1)?;
     assert!(
-        result.is_err(),
-        "Expected transaction to fail due to exceeding max deposit amount, but it succeeded"
+        allowed_report.faucet_accepted && allowed_report.is_go(),
+        "An allowed faucet other than the first should be a go"
     );
 
-    println!(
-        "Max deposit constraint test passed - deposit of {} tokens correctly rejected (max is 1,000,000)",
-        large_amount
+    let disallowed_report = preflight(0, 2)?;
+    assert!(
+        !disallowed_report.faucet_accepted && !disallowed_report.is_go(),
+        "A faucet left off the allowlist should be a no-go"
+    );
+
+    let blacklisted_report = preflight(1, 0)?;
+    assert!(
+        blacklisted_report.faucet_accepted
+            && blacklisted_report.depositor_blacklisted
+            && !blacklisted_report.is_go(),
+        "A blacklisted depositor should be a no-go"
     );
+    assert!(
+        !preflight(0, 0)?.depositor_blacklisted,
+        "Only the blacklisted depositor should be flagged"
+    );
+
     Ok(())
 }
 
-/// Test that deposits fail when the bank has not been initialized.
+/// Test that a deposit note asking for a receipt credits the depositor and emits an
+/// asset-less receipt note recording the deposit.
 ///
-/// The bank must be initialized via a transaction script before deposits
-/// can be accepted. This test verifies that attempting to deposit before
-/// initialization causes the transaction to fail.
+/// Two deposits are made, one requesting a public receipt and one a private receipt.
+/// The receipt's recipient commits to its inputs, so matching each produced note's id
+/// against one built from the expected inputs shows it records the amount, the faucet
+/// and the depositor's new balance.
 #[tokio::test]
-async fn deposit_without_init_should_fail() -> anyhow::Result<()> {
+async fn deposit_receipt_test() -> anyhow::Result<()> {
     let mut builder = MockChain::builder();
 
+    let deposit_amount: u64 = 500;
+
     // Create a faucet to mint test assets
     let faucet = builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", 1000, Some(10))?;
 
     // Create note sender account (the depositor)
     let sender = builder.add_existing_wallet_with_assets(
         Auth::BasicAuth,
-        [FungibleAsset::new(faucet.id(), 100)?.into()],
+        [FungibleAsset::new(faucet.id(), deposit_amount * 2)?.into()],
     )?;
 
     // Build contracts
-    let bank_package = Arc::new(build_project_in_dir(
-        Path::new("../contracts/bank-account"),
-        true,
-    )?);
-    let deposit_note_package = Arc::new(build_project_in_dir(
-        Path::new("../contracts/deposit-note"),
-        true,
-    )?);
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
 
     // Create the bank account with named storage slots
-    // Note: We intentionally do NOT initialize the bank
-    let (_initialized_slot, _balances_slot, storage_slots) = bank_storage_slots();
+    let bank_cfg = AccountCreationConfig::bank_default()?;
+
+    // Deposit notes carrying receipt parameters:
+    // [serial_num (4), tag, note_type, receipt flag]
+    let receipt_tag = NoteTag::with_account_target(sender.id());
+    let receipt_cases = [
+        (
+            NoteType::Public,
+            Word::from([Felt::new(0x1001), Felt::new(0x1), Felt::new(0x2), Felt::new(0x3)]),
+        ),
+        (
+            NoteType::Private,
+            Word::from([Felt::new(0x2002), Felt::new(0x1), Felt::new(0x2), Felt::new(0x3)]),
+        ),
+    ];
+
+    let mut deposit_notes = Vec::new();
+    for (note_type, serial_num) in receipt_cases {
+        let deposit_note = create_testing_note_from_package(
+            deposit_note_package.clone(),
+            sender.id(),
+            NoteCreationConfig {
+                assets: NoteAssets::new(vec![
+                    FungibleAsset::new(faucet.id(), deposit_amount)?.into(),
+                ])?,
+                inputs: vec![
+                    serial_num[0],
+                    serial_num[1],
+                    serial_num[2],
+                    serial_num[3],
+                    Felt::new(receipt_tag.as_u32() as u64),
+                    Felt::new(note_type as u64),
+                    Felt::new(1),
+                ],
+                ..Default::default()
+            },
+        )?;
+        builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+        deposit_notes.push(deposit_note);
+    }
+
+    // Create and initialize the bank
+    let InitializedBank {
+        mut bank_account,
+        mut mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
+
+    for (round, ((note_type, serial_num), deposit_note)) in
+        receipt_cases.into_iter().zip(&deposit_notes).enumerate()
+    {
+        let new_balance = deposit_amount * (round as u64 + 1);
+
+        // Expected receipt: no assets, P2ID script, inputs recording the deposit
+        let expected_receipt = Note::new(
+            NoteAssets::default(),
+            NoteMetadata::new(bank_account.id(), note_type, receipt_tag),
+            NoteRecipient::new(
+                serial_num,
+                WellKnownNote::P2ID.script(),
+                NoteInputs::new(vec![
+                    Felt::new(deposit_amount),
+                    faucet.id().prefix().as_felt(),
+                    faucet.id().suffix(),
+                    Felt::new(new_balance),
+                ])?,
+            ),
+        );
+
+        let tx_context = mock_chain
+            .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+            .extend_expected_output_notes(vec![OutputNote::Full(expected_receipt.clone())])
+            .build()?;
+
+        let executed_transaction = tx_context.execute().await?;
+
+        assert_eq!(
+            executed_transaction.output_notes().num_notes(),
+            1,
+            "Expected exactly one receipt note"
+        );
+        let receipt = executed_transaction.output_notes().get_note(0);
+        assert_eq!(
+            receipt.id(),
+            expected_receipt.id(),
+            "Receipt does not record the expected amount, faucet and new balance"
+        );
+        assert_eq!(
+            receipt.metadata().note_type(),
+            note_type,
+            "Receipt has the wrong note type"
+        );
+
+        bank_account.apply_delta(executed_transaction.account_delta())?;
+        mock_chain.add_pending_executed_transaction(&executed_transaction)?;
+        mock_chain.prove_next_block()?;
+
+        // Deposit with receipt should credit the depositor
+        assert_balance(&bank_account, sender.id(), faucet.id(), new_balance);
+    }
+
+    Ok(())
+}
+
+/// Test that a bank with deposit events enabled creates one event note per credited
+/// deposit, tagged for the indexer, whose inputs record the depositor, faucet and
+/// amount, with distinct serial numbers from the bank's event count.
+#[tokio::test]
+async fn deposit_event_note_test() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let deposit_amounts: [u64; 2] = [300, 200];
+
+    // Create a faucet to mint test assets
+    let faucet = builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", 1000, Some(10))?;
+
+    // Create note sender account (the depositor) and the indexer the events are sent to
+    let sender = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet.id(), 500)?.into()],
+    )?;
+    let indexer = builder.add_existing_wallet(Auth::BasicAuth)?;
+
+    // Build contracts
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+
+    // Create the bank account with deposit events enabled for the indexer
+    let indexer_tag = NoteTag::with_account_target(indexer.id());
+    let storage_slots = AccountCreationConfig::bank_default()?.storage_slots;
+    let deposit_events_slot = bank_slot_name("deposit_events")?;
+    let storage_slots = storage_slots
+        .into_iter()
+        .map(|slot| {
+            if slot.name() == &deposit_events_slot {
+                StorageSlot::with_value(
+                    deposit_events_slot.clone(),
+                    Word::from([
+                        Felt::new(1),
+                        Felt::new(indexer_tag.as_u32() as u64),
+                        Felt::new(0),
+                        Felt::new(0),
+                    ]),
+                )
+            } else {
+                slot
+            }
+        })
+        .collect();
     let bank_cfg = AccountCreationConfig {
         storage_slots,
         ..Default::default()
     };
 
-    let bank_account =
-        create_testing_account_from_package(bank_package.clone(), bank_cfg).await?;
-
-    // Create a deposit note
-    let deposit_amount: u64 = 1000;
-    let fungible_asset = FungibleAsset::new(faucet.id(), deposit_amount)?;
-    let note_assets = NoteAssets::new(vec![Asset::Fungible(fungible_asset)])?;
-
-    let deposit_note = create_testing_note_from_package(
-        deposit_note_package.clone(),
-        sender.id(),
-        NoteCreationConfig {
-            assets: note_assets,
-            ..Default::default()
-        },
-    )?;
+    // Craft one deposit note per amount
+    let mut deposit_notes = Vec::new();
+    for amount in deposit_amounts {
+        let deposit_note = create_testing_note_from_package(
+            deposit_note_package.clone(),
+            sender.id(),
+            NoteCreationConfig {
+                assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), amount)?.into()])?,
+                ..Default::default()
+            },
+        )?;
+        builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+        deposit_notes.push(deposit_note);
+    }
 
-    // Add bank account and deposit note to mockchain
-    builder.add_account(bank_account.clone())?;
-    builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+    // Create and initialize the bank
+    let InitializedBank {
+        mut bank_account,
+        mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
+    assert_eq!(
+        read_bank_config(&bank_account)?.deposit_event_tag,
+        Some(indexer_tag),
+        "Decoded deposit event tag"
+    );
 
-    // Build the mock chain
-    let mock_chain = builder.build()?;
+    // Expected events: no assets, public, P2ID script root, event count as serial
+    let expected_events = deposit_amounts
+        .iter()
+        .enumerate()
+        .map(|(index, &amount)| {
+            Ok(Note::new(
+                NoteAssets::default(),
+                NoteMetadata::new(bank_account.id(), NoteType::Public, indexer_tag),
+                NoteRecipient::new(
+                    Word::from([Felt::new(index as u64 + 1), Felt::new(0), Felt::new(0), Felt::new(0)]),
+                    WellKnownNote::P2ID.script(),
+                    NoteInputs::new(vec![
+                        sender.id().prefix().as_felt(),
+                        sender.id().suffix(),
+                        faucet.id().prefix().as_felt(),
+                        Felt::new(amount),
+                    ])?,
+                ),
+            ))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
 
-    // Try to deposit WITHOUT initializing the bank first
+    // Consume both deposit notes in one transaction
+    let note_ids: Vec<_> = deposit_notes.iter().map(|note| note.id()).collect();
     let tx_context = mock_chain
-        .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+        .build_tx_context(bank_account.id(), &note_ids, &[])?
+        .extend_expected_output_notes(
+            expected_events.iter().cloned().map(OutputNote::Full).collect(),
+        )
         .build()?;
 
-    // Execute should fail because the bank is not initialized
-    let result = tx_context.execute().await;
+    let executed_transaction = tx_context.execute().await?;
 
-    assert!(
-        result.is_err(),
-        "Expected deposit to fail when bank not initialized, but it succeeded"
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(
+        output_notes.num_notes(),
+        expected_events.len(),
+        "Expected one event note per deposit"
     );
+    for (index, expected_event) in expected_events.iter().enumerate() {
+        assert_eq!(
+            output_notes.get_note(index).id(),
+            expected_event.id(),
+            "Event note {} does not record the expected depositor, faucet and amount",
+            index
+        );
+    }
+
+    // The event count advances once per deposit
+    bank_account.apply_delta(executed_transaction.account_delta())?;
+    assert_word_eq(
+        bank_account.storage().get_item(&deposit_events_slot)?,
+        Word::from([
+            Felt::new(1),
+            Felt::new(indexer_tag.as_u32() as u64),
+            Felt::new(2),
+            Felt::new(0),
+        ]),
+        "deposit_events slot",
+    );
+    assert_balance(&bank_account, sender.id(), faucet.id(), 500);
 
-    println!("Uninitialized deposit correctly rejected - bank must be initialized first");
     Ok(())
 }
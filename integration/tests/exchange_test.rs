@@ -0,0 +1,342 @@
+use integration::helpers::{
+    assert_p2id_recipient_targets, assert_word_eq, bank_slot_name, build_project_cached,
+    create_initialized_bank,
+    create_testing_note_from_package, AccountCreationConfig, InitializedBank, NoteCreationConfig,
+};
+
+use miden_client::{
+    account::{AccountId, StorageMap, StorageSlot},
+    asset::Asset,
+    note::{build_p2id_recipient, Note, NoteAssets, NoteMetadata, NoteTag, NoteType},
+    transaction::OutputNote,
+    Felt, Word,
+};
+use miden_client::asset::FungibleAsset;
+use miden_testing::{Auth, MockChain};
+use std::path::Path;
+
+/// A bank account configuration with a 1:2 exchange rate from `from` to `to`, whose
+/// vault starts with `vault_assets`
+fn exchange_bank_cfg(
+    from: AccountId,
+    to: AccountId,
+    vault_assets: Vec<Asset>,
+) -> anyhow::Result<AccountCreationConfig> {
+    // Rate key: [from_prefix, from_suffix, to_prefix, to_suffix]
+    // Rate value: [numerator, denominator, 0, 0]
+    let storage_slots = AccountCreationConfig::bank_default()?.storage_slots;
+    let exchange_rates_slot = bank_slot_name("exchange_rates")?;
+    let rate_key = Word::from([
+        from.prefix().as_felt(),
+        from.suffix(),
+        to.prefix().as_felt(),
+        to.suffix(),
+    ]);
+    let rate = Word::from([Felt::new(2), Felt::new(1), Felt::new(0), Felt::new(0)]);
+    let storage_slots = storage_slots
+        .into_iter()
+        .map(|slot| {
+            if slot.name() == &exchange_rates_slot {
+                StorageSlot::with_map(
+                    exchange_rates_slot.clone(),
+                    StorageMap::with_entries([(rate_key, rate)]).expect("Valid storage map"),
+                )
+            } else {
+                slot
+            }
+        })
+        .collect();
+
+    Ok(AccountCreationConfig {
+        storage_slots,
+        vault_assets,
+        ..Default::default()
+    })
+}
+
+/// The inputs of an exchange request note withdrawing `amount` of `from`, paid out in
+/// `to` to `sender` in a public P2ID note
+///
+/// # Note Inputs Layout (12 Felts)
+/// [0-3]: withdraw asset (amount, 0, faucet_suffix, faucet_prefix)
+/// [4-5]: payout faucet AccountId (prefix, suffix)
+/// [6-9]: serial_num
+/// [10]: tag
+/// [11]: note_type (1 = Public)
+fn exchange_request_inputs(
+    sender: AccountId,
+    from: AccountId,
+    to: AccountId,
+    amount: u64,
+    serial_num: Word,
+) -> Vec<Felt> {
+    vec![
+        Felt::new(amount),
+        Felt::new(0),
+        from.suffix(),
+        from.prefix().as_felt(),
+        to.prefix().as_felt(),
+        to.suffix(),
+        serial_num[0],
+        serial_num[1],
+        serial_num[2],
+        serial_num[3],
+        Felt::new(NoteTag::with_account_target(sender).as_u32() as u64),
+        Felt::new(1),
+    ]
+}
+
+/// Test an exchanged withdrawal at a 1:2 rate.
+///
+/// The bank's vault starts with a reserve of faucet B that no depositor is owed. The
+/// depositor deposits faucet A, then withdraws part of it as B: their A balance is
+/// debited by the withdrawn amount and the P2ID note pays twice that amount in B out
+/// of the reserve.
+#[tokio::test]
+async fn withdraw_exchanged_test() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let deposit_amount: u64 = 500;
+    let reserve_amount: u64 = 2000;
+    let withdraw_amount: u64 = 300;
+    let payout_amount = withdraw_amount * 2;
+
+    // Create the two faucets
+    let faucet_a = builder.add_existing_basic_faucet(Auth::BasicAuth, "AAA", 1000, Some(10))?;
+    let faucet_b =
+        builder.add_existing_basic_faucet(Auth::BasicAuth, "BBB", reserve_amount, Some(10))?;
+
+    // The depositor holds A
+    let sender = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet_a.id(), deposit_amount)?.into()],
+    )?;
+
+    // Build contracts
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+    let exchange_request_note_package =
+        build_project_cached(Path::new("../contracts/exchange-request-note"), true)?;
+
+    // Create the bank account with a 1:2 rate from A to B and a reserve of B
+    let balances_slot = bank_slot_name("balances")?;
+    let bank_cfg = exchange_bank_cfg(
+        faucet_a.id(),
+        faucet_b.id(),
+        vec![FungibleAsset::new(faucet_b.id(), reserve_amount)?.into()],
+    )?;
+
+    // Craft the deposit note
+    let deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet_a.id(), deposit_amount)?.into()])?,
+            ..Default::default()
+        },
+    )?;
+
+    // Craft the exchange request note
+    let p2id_tag = NoteTag::with_account_target(sender.id());
+    let p2id_output_note_serial_num = Word::from([
+        Felt::new(0x3030303030303030),
+        Felt::new(0x4040404040404040),
+        Felt::new(0x5050505050505050),
+        Felt::new(0x6060606060606060),
+    ]);
+    let exchange_request_note = create_testing_note_from_package(
+        exchange_request_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            inputs: exchange_request_inputs(
+                sender.id(),
+                faucet_a.id(),
+                faucet_b.id(),
+                withdraw_amount,
+                p2id_output_note_serial_num,
+            ),
+            ..Default::default()
+        },
+    )?;
+
+    builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+    builder.add_output_note(OutputNote::Full(exchange_request_note.clone()));
+
+    // Create and initialize the bank
+    let InitializedBank {
+        mut bank_account,
+        mut mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
+
+    // Deposit A
+    let deposit_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+        .build()?;
+
+    let executed_deposit = deposit_tx_context.execute().await?;
+    bank_account.apply_delta(executed_deposit.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_deposit)?;
+    mock_chain.prove_next_block()?;
+
+    // Withdraw A as B
+    let p2id_output_note = Note::new(
+        NoteAssets::new(vec![FungibleAsset::new(faucet_b.id(), payout_amount)?.into()])?,
+        NoteMetadata::new(bank_account.id(), NoteType::Public, p2id_tag),
+        build_p2id_recipient(sender.id(), p2id_output_note_serial_num)?,
+    );
+
+    let exchange_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[exchange_request_note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(p2id_output_note)])
+        .build()?;
+
+    let executed_exchange = exchange_tx_context.execute().await?;
+
+    let OutputNote::Full(payout_note) = executed_exchange.output_notes().get_note(0) else {
+        panic!("Expected the exchanged withdrawal to output a full P2ID note");
+    };
+    assert_p2id_recipient_targets(
+        payout_note,
+        sender.id(),
+        FungibleAsset::new(faucet_b.id(), payout_amount)?.into(),
+    );
+
+    bank_account.apply_delta(executed_exchange.account_delta())?;
+
+    // The depositor's A balance is debited by the withdrawn amount
+    let depositor_key = Word::from([
+        sender.id().prefix().as_felt(),
+        sender.id().suffix(),
+        faucet_a.id().prefix().as_felt(),
+        faucet_a.id().suffix(),
+    ]);
+    assert_word_eq(
+        bank_account.storage().get_map_item(&balances_slot, depositor_key)?,
+        Word::from([
+            Felt::new(0),
+            Felt::new(0),
+            Felt::new(0),
+            Felt::new(deposit_amount - withdraw_amount),
+        ]),
+        "Depositor A balance after exchanged withdrawal",
+    );
+
+    // The vault keeps all of A and pays out B from the reserve
+    assert_eq!(bank_account.vault().get_balance(faucet_a.id())?, deposit_amount);
+    assert_eq!(
+        bank_account.vault().get_balance(faucet_b.id())?,
+        reserve_amount - payout_amount
+    );
+
+    Ok(())
+}
+
+/// Test that an exchanged withdrawal cannot pay out another depositor's funds.
+///
+/// The bank has no reserve of faucet B: the only B in its vault is another depositor's
+/// balance. The vault holds more than the payout, but all of it is owed, so the
+/// exchange is rejected.
+#[tokio::test]
+async fn withdraw_exchanged_from_deposits_should_fail() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let deposit_amount: u64 = 500;
+    let other_deposit_amount: u64 = 2000;
+    let withdraw_amount: u64 = 300;
+
+    // Create the two faucets
+    let faucet_a = builder.add_existing_basic_faucet(Auth::BasicAuth, "AAA", 1000, Some(10))?;
+    let faucet_b =
+        builder.add_existing_basic_faucet(Auth::BasicAuth, "BBB", other_deposit_amount, Some(10))?;
+
+    // The exchanging depositor holds A, the other depositor holds B
+    let sender = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet_a.id(), deposit_amount)?.into()],
+    )?;
+    let other_depositor = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet_b.id(), other_deposit_amount)?.into()],
+    )?;
+
+    // Build contracts
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+    let exchange_request_note_package =
+        build_project_cached(Path::new("../contracts/exchange-request-note"), true)?;
+
+    // Create the bank account with a 1:2 rate from A to B and no reserve
+    let bank_cfg = exchange_bank_cfg(faucet_a.id(), faucet_b.id(), vec![])?;
+
+    // Craft the deposit notes and the exchange request note
+    let other_deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        other_depositor.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![
+                FungibleAsset::new(faucet_b.id(), other_deposit_amount)?.into(),
+            ])?,
+            ..Default::default()
+        },
+    )?;
+    let deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet_a.id(), deposit_amount)?.into()])?,
+            ..Default::default()
+        },
+    )?;
+    let exchange_request_note = create_testing_note_from_package(
+        exchange_request_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            inputs: exchange_request_inputs(
+                sender.id(),
+                faucet_a.id(),
+                faucet_b.id(),
+                withdraw_amount,
+                Word::from([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+            ),
+            ..Default::default()
+        },
+    )?;
+
+    builder.add_output_note(OutputNote::Full(other_deposit_note.clone()));
+    builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+    builder.add_output_note(OutputNote::Full(exchange_request_note.clone()));
+
+    // Create and initialize the bank
+    let InitializedBank {
+        mut bank_account,
+        mut mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
+
+    // Both depositors deposit
+    let deposit_tx_context = mock_chain
+        .build_tx_context(
+            bank_account.id(),
+            &[other_deposit_note.id(), deposit_note.id()],
+            &[],
+        )?
+        .build()?;
+
+    let executed_deposit = deposit_tx_context.execute().await?;
+    bank_account.apply_delta(executed_deposit.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_deposit)?;
+    mock_chain.prove_next_block()?;
+
+    // The vault alone would cover the payout
+    assert!(bank_account.vault().get_balance(faucet_b.id())? >= withdraw_amount * 2);
+
+    let exchange_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[exchange_request_note.id()], &[])?
+        .build()?;
+
+    assert!(
+        exchange_tx_context.execute().await.is_err(),
+        "Expected the exchange to be rejected when only deposits cover the payout"
+    );
+
+    Ok(())
+}
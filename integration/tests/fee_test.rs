@@ -0,0 +1,129 @@
+use integration::helpers::{get_fees, get_total, read_bank_config};
+use integration::scenario::{run_scenario, Operation, Scenario, ScenarioFailure};
+
+/// Depositor 1 deposits 1000 tokens and withdraws 900 of them in three withdrawals,
+/// leaving 9 tokens of fees (2 + 3 + 4) in the vault
+fn accrue_fees() -> Vec<Operation> {
+    let mut operations = vec![Operation::Deposit {
+        depositor: 1,
+        faucet: 0,
+        amount: 1000,
+    }];
+    for amount in [200, 300, 400] {
+        operations.push(Operation::Withdraw {
+            depositor: 1,
+            faucet: 0,
+            amount,
+        });
+    }
+    operations
+}
+
+/// Test that the owner can sweep fees accrued over several withdrawals, leaving only
+/// depositor balances in the vault.
+#[tokio::test]
+async fn sweep_fees_test() -> anyhow::Result<()> {
+    let mut operations = accrue_fees();
+    operations.push(Operation::SweepFees {
+        sender: 0,
+        faucet: 0,
+        amount: 9,
+    });
+
+    let scenario = Scenario::builder()
+        .faucet("TEST", 2000)
+        .depositor(&[(0, 1000)])
+        .depositor(&[(0, 1000)])
+        .config("fee_bps", 100)
+        .owner(0);
+    let result = run_scenario(scenario.operations(operations)).await?;
+    let faucet = result.faucet_ids[0];
+
+    assert_eq!(get_fees(&result.bank_account, faucet)?, 0, "Fees after the sweep");
+    assert_eq!(result.balance(1, 0), 100, "Depositor balance after the sweep");
+    assert_eq!(
+        result.bank_account.vault().get_balance(faucet)?,
+        get_total(&result.bank_account, faucet)?,
+        "Only depositor balances should remain in the vault after the sweep"
+    );
+
+    Ok(())
+}
+
+/// Test that only the owner can sweep the retained fees.
+#[tokio::test]
+async fn sweep_fees_by_non_owner_should_fail() -> anyhow::Result<()> {
+    let mut operations = accrue_fees();
+    operations.push(Operation::SweepFees {
+        sender: 1,
+        faucet: 0,
+        amount: 9,
+    });
+
+    let scenario = Scenario::builder()
+        .faucet("TEST", 2000)
+        .depositor(&[(0, 1000)])
+        .depositor(&[(0, 1000)])
+        .config("fee_bps", 100)
+        .owner(0);
+    let result = run_scenario(scenario.operations(operations)).await;
+
+    let failure = result
+        .err()
+        .expect("Expected the non-owner sweep to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 4, "{}", failure);
+    let state = &failure.state;
+    assert_eq!(
+        read_bank_config(&state.bank_account)?.owner,
+        Some(state.depositor_ids[0]),
+        "Depositor 0 should own the bank"
+    );
+    assert_eq!(
+        get_fees(&state.bank_account, state.faucet_ids[0])?,
+        9,
+        "The fees should stay retained"
+    );
+
+    Ok(())
+}
+
+/// Test that sweeping a faucet with no retained fees is rejected instead of emitting an
+/// empty P2ID note.
+#[tokio::test]
+async fn sweep_without_fees_should_fail() -> anyhow::Result<()> {
+    // Deposits alone retain no fees
+    let scenario = Scenario::builder()
+        .faucet("TEST", 2000)
+        .depositor(&[(0, 1000)])
+        .depositor(&[(0, 1000)])
+        .config("fee_bps", 100)
+        .owner(0);
+    let result = run_scenario(scenario.operations(vec![
+        Operation::Deposit {
+            depositor: 1,
+            faucet: 0,
+            amount: 1000,
+        },
+        Operation::SweepFees {
+            sender: 0,
+            faucet: 0,
+            amount: 9,
+        },
+    ]))
+    .await;
+
+    let failure = result
+        .err()
+        .expect("Expected the empty sweep to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 1, "{}", failure);
+    let state = &failure.state;
+    assert_eq!(
+        get_fees(&state.bank_account, state.faucet_ids[0])?,
+        0,
+        "No fees should be retained"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+// Do not link against libstd (i.e. anything defined in `std::`)
+#![no_std]
+#![feature(alloc_error_handler)]
+
+use miden::*;
+
+// Import the Account binding which wraps the bank-account component methods
+use crate::bindings::Account;
+
+/// Audit Transaction Script (test fixture)
+///
+/// Compares the bank's vault holdings for one faucet against its tracked amounts: the
+/// transaction only succeeds if `get_vault_balance` equals `get_total` plus `get_fees`.
+/// The script runs after the transaction's notes are consumed, so it audits the state
+/// they leave behind.
+///
+/// # Arguments
+/// * `arg` - [0, 0, faucet_prefix, faucet_suffix]
+/// * `account` - Mutable reference to the Account (bank component)
+#[tx_script]
+fn run(arg: Word, account: &mut Account) {
+    // The script argument arrives with its elements in reverse order relative to the
+    // Word supplied by the client, so restore the client-side layout first.
+    let arg = arg.reverse();
+    let faucet = AccountId::new(arg[2], arg[3]);
+
+    let vault_balance = account.get_vault_balance(faucet);
+    let tracked = account.get_total(faucet) + account.get_fees(faucet);
+    assert!(vault_balance == tracked, "Vault balance does not match the tracked total");
+}
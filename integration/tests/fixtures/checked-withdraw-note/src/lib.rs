@@ -0,0 +1,47 @@
+// Do not link against libstd (i.e. anything defined in `std::`)
+#![no_std]
+#![feature(alloc_error_handler)]
+
+use miden::*;
+
+// Import the bank account's generated bindings
+use crate::bindings::miden::bank_account::bank_account;
+
+/// Checked Withdraw Note Script (test fixture)
+///
+/// Like the withdraw request note, but also checks the balance and, optionally, the
+/// P2ID note index `withdraw` returns against expected values, so tests can observe
+/// them: the transaction only succeeds if they match.
+///
+/// # Note Inputs (11 or 12 Felts)
+/// [0-3]: withdraw asset (amount, 0, faucet_suffix, faucet_prefix)
+/// [4-7]: serial_num of the P2ID output note
+/// [8]: tag (P2ID note tag for routing)
+/// [9]: note_type (1 = Public, 2 = Private)
+/// [10]: expected balance left after the withdrawal
+/// [11]: optional expected index of the P2ID note among the output notes
+#[note]
+struct CheckedWithdrawNote;
+
+#[note]
+impl CheckedWithdrawNote {
+    #[note_script]
+    fn run(self, _arg: Word) {
+        let depositor = active_note::get_sender();
+        let inputs = active_note::get_inputs();
+
+        let withdraw_asset = Asset::new(Word::from([inputs[0], inputs[1], inputs[2], inputs[3]]));
+        let serial_num = Word::from([inputs[4], inputs[5], inputs[6], inputs[7]]);
+        let tag = inputs[8];
+        let note_type = inputs[9];
+
+        // Returned as [new_balance, note_index, 0, 0]
+        let withdrawal =
+            bank_account::withdraw(depositor, withdraw_asset, serial_num, tag, note_type, felt!(0));
+
+        assert!(withdrawal[0] == inputs[10], "Unexpected balance after withdrawal");
+        if inputs.len() > 11 {
+            assert!(withdrawal[1] == inputs[11], "Unexpected P2ID note index");
+        }
+    }
+}
@@ -0,0 +1,41 @@
+// Do not link against libstd (i.e. anything defined in `std::`)
+#![no_std]
+#![feature(alloc_error_handler)]
+
+use miden::*;
+
+// Import the bank account's generated bindings
+use crate::bindings::miden::bank_account::bank_account;
+
+/// Spoofed Balance Proof Note Script (test fixture)
+///
+/// Like the balance proof note, but takes the depositor from its inputs instead of the
+/// note sender, so tests can check that the bank refuses to attest a balance for
+/// anyone other than the depositor.
+///
+/// # Note Inputs (14 Felts)
+/// [0-1]: depositor AccountId (prefix, suffix) whose balance is requested
+/// [2-3]: faucet AccountId (prefix, suffix)
+/// [4-7]: serial_num for the proof note
+/// [8-11]: script root of the proof note
+/// [12]: tag for the proof note
+/// [13]: note_type (1 = Public, 2 = Private)
+#[note]
+struct SpoofedBalanceProofNote;
+
+#[note]
+impl SpoofedBalanceProofNote {
+    #[note_script]
+    fn run(self, _arg: Word) {
+        let inputs = active_note::get_inputs();
+
+        let depositor = AccountId::new(inputs[0], inputs[1]);
+        let faucet = AccountId::new(inputs[2], inputs[3]);
+        let serial_num = Word::from([inputs[4], inputs[5], inputs[6], inputs[7]]);
+        let script_root = Word::from([inputs[8], inputs[9], inputs[10], inputs[11]]);
+        let tag = inputs[12];
+        let note_type = inputs[13];
+
+        bank_account::emit_balance_proof(depositor, faucet, serial_num, script_root, tag, note_type);
+    }
+}
@@ -0,0 +1,39 @@
+// Do not link against libstd (i.e. anything defined in `std::`)
+#![no_std]
+#![feature(alloc_error_handler)]
+
+use miden::*;
+
+// Import the bank account's generated bindings
+use crate::bindings::miden::bank_account::bank_account;
+
+/// Spoofed Withdraw Note Script (test fixture)
+///
+/// Like the withdraw request note, but takes the depositor from its inputs instead of
+/// the note sender, so tests can check that the bank rejects a withdrawal requested
+/// by anyone other than the depositor.
+///
+/// # Note Inputs (12 Felts)
+/// [0-1]: depositor AccountId (prefix, suffix) whose balance is claimed
+/// [2-5]: withdraw asset (amount, 0, faucet_suffix, faucet_prefix)
+/// [6-9]: serial_num of the P2ID output note
+/// [10]: tag (P2ID note tag for routing)
+/// [11]: note_type (1 = Public, 2 = Private)
+#[note]
+struct SpoofedWithdrawNote;
+
+#[note]
+impl SpoofedWithdrawNote {
+    #[note_script]
+    fn run(self, _arg: Word) {
+        let inputs = active_note::get_inputs();
+
+        let depositor = AccountId::new(inputs[0], inputs[1]);
+        let withdraw_asset = Asset::new(Word::from([inputs[2], inputs[3], inputs[4], inputs[5]]));
+        let serial_num = Word::from([inputs[6], inputs[7], inputs[8], inputs[9]]);
+        let tag = inputs[10];
+        let note_type = inputs[11];
+
+        bank_account::withdraw(depositor, withdraw_asset, serial_num, tag, note_type, felt!(0));
+    }
+}
@@ -0,0 +1,32 @@
+// Do not link against libstd (i.e. anything defined in `std::`)
+#![no_std]
+#![feature(alloc_error_handler)]
+
+use miden::*;
+
+// Import the Account binding which wraps the bank-account component methods
+use crate::bindings::Account;
+
+/// Try-Initialize Transaction Script (test fixture)
+///
+/// Calls `account.try_initialize(owner)` twice and checks both returned flags, so
+/// tests can observe them: the transaction only succeeds if the first call returns
+/// the expected flag and the second call returns 0.
+///
+/// # Arguments
+/// * `arg` - [expected_first_flag, 0, owner_prefix, owner_suffix].
+///   The expected flag is 1 for a bank that is not yet initialized, 0 otherwise.
+/// * `account` - Mutable reference to the Account (bank component)
+#[tx_script]
+fn run(arg: Word, account: &mut Account) {
+    // The script argument arrives with its elements in reverse order relative to the
+    // Word supplied by the client, so restore the client-side layout first.
+    let arg = arg.reverse();
+    let owner = AccountId::new(arg[2], arg[3]);
+
+    let first = account.try_initialize(owner);
+    assert!(first == arg[0], "Unexpected flag from first try_initialize");
+
+    let second = account.try_initialize(owner);
+    assert!(second == felt!(0), "Unexpected flag from second try_initialize");
+}
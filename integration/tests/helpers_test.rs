@@ -0,0 +1,305 @@
+use integration::helpers::{
+    assert_bank_slots, assert_p2id_recipient_targets, assert_word_eq, bank_consume_request,
+    bank_slot_name, bank_storage_slots, build_project_cached, build_withdraw_note_inputs,
+    create_testing_account_from_package, create_testing_note_from_package,
+    p2id_tag_for_account, parse_endpoint, publish_note_request, AccountCreationConfig, NoteCreationConfig,
+    P2ID_TAG_ACCOUNT_BITS,
+};
+
+use miden_client::{
+    account::{AccountId, StorageMap, StorageSlot},
+    note::{build_p2id_recipient, Note, NoteAssets, NoteMetadata, NoteTag, NoteType},
+    Felt, Word,
+};
+use miden_client::asset::FungibleAsset;
+use miden_client::rpc::Endpoint;
+use miden_client::testing::account_id::{
+    ACCOUNT_ID_PRIVATE_SENDER, ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET,
+    ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE, ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE,
+};
+use miden_testing::{Auth, MockChain};
+use std::path::Path;
+
+#[test]
+fn assert_word_eq_accepts_equal_words() {
+    let word = Word::from([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+
+    assert_word_eq(word, word, "identical words");
+}
+
+/// Two words that differ only in their last element must produce a message naming
+/// that index and both values, and no other index.
+#[test]
+#[should_panic(expected = "balance: words differ at index 3: actual 500, expected 1000")]
+fn assert_word_eq_names_differing_index() {
+    let actual = Word::from([Felt::new(0), Felt::new(0), Felt::new(0), Felt::new(500)]);
+    let expected = Word::from([Felt::new(0), Felt::new(0), Felt::new(0), Felt::new(1000)]);
+
+    assert_word_eq(actual, expected, "balance");
+}
+
+/// A bank created with a stale storage slot vec (the `refunds` map is missing and
+/// `refund_mode` is declared as a map) must be flagged by `assert_bank_slots`.
+#[tokio::test]
+#[should_panic(expected = "has the wrong kind")]
+async fn assert_bank_slots_detects_wrong_layout() {
+    let bank_package = build_project_cached(Path::new("../contracts/bank-account"), true)
+        .expect("Bank account contract builds");
+
+    let slot = |field: &str| bank_slot_name(field).expect("Valid slot name");
+    let storage_slots = vec![
+        StorageSlot::with_value(slot("initialized"), Word::default()),
+        StorageSlot::with_map(
+            slot("balances"),
+            StorageMap::with_entries([]).expect("Empty storage map"),
+        ),
+        StorageSlot::with_value(slot("default_faucet"), Word::default()),
+        StorageSlot::with_map(
+            slot("refund_mode"),
+            StorageMap::with_entries([]).expect("Empty storage map"),
+        ),
+    ];
+
+    let bank_account = create_testing_account_from_package(
+        bank_package,
+        AccountCreationConfig {
+            storage_slots,
+            ..Default::default()
+        },
+    )
+    .await
+    .expect("Account builds despite the stale layout");
+
+    assert_bank_slots(&bank_account);
+}
+
+/// The bank consume request must carry exactly the given notes as input notes, in
+/// order, and expect no output notes; the publish request must carry its note only
+/// as an expected own output note.
+#[tokio::test]
+async fn bank_note_request_builders() -> anyhow::Result<()> {
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+
+    let mut builder = MockChain::builder();
+    let faucet = builder
+        .add_existing_basic_faucet(Auth::BasicAuth, "TEST", 1000, Some(10))?
+        .id();
+    let sender = builder.add_existing_wallet(Auth::BasicAuth)?.id();
+
+    let notes = [100u64, 200]
+        .into_iter()
+        .map(|amount| {
+            create_testing_note_from_package(
+                deposit_note_package.clone(),
+                sender,
+                NoteCreationConfig {
+                    assets: NoteAssets::new(vec![FungibleAsset::new(faucet, amount)?.into()])?,
+                    ..Default::default()
+                },
+            )
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let consume_request = bank_consume_request(notes.clone())?;
+    let input_note_ids: Vec<_> = consume_request.input_note_ids().collect();
+
+    assert_eq!(
+        input_note_ids,
+        notes.iter().map(|note| note.id()).collect::<Vec<_>>(),
+        "Consume request should contain the given notes in order"
+    );
+    assert!(
+        consume_request.expected_output_own_notes().is_empty(),
+        "Consume request should not expect any own output notes"
+    );
+
+    let publish_request = publish_note_request(notes[0].clone())?;
+
+    assert!(
+        publish_request.input_notes().is_empty(),
+        "Publish request should not consume any notes"
+    );
+    assert_eq!(
+        publish_request.expected_output_own_notes(),
+        vec![notes[0].clone()],
+        "Publish request should output exactly the published note"
+    );
+
+    Ok(())
+}
+
+/// A P2ID note built for one account must be rejected when asserted against another,
+/// even though the serial number and asset match.
+#[test]
+#[should_panic(expected = "P2ID note recipient does not target account")]
+fn assert_p2id_recipient_targets_detects_wrong_target() {
+    let mut builder = MockChain::builder();
+    let faucet = builder
+        .add_existing_basic_faucet(Auth::BasicAuth, "TEST", 1000, Some(10))
+        .expect("Faucet builds")
+        .id();
+    let bank = builder.add_existing_wallet(Auth::BasicAuth).expect("Wallet builds").id();
+    let depositor = builder.add_existing_wallet(Auth::BasicAuth).expect("Wallet builds").id();
+    let other = builder.add_existing_wallet(Auth::BasicAuth).expect("Wallet builds").id();
+
+    let serial_num = Word::from([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let asset = FungibleAsset::new(faucet, 500).expect("Valid asset");
+    let note = Note::new(
+        NoteAssets::new(vec![asset.into()]).expect("Valid assets"),
+        NoteMetadata::new(bank, NoteType::Public, NoteTag::with_account_target(depositor)),
+        build_p2id_recipient(depositor, serial_num).expect("P2ID recipient builds"),
+    );
+
+    // The correct target passes
+    assert_p2id_recipient_targets(&note, depositor, asset.into());
+
+    // Any other account is detected
+    assert_p2id_recipient_targets(&note, other, asset.into());
+}
+
+/// For local accounts, the P2ID tag must be the top 14 bits of the account ID prefix
+/// placed below two zero bits, with the low 16 bits cleared.
+#[test]
+fn p2id_tag_for_account_masks_prefix_bits() -> anyhow::Result<()> {
+    let account_ids = [
+        ACCOUNT_ID_PRIVATE_SENDER,
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE,
+        ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET,
+    ];
+
+    for account_id in account_ids {
+        let account_id = AccountId::try_from(account_id)?;
+        let prefix = account_id.prefix().as_u64();
+        let expected = ((prefix >> (64 - P2ID_TAG_ACCOUNT_BITS as u32)) as u32) << 16;
+
+        let tag = p2id_tag_for_account(account_id).as_u32();
+        assert_eq!(tag, expected, "P2ID tag for account {}", account_id);
+        assert_eq!(tag >> 30, 0, "P2ID tag for account {} should start with 0b00", account_id);
+    }
+
+    Ok(())
+}
+
+/// The withdraw note inputs must follow the layout the withdraw-request-note script
+/// reads: asset word, P2ID serial number, tag, note type.
+#[test]
+fn build_withdraw_note_inputs_layout() -> anyhow::Result<()> {
+    let faucet = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET)?;
+    let depositor = AccountId::try_from(ACCOUNT_ID_PRIVATE_SENDER)?;
+    let serial_num = Word::from([Felt::new(11), Felt::new(12), Felt::new(13), Felt::new(14)]);
+    let tag = p2id_tag_for_account(depositor);
+
+    let inputs = build_withdraw_note_inputs(
+        FungibleAsset::new(faucet, 400)?,
+        serial_num,
+        tag,
+        NoteType::Private,
+    );
+
+    assert_eq!(
+        inputs,
+        vec![
+            Felt::new(400),
+            Felt::new(0),
+            faucet.suffix(),
+            faucet.prefix().as_felt(),
+            Felt::new(11),
+            Felt::new(12),
+            Felt::new(13),
+            Felt::new(14),
+            Felt::new(tag.as_u32() as u64),
+            Felt::new(2),
+        ],
+        "Withdraw note inputs"
+    );
+
+    let public_inputs =
+        build_withdraw_note_inputs(FungibleAsset::new(faucet, 400)?, serial_num, tag, NoteType::Public);
+    assert_eq!(public_inputs[9], Felt::new(1), "Public note type input");
+
+    Ok(())
+}
+
+/// Endpoints can be named by network or given as a node URL; anything else is rejected.
+#[test]
+fn parse_endpoint_accepts_networks_and_urls() -> anyhow::Result<()> {
+    assert_eq!(parse_endpoint("testnet")?.to_string(), Endpoint::testnet().to_string());
+    assert_eq!(parse_endpoint("devnet")?.to_string(), Endpoint::devnet().to_string());
+    assert_eq!(parse_endpoint("localhost")?.to_string(), Endpoint::localhost().to_string());
+    assert_eq!(
+        parse_endpoint("http://127.0.0.1:57291")?.to_string(),
+        Endpoint::new("http".to_string(), "127.0.0.1".to_string(), Some(57291)).to_string()
+    );
+
+    assert!(
+        parse_endpoint("not a url").is_err(),
+        "Expected an invalid endpoint to be rejected"
+    );
+
+    Ok(())
+}
+
+/// Building the same contract twice, even through a different relative path, must
+/// return the package from the first build instead of compiling it again.
+#[test]
+fn build_project_cached_reuses_package() -> anyhow::Result<()> {
+    let first = build_project_cached(Path::new("../contracts/init-tx-script"), true)?;
+    let second = build_project_cached(Path::new("../contracts/../contracts/init-tx-script"), true)?;
+
+    assert!(
+        std::sync::Arc::ptr_eq(&first, &second),
+        "Expected the second build to return the cached package"
+    );
+
+    Ok(())
+}
+
+/// The bank preset must produce a layout `assert_bank_slots` accepts, with configured
+/// value slots set at creation, and must reject fields that are not bank value slots.
+#[tokio::test]
+async fn bank_storage_slots_builds_canonical_layout() -> anyhow::Result<()> {
+    let bank_package = build_project_cached(Path::new("../contracts/bank-account"), true)?;
+
+    let fee_bps = Word::from([Felt::new(25), Felt::new(0), Felt::new(0), Felt::new(0)]);
+    let bank_account = create_testing_account_from_package(
+        bank_package,
+        AccountCreationConfig {
+            storage_slots: bank_storage_slots(&[("fee_bps", fee_bps)])?,
+            ..Default::default()
+        },
+    )
+    .await?;
+    assert_bank_slots(&bank_account);
+
+    assert_word_eq(
+        bank_account.storage().get_item(&bank_slot_name("fee_bps")?)?,
+        fee_bps,
+        "fee_bps slot",
+    );
+    assert_word_eq(
+        bank_account.storage().get_item(&bank_slot_name("max_deposit")?)?,
+        Word::default(),
+        "max_deposit slot",
+    );
+
+    assert_eq!(
+        AccountCreationConfig::bank_default()?.storage_slots,
+        bank_storage_slots(&[])?,
+        "bank_default() should use the unconfigured layout"
+    );
+    assert!(
+        bank_storage_slots(&[("balances", fee_bps)]).is_err(),
+        "Expected a map slot to be rejected"
+    );
+    assert!(
+        bank_storage_slots(&[("fee", fee_bps)]).is_err(),
+        "Expected an unknown field to be rejected"
+    );
+    assert!(
+        bank_slot_name("fee").is_err(),
+        "Expected an unknown slot name to be rejected"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use integration::helpers::{
+    assert_word_eq, bank_slot_name, build_project_cached, create_testing_note_from_package,
+    BankFixture, NoteCreationConfig,
+};
+
+use miden_client::{
+    asset::FungibleAsset,
+    note::NoteAssets,
+    Felt, Word,
+};
+use std::path::Path;
+
+/// Test that a locked deposit cannot be withdrawn before its unlock block, and can be
+/// withdrawn once the chain has advanced to it.
+#[tokio::test]
+async fn withdraw_locked_deposit_test() -> anyhow::Result<()> {
+    let deposit_amount: u64 = 600;
+    let withdraw_amount: u64 = 400;
+    let lock_blocks: u32 = 5;
+
+    let mut fixture = BankFixture::builder().build().await?;
+    let locked_deposit_note_package =
+        build_project_cached(Path::new("../contracts/locked-deposit-note"), true)?;
+
+    // Deposit with an unlock block a few blocks in the future
+    let unlock_block = fixture.mock_chain.latest_block_header().block_num().as_u32() + lock_blocks;
+    let locked_deposit_note = create_testing_note_from_package(
+        locked_deposit_note_package,
+        fixture.sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![
+                FungibleAsset::new(fixture.faucet.id(), deposit_amount)?.into(),
+            ])?,
+            inputs: vec![Felt::new(unlock_block as u64)],
+            ..Default::default()
+        },
+    )?;
+    fixture.publish(std::slice::from_ref(&locked_deposit_note)).await?;
+    fixture.consume(&[locked_deposit_note], vec![]).await?;
+
+    assert_eq!(fixture.balance_of(fixture.sender.id())?, deposit_amount);
+    let locks_slot = bank_slot_name("locks")?;
+    let lock_key = Word::from([
+        fixture.sender.id().prefix().as_felt(),
+        fixture.sender.id().suffix(),
+        fixture.faucet.id().prefix().as_felt(),
+        fixture.faucet.id().suffix(),
+    ]);
+    assert_word_eq(
+        fixture.bank_account.storage().get_map_item(&locks_slot, lock_key)?,
+        Word::from([Felt::new(0), Felt::new(0), Felt::new(0), Felt::new(unlock_block as u64)]),
+        "Unlock block",
+    );
+
+    // Withdrawing before the unlock block is rejected
+    let result = fixture.withdraw(withdraw_amount).await;
+    assert!(
+        result.is_err(),
+        "Expected the early withdrawal to fail, but it succeeded"
+    );
+    assert_eq!(fixture.balance_of(fixture.sender.id())?, deposit_amount);
+
+    // Once the chain reaches the unlock block, the withdrawal goes through
+    fixture.mock_chain.prove_until_block(unlock_block)?;
+    fixture.withdraw(withdraw_amount).await?;
+
+    assert_eq!(
+        fixture.balance_of(fixture.sender.id())?,
+        deposit_amount - withdraw_amount
+    );
+
+    Ok(())
+}
@@ -0,0 +1,270 @@
+use integration::helpers::{
+    assert_word_eq, bank_slot_name, build_project_cached, create_initialized_bank,
+    create_testing_note_from_package, read_bank_config, AccountCreationConfig, InitializedBank,
+    NoteCreationConfig,
+};
+use integration::scenario::{run_scenario, Operation, Scenario, ScenarioFailure};
+
+use miden_client::{
+    note::NoteAssets,
+    transaction::OutputNote,
+    Felt, Word,
+};
+use miden_client::asset::FungibleAsset;
+use miden_testing::{Auth, MockChain};
+use std::path::Path;
+
+/// Test that a depositor can move their full balance to a new AccountId.
+///
+/// Account A deposits, then sends a migrate note naming B. Afterwards A's balance
+/// entry is zero, B holds the full amount and the bank's vault is unchanged.
+#[tokio::test]
+async fn migrate_depositor_test() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let deposit_amount: u64 = 1000;
+
+    // Create a faucet to mint test assets
+    let faucet =
+        builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", deposit_amount, Some(10))?;
+
+    // Account A: the original depositor
+    let old_depositor = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet.id(), deposit_amount)?.into()],
+    )?;
+
+    // Account B: the depositor's new account
+    let new_depositor = builder.add_existing_wallet(Auth::BasicAuth)?;
+
+    // Build contracts
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+    let migrate_note_package = build_project_cached(Path::new("../contracts/migrate-note"), true)?;
+
+    // Create the bank account with named storage slots
+    let balances_slot = bank_slot_name("balances")?;
+    let bank_cfg = AccountCreationConfig::bank_default()?;
+
+    // Craft the deposit note from A
+    let deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        old_depositor.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount)?.into()])?,
+            ..Default::default()
+        },
+    )?;
+
+    // Craft the migrate note from A
+    // Note inputs layout (4 Felts):
+    // [0-1]: new depositor AccountId (prefix, suffix)
+    // [2-3]: faucet AccountId (prefix, suffix)
+    let migrate_note = create_testing_note_from_package(
+        migrate_note_package.clone(),
+        old_depositor.id(),
+        NoteCreationConfig {
+            inputs: vec![
+                new_depositor.id().prefix().as_felt(),
+                new_depositor.id().suffix(),
+                faucet.id().prefix().as_felt(),
+                faucet.id().suffix(),
+            ],
+            ..Default::default()
+        },
+    )?;
+
+    builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+    builder.add_output_note(OutputNote::Full(migrate_note.clone()));
+
+    // Create and initialize the bank
+    let InitializedBank {
+        mut bank_account,
+        mut mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
+
+    // Deposit to A
+    let deposit_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+        .build()?;
+
+    let executed_deposit = deposit_tx_context.execute().await?;
+    bank_account.apply_delta(executed_deposit.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_deposit)?;
+    mock_chain.prove_next_block()?;
+
+    // Migrate A's balance to B; no output notes are expected
+    let migrate_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[migrate_note.id()], &[])?
+        .build()?;
+
+    let executed_migrate = migrate_tx_context.execute().await?;
+    assert_eq!(
+        executed_migrate.output_notes().num_notes(),
+        0,
+        "Migration must not create any notes"
+    );
+    bank_account.apply_delta(executed_migrate.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_migrate)?;
+    mock_chain.prove_next_block()?;
+
+    // Key format: [prefix, suffix, faucet_prefix, faucet_suffix]
+    let old_key = Word::from([
+        old_depositor.id().prefix().as_felt(),
+        old_depositor.id().suffix(),
+        faucet.id().prefix().as_felt(),
+        faucet.id().suffix(),
+    ]);
+    let new_key = Word::from([
+        new_depositor.id().prefix().as_felt(),
+        new_depositor.id().suffix(),
+        faucet.id().prefix().as_felt(),
+        faucet.id().suffix(),
+    ]);
+
+    assert_word_eq(
+        bank_account.storage().get_map_item(&balances_slot, old_key)?,
+        Word::default(),
+        "Old depositor balance after migration",
+    );
+    assert_word_eq(
+        bank_account.storage().get_map_item(&balances_slot, new_key)?,
+        Word::from([Felt::new(0), Felt::new(0), Felt::new(0), Felt::new(deposit_amount)]),
+        "New depositor balance after migration",
+    );
+
+    // The funds never left the bank
+    assert_eq!(bank_account.vault().get_balance(faucet.id())?, deposit_amount);
+
+    Ok(())
+}
+
+/// Test that migrating doesn't reset the withdrawal limit window.
+///
+/// The owner caps A's withdrawals at 300 and A withdraws all of it. After A migrates
+/// to B, B inherits the spent window, so withdrawing even 100 more fails.
+#[tokio::test]
+async fn migrate_then_withdraw_past_limit_should_fail() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("TEST", 1000)
+        .depositor(&[(0, 1000)])
+        .depositor(&[])
+        .owner(0);
+    let result = run_scenario(scenario.operations(vec![
+        Operation::SetWithdrawLimit {
+            sender: 0,
+            depositor: 0,
+            faucet: 0,
+            limit: 300,
+        },
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: 1000,
+        },
+        Operation::Withdraw {
+            depositor: 0,
+            faucet: 0,
+            amount: 300,
+        },
+        Operation::Migrate {
+            from: 0,
+            to: 1,
+            faucet: 0,
+        },
+        Operation::Withdraw {
+            depositor: 1,
+            faucet: 0,
+            amount: 100,
+        },
+    ]))
+    .await;
+
+    let failure = result
+        .err()
+        .expect("Expected the withdrawal past the migrated limit to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 4, "{}", failure);
+    let state = &failure.state;
+    assert_eq!(state.balance(0, 0), 0, "A should hold no balance after the migration");
+    assert_eq!(state.balance(1, 0), 700, "B should not withdraw past the inherited limit");
+
+    Ok(())
+}
+
+/// Test that the owner can migrate a depositor's balance on their behalf.
+///
+/// A deposits and the owner sends an admin note moving A's balance to B.
+#[tokio::test]
+async fn owner_migrate_depositor_test() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("TEST", 1000)
+        .depositor(&[])
+        .depositor(&[(0, 1000)])
+        .depositor(&[])
+        .owner(0);
+    let result = run_scenario(scenario.operations(vec![
+        Operation::Deposit {
+            depositor: 1,
+            faucet: 0,
+            amount: 1000,
+        },
+        Operation::AdminMigrate {
+            sender: 0,
+            from: 1,
+            to: 2,
+            faucet: 0,
+        },
+    ]))
+    .await?;
+
+    assert_eq!(result.balance(1, 0), 0, "Old depositor balance after migration");
+    assert_eq!(result.balance(2, 0), 1000, "New depositor balance after migration");
+
+    Ok(())
+}
+
+/// Test that an account that is neither the depositor nor the owner can't migrate
+/// someone else's balance.
+///
+/// A deposits, then a third party sends an admin note trying to move A's balance to
+/// itself.
+#[tokio::test]
+async fn third_party_migrate_should_fail() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("TEST", 1000)
+        .depositor(&[])
+        .depositor(&[(0, 1000)])
+        .depositor(&[])
+        .owner(0);
+    let result = run_scenario(scenario.operations(vec![
+        Operation::Deposit {
+            depositor: 1,
+            faucet: 0,
+            amount: 1000,
+        },
+        Operation::AdminMigrate {
+            sender: 2,
+            from: 1,
+            to: 2,
+            faucet: 0,
+        },
+    ]))
+    .await;
+
+    let failure = result
+        .err()
+        .expect("Expected the third party's migration to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 1, "{}", failure);
+    let state = &failure.state;
+    assert_eq!(state.balance(1, 0), 1000, "A should keep their balance");
+    assert_eq!(state.balance(2, 0), 0, "The third party should not be credited");
+    assert_eq!(
+        read_bank_config(&state.bank_account)?.owner,
+        Some(state.depositor_ids[0]),
+        "The third party should not own the bank"
+    );
+
+    Ok(())
+}
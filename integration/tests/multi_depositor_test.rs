@@ -0,0 +1,99 @@
+use integration::helpers::get_total;
+use integration::scenario::{run_scenario, Operation, Scenario};
+
+/// Test that two depositors of the same faucet keep separate balances across several
+/// blocks, with one withdrawing while the other's balance stays untouched.
+#[tokio::test]
+async fn shared_faucet_depositors_test() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("SHARED", 2000)
+        .faucet("OTHER", 1000)
+        .depositor(&[(0, 1000), (1, 1000)])
+        .depositor(&[(0, 1000)]);
+    let result = run_scenario(scenario.operations(vec![
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: 300,
+        },
+        Operation::Deposit {
+            depositor: 1,
+            faucet: 0,
+            amount: 500,
+        },
+        Operation::Withdraw {
+            depositor: 1,
+            faucet: 0,
+            amount: 200,
+        },
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: 100,
+        },
+    ]))
+    .await?;
+
+    assert_eq!(result.balance(0, 0), 400, "Depositor 0 balance in the shared faucet");
+    assert_eq!(result.balance(1, 0), 300, "Depositor 1 balance in the shared faucet");
+    assert_eq!(
+        get_total(&result.bank_account, result.faucet_ids[0])?,
+        700,
+        "Total of the shared faucet"
+    );
+
+    Ok(())
+}
+
+/// Test that balances are keyed by faucet as well as by depositor: depositor 0 holds
+/// two faucets and withdraws from one, while depositor 1 only ever holds the shared
+/// faucet.
+#[tokio::test]
+async fn distinct_faucet_depositors_test() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("SHARED", 2000)
+        .faucet("OTHER", 1000)
+        .depositor(&[(0, 1000), (1, 1000)])
+        .depositor(&[(0, 1000)]);
+    let result = run_scenario(scenario.operations(vec![
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: 300,
+        },
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 1,
+            amount: 600,
+        },
+        Operation::Deposit {
+            depositor: 1,
+            faucet: 0,
+            amount: 500,
+        },
+        Operation::Withdraw {
+            depositor: 0,
+            faucet: 1,
+            amount: 250,
+        },
+    ]))
+    .await?;
+
+    assert_eq!(result.balance(0, 0), 300, "Depositor 0 balance in faucet 0");
+    assert_eq!(result.balance(0, 1), 350, "Depositor 0 balance in faucet 1");
+    assert_eq!(result.balance(1, 0), 500, "Depositor 1 balance in faucet 0");
+    assert_eq!(result.balance(1, 1), 0, "Depositor 1 never deposited faucet 1");
+
+    assert_eq!(
+        get_total(&result.bank_account, result.faucet_ids[0])?,
+        800,
+        "Total of faucet 0"
+    );
+    assert_eq!(
+        get_total(&result.bank_account, result.faucet_ids[1])?,
+        350,
+        "Total of faucet 1"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use integration::helpers::{preflight_bank_account, read_bank_config};
+use integration::scenario::{run_scenario, Operation, Scenario, ScenarioFailure};
+
+/// Test that initialization records the initializing account as the owner.
+#[tokio::test]
+async fn initialize_records_owner_test() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("TEST", 2000)
+        .depositor(&[(0, 1000)])
+        .depositor(&[(0, 1000)])
+        .owner(0);
+    let result = run_scenario(scenario.operations(vec![])).await?;
+
+    assert_eq!(
+        read_bank_config(&result.bank_account)?.owner,
+        Some(result.depositor_ids[0]),
+        "The initializing account should own the bank"
+    );
+
+    Ok(())
+}
+
+/// Test that an admin note from an account other than the owner cannot pause the bank.
+#[tokio::test]
+async fn pause_by_non_owner_should_fail() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("TEST", 2000)
+        .depositor(&[(0, 1000)])
+        .depositor(&[(0, 1000)])
+        .owner(0);
+    let result = run_scenario(scenario.operations(vec![Operation::Pause { sender: 1 }])).await;
+
+    let failure = result
+        .err()
+        .expect("Expected the non-owner pause to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 0, "{}", failure);
+    let state = &failure.state;
+    let report = preflight_bank_account(
+        &state.bank_account,
+        state.depositor_ids[0],
+        state.faucet_ids[0],
+        0,
+    )?;
+    assert_eq!(
+        read_bank_config(&state.bank_account)?.owner,
+        Some(state.depositor_ids[0]),
+        "Depositor 0 should own the bank"
+    );
+    assert!(!report.paused, "The bank should not be paused");
+
+    Ok(())
+}
+
+/// Test that a bank initialized without an owner cannot be paused by anyone.
+#[tokio::test]
+async fn pause_without_owner_should_fail() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("TEST", 2000)
+        .depositor(&[(0, 1000)])
+        .depositor(&[(0, 1000)]);
+    let result = run_scenario(scenario.operations(vec![Operation::Pause { sender: 0 }])).await;
+
+    let failure = result
+        .err()
+        .expect("Expected the ownerless pause to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 0, "{}", failure);
+    let state = &failure.state;
+    let report = preflight_bank_account(
+        &state.bank_account,
+        state.depositor_ids[0],
+        state.faucet_ids[0],
+        0,
+    )?;
+    assert_eq!(read_bank_config(&state.bank_account)?.owner, None, "The bank should have no owner");
+    assert!(!report.paused, "The bank should not be paused");
+
+    Ok(())
+}
+
+/// Test that after a transfer the new owner holds the privileged methods.
+#[tokio::test]
+async fn transfer_ownership_test() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("TEST", 2000)
+        .depositor(&[(0, 1000)])
+        .depositor(&[(0, 1000)])
+        .owner(0);
+    let result = run_scenario(scenario.operations(vec![
+        Operation::TransferOwnership {
+            sender: 0,
+            new_owner: 1,
+        },
+        Operation::Pause { sender: 1 },
+    ]))
+    .await?;
+
+    assert_eq!(
+        read_bank_config(&result.bank_account)?.owner,
+        Some(result.depositor_ids[1]),
+        "Ownership should have moved to depositor 1"
+    );
+
+    Ok(())
+}
+
+/// Test that the previous owner loses the privileged methods after a transfer.
+#[tokio::test]
+async fn pause_by_previous_owner_should_fail() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("TEST", 2000)
+        .depositor(&[(0, 1000)])
+        .depositor(&[(0, 1000)])
+        .owner(0);
+    let result = run_scenario(scenario.operations(vec![
+        Operation::TransferOwnership {
+            sender: 0,
+            new_owner: 1,
+        },
+        Operation::Pause { sender: 0 },
+    ]))
+    .await;
+
+    let failure = result
+        .err()
+        .expect("Expected the previous owner's pause to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 1, "{}", failure);
+    let state = &failure.state;
+    let report = preflight_bank_account(
+        &state.bank_account,
+        state.depositor_ids[0],
+        state.faucet_ids[0],
+        0,
+    )?;
+    assert_eq!(
+        read_bank_config(&state.bank_account)?.owner,
+        Some(state.depositor_ids[1]),
+        "Depositor 1 should own the bank"
+    );
+    assert!(!report.paused, "The bank should not be paused");
+
+    Ok(())
+}
+
+/// Test that only the owner can transfer ownership.
+#[tokio::test]
+async fn transfer_ownership_by_non_owner_should_fail() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("TEST", 2000)
+        .depositor(&[(0, 1000)])
+        .depositor(&[(0, 1000)])
+        .owner(0);
+    let result = run_scenario(scenario.operations(vec![Operation::TransferOwnership {
+        sender: 1,
+        new_owner: 1,
+    }]))
+    .await;
+
+    let failure = result
+        .err()
+        .expect("Expected the non-owner transfer to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 0, "{}", failure);
+    let state = &failure.state;
+    assert_eq!(
+        read_bank_config(&state.bank_account)?.owner,
+        Some(state.depositor_ids[0]),
+        "Depositor 0 should own the bank"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use std::process::Command;
+
+/// Test that the P2ID root compiled into the bank contract matches the installed
+/// miden-standards P2ID script, by running the `check-p2id-root` binary.
+#[test]
+fn check_p2id_root_binary_succeeds() -> anyhow::Result<()> {
+    let output = Command::new(env!("CARGO_BIN_EXE_check-p2id-root")).output()?;
+
+    assert!(
+        output.status.success(),
+        "check-p2id-root failed:\n{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use integration::helpers::preflight_bank_account;
+use integration::scenario::{run_scenario, single_depositor_scenario, Operation, ScenarioFailure};
+
+/// Test that a paused bank rejects a deposit note.
+#[tokio::test]
+async fn deposit_while_paused_should_fail() -> anyhow::Result<()> {
+    let result = run_scenario(single_depositor_scenario(vec![
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: 300,
+        },
+        Operation::Pause { sender: 0 },
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: 400,
+        },
+    ]))
+    .await;
+
+    // The first deposit and the pause succeed; the deposit after the pause is rejected
+    let failure = result
+        .err()
+        .expect("Expected the deposit to a paused bank to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 2, "{}", failure);
+    let state = &failure.state;
+    let report = preflight_bank_account(
+        &state.bank_account,
+        state.depositor_ids[0],
+        state.faucet_ids[0],
+        0,
+    )?;
+    assert!(report.paused, "The bank should be paused");
+    assert_eq!(state.balance(0, 0), 300, "Only the deposit before the pause should be credited");
+
+    Ok(())
+}
+
+/// Test that deposits are accepted again once a paused bank is unpaused.
+#[tokio::test]
+async fn deposit_after_unpause_test() -> anyhow::Result<()> {
+    let result = run_scenario(single_depositor_scenario(vec![
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: 300,
+        },
+        Operation::Pause { sender: 0 },
+        Operation::Unpause { sender: 0 },
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: 400,
+        },
+    ]))
+    .await?;
+
+    assert_eq!(result.balance(0, 0), 700, "Both deposits should be credited");
+
+    Ok(())
+}
+
+/// Test that a paused bank rejects a withdraw request for a funded balance.
+#[tokio::test]
+async fn withdraw_while_paused_should_fail() -> anyhow::Result<()> {
+    let result = run_scenario(single_depositor_scenario(vec![
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: 1000,
+        },
+        Operation::Pause { sender: 0 },
+        Operation::Withdraw {
+            depositor: 0,
+            faucet: 0,
+            amount: 400,
+        },
+    ]))
+    .await;
+
+    let failure = result
+        .err()
+        .expect("Expected the withdrawal from a paused bank to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 2, "{}", failure);
+    let state = &failure.state;
+    let report = preflight_bank_account(
+        &state.bank_account,
+        state.depositor_ids[0],
+        state.faucet_ids[0],
+        0,
+    )?;
+    assert!(report.paused, "The bank should be paused");
+    assert_eq!(state.balance(0, 0), 1000, "Nothing should be withdrawn from a paused bank");
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use integration::scenario::{run_scenario, DepositorSpec, FaucetSpec, Operation, Scenario};
+
+/// Test a three-operation scenario across two depositors and two faucets.
+///
+/// Depositor 0 deposits faucet 0 and withdraws part of it, while depositor 1 deposits
+/// faucet 1. Each depositor's balance must only reflect their own operations.
+#[tokio::test]
+async fn three_operation_scenario_test() -> anyhow::Result<()> {
+    let result = run_scenario(Scenario {
+        faucets: vec![
+            FaucetSpec {
+                symbol: "AAA",
+                max_supply: 1000,
+            },
+            FaucetSpec {
+                symbol: "BBB",
+                max_supply: 1000,
+            },
+        ],
+        depositors: vec![
+            DepositorSpec {
+                assets: vec![(0, 1000)],
+            },
+            DepositorSpec {
+                assets: vec![(1, 300)],
+            },
+        ],
+        operations: vec![
+            Operation::Deposit {
+                depositor: 0,
+                faucet: 0,
+                amount: 1000,
+            },
+            Operation::Deposit {
+                depositor: 1,
+                faucet: 1,
+                amount: 300,
+            },
+            Operation::Withdraw {
+                depositor: 0,
+                faucet: 0,
+                amount: 400,
+            },
+        ],
+        ..Default::default()
+    })
+    .await?;
+
+    assert_eq!(result.balance(0, 0), 600);
+    assert_eq!(result.balance(0, 1), 0);
+    assert_eq!(result.balance(1, 0), 0);
+    assert_eq!(result.balance(1, 1), 300);
+
+    Ok(())
+}
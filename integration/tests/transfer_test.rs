@@ -0,0 +1,78 @@
+use integration::helpers::get_total;
+use integration::scenario::{run_scenario, Operation, Scenario, ScenarioFailure};
+
+/// Test that a transfer moves balance between two depositors without touching the vault.
+#[tokio::test]
+async fn transfer_test() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("TEST", 1000)
+        .depositor(&[(0, 1000)])
+        .depositor(&[]);
+    let result = run_scenario(scenario.operations(vec![
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: 1000,
+        },
+        Operation::Transfer {
+            from: 0,
+            to: 1,
+            faucet: 0,
+            amount: 500,
+        },
+    ]))
+    .await?;
+
+    assert_eq!(result.balance(0, 0), 500, "Sender keeps the rest of the balance");
+    assert_eq!(result.balance(1, 0), 500, "Recipient is credited the transfer");
+    assert_eq!(
+        result.bank_account.vault().get_balance(result.faucet_ids[0])?,
+        1000,
+        "A transfer must not move assets out of the vault"
+    );
+    assert_eq!(
+        get_total(&result.bank_account, result.faucet_ids[0])?,
+        1000,
+        "A transfer must not change the faucet's total"
+    );
+
+    Ok(())
+}
+
+/// Test that a depositor cannot transfer more than their balance.
+///
+/// Balances are Felts, so without the balance check the debit would wrap to a huge
+/// balance instead of failing.
+#[tokio::test]
+async fn transfer_exceeds_balance_should_fail() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("TEST", 1000)
+        .depositor(&[(0, 1000)])
+        .depositor(&[]);
+    let result = run_scenario(scenario.operations(vec![
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: 1000,
+        },
+        Operation::Transfer {
+            from: 1,
+            to: 0,
+            faucet: 0,
+            amount: 1,
+        },
+    ]))
+    .await;
+
+    // Depositor 1 holds no balance, so their transfer (operation 1) is rejected
+    let failure = result
+        .err()
+        .expect("Expected the over-transfer to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 1, "{}", failure);
+    let state = &failure.state;
+    assert_eq!(state.balance(1, 0), 0, "Depositor 1 should hold no balance");
+    assert_eq!(state.balance(0, 0), 1000, "Depositor 0 should keep their balance");
+
+    Ok(())
+}
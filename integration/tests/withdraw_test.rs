@@ -1,49 +1,1616 @@
 use integration::helpers::{
-    build_project_in_dir, create_testing_account_from_package, create_testing_note_from_package,
-    AccountCreationConfig, NoteCreationConfig,
+    assert_bank_slots, assert_p2id_recipient_targets, BankFixture, assert_word_eq, bank_slot_name,
+    build_project_cached, build_withdraw_note_inputs, assert_balance, create_testing_account_from_package, create_testing_note_from_package,
+    create_initialized_bank, get_balance_for_asset, get_fees, get_total, p2id_tag_for_account, AccountCreationConfig,
+    InitializedBank, NoteCreationConfig,
 };
 
 use miden_client::{
-    account::{StorageMap, StorageSlot, StorageSlotName},
-    note::{build_p2id_recipient, Note, NoteAssets, NoteMetadata, NoteTag, NoteType},
+    account::StorageSlot,
+    note::{
+        build_p2id_recipient, Note, NoteAssets, NoteInputs, NoteMetadata, NoteRecipient,
+        NoteType, WellKnownNote,
+    },
     transaction::{OutputNote, TransactionScript},
     Felt, Word,
 };
-use miden_client::asset::{Asset, FungibleAsset};
+use miden_client::asset::FungibleAsset;
+use integration::scenario::{
+    run_scenario, single_depositor_scenario, DepositorSpec, FaucetSpec, Operation, Scenario,
+    ScenarioFailure,
+};
 use miden_testing::{Auth, MockChain};
-use std::{path::Path, sync::Arc};
-
-/// Helper to create the bank account storage slots with named slot names
-fn bank_storage_slots() -> (StorageSlotName, StorageSlotName, Vec<StorageSlot>) {
-    let initialized_slot =
-        StorageSlotName::new("miden::component::miden_bank_account::initialized")
-            .expect("Valid slot name");
-    let balances_slot =
-        StorageSlotName::new("miden::component::miden_bank_account::balances")
-            .expect("Valid slot name");
-
-    let slots = vec![
-        StorageSlot::with_value(initialized_slot.clone(), Word::default()),
-        StorageSlot::with_map(
-            balances_slot.clone(),
-            StorageMap::with_entries([]).expect("Empty storage map"),
-        ),
-    ];
+use std::path::Path;
+
+/// Test that a depositor can withdraw part of their deposit.
+///
+/// Written as a scenario spec: the scenario runner deploys and initializes the bank,
+/// runs each operation in its own transaction and checks the P2ID payout of the
+/// withdrawal against the expected output note.
+#[tokio::test]
+async fn withdraw_test() -> anyhow::Result<()> {
+    let deposit_amount: u64 = 1000;
+    let withdraw_amount = deposit_amount / 2;
+
+    let result = run_scenario(Scenario {
+        faucets: vec![FaucetSpec {
+            symbol: "TEST",
+            max_supply: deposit_amount,
+        }],
+        depositors: vec![DepositorSpec {
+            assets: vec![(0, deposit_amount)],
+        }],
+        operations: vec![
+            Operation::Deposit {
+                depositor: 0,
+                faucet: 0,
+                amount: deposit_amount,
+            },
+            Operation::Withdraw {
+                depositor: 0,
+                faucet: 0,
+                amount: withdraw_amount,
+            },
+        ],
+        ..Default::default()
+    })
+    .await?;
+
+    assert_eq!(result.balance(0, 0), deposit_amount - withdraw_amount);
+    assert_eq!(
+        result.bank_account.vault().get_balance(result.faucet_ids[0])?,
+        deposit_amount - withdraw_amount,
+        "The withdrawn amount should have left the bank's vault"
+    );
+
+    println!("Withdraw test passed!");
+
+    Ok(())
+}
+
+/// Test that the P2ID note paid out by a withdrawal can be consumed by the depositor.
+///
+/// The bank emits the note as a public output, so once its transaction is proven the
+/// note is committed on chain and the depositor's vault gains the withdrawn amount.
+#[tokio::test]
+async fn withdraw_p2id_note_consumed_by_depositor_test() -> anyhow::Result<()> {
+    let deposit_amount: u64 = 1000;
+    let withdraw_amount = deposit_amount / 2;
+
+    let mut fixture = BankFixture::builder().faucet_supply(deposit_amount).build().await?;
+    fixture.deposit(deposit_amount).await?;
+
+    let executed_withdraw = fixture.withdraw(withdraw_amount).await?;
+    let OutputNote::Full(p2id_note) = executed_withdraw.output_notes().get_note(0) else {
+        panic!("Expected the withdrawal to output a full P2ID note");
+    };
+
+    let balance_before = fixture.sender.vault().get_balance(fixture.faucet.id())?;
+
+    // The depositor consumes the P2ID note committed by the withdrawal's block
+    let tx_context = fixture
+        .mock_chain
+        .build_tx_context(fixture.sender.id(), &[p2id_note.id()], &[])?
+        .build()?;
+
+    let executed_consume = tx_context.execute().await?;
+    fixture.sender.apply_delta(executed_consume.account_delta())?;
+    fixture.mock_chain.add_pending_executed_transaction(&executed_consume)?;
+    fixture.mock_chain.prove_next_block()?;
+
+    assert_eq!(
+        fixture.sender.vault().get_balance(fixture.faucet.id())?,
+        balance_before + withdraw_amount,
+        "The depositor's vault should gain the withdrawn amount"
+    );
+
+    println!("P2ID consume test passed!");
+    Ok(())
+}
+
+/// Test that withdrawing more than the deposited balance fails.
+///
+/// Balances are Felts, so without the balance check the subtraction would wrap to a huge
+/// balance instead of failing and no P2ID note must be created for an over-withdrawal.
+#[tokio::test]
+async fn withdraw_exceeds_balance_should_fail() -> anyhow::Result<()> {
+    let deposit_amount: u64 = 1000;
+    let withdraw_amount: u64 = 2000;
+
+    let result = run_scenario(Scenario {
+        faucets: vec![FaucetSpec {
+            symbol: "TEST",
+            max_supply: withdraw_amount,
+        }],
+        depositors: vec![DepositorSpec {
+            assets: vec![(0, deposit_amount)],
+        }],
+        operations: vec![
+            Operation::Deposit {
+                depositor: 0,
+                faucet: 0,
+                amount: deposit_amount,
+            },
+            Operation::Withdraw {
+                depositor: 0,
+                faucet: 0,
+                amount: withdraw_amount,
+            },
+        ],
+        ..Default::default()
+    })
+    .await;
+
+    // The deposit (operation 0) succeeds and the over-withdrawal (operation 1) is rejected
+    let failure = result
+        .err()
+        .expect("Expected the over-withdrawal to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 1, "{}", failure);
+    let state = &failure.state;
+    assert_eq!(state.balance(0, 0), deposit_amount, "The balance should not wrap");
+    assert_eq!(
+        state.bank_account.vault().get_balance(state.faucet_ids[0])?,
+        deposit_amount,
+        "Nothing should leave the vault"
+    );
+
+    Ok(())
+}
+
+/// Test that a withdrawal by an account that never deposited is rejected.
+///
+/// The depositor has no entry in the balances map, which reads back as zero, so the
+/// insufficient-balance check must reject the request before the subtraction could
+/// wrap around and pay out another depositor's assets from the vault.
+#[tokio::test]
+async fn withdraw_without_deposit_should_fail() -> anyhow::Result<()> {
+    let deposit_amount: u64 = 1000;
+    let withdraw_amount: u64 = 400;
+
+    // Depositor 0 funds the vault; depositor 1 never deposits
+    let result = run_scenario(Scenario {
+        faucets: vec![FaucetSpec {
+            symbol: "TEST",
+            max_supply: deposit_amount,
+        }],
+        depositors: vec![
+            DepositorSpec {
+                assets: vec![(0, deposit_amount)],
+            },
+            DepositorSpec { assets: vec![] },
+        ],
+        operations: vec![
+            Operation::Deposit {
+                depositor: 0,
+                faucet: 0,
+                amount: deposit_amount,
+            },
+            Operation::Withdraw {
+                depositor: 1,
+                faucet: 0,
+                amount: withdraw_amount,
+            },
+        ],
+        ..Default::default()
+    })
+    .await;
+
+    // The deposit (operation 0) succeeds and the withdrawal (operation 1) is rejected
+    let failure = result
+        .err()
+        .expect("Expected the withdrawal without a deposit to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 1, "{}", failure);
+    let state = &failure.state;
+    assert_eq!(state.balance(1, 0), 0, "Depositor 1 should hold no balance");
+    assert_eq!(
+        state.bank_account.vault().get_balance(state.faucet_ids[0])?,
+        deposit_amount,
+        "Depositor 0's assets should stay in the vault"
+    );
+
+    Ok(())
+}
+
+/// Test a deposit and a withdrawal for the same depositor proven in a single transaction.
+///
+/// The bank consumes the deposit note and the withdraw request note together, so the
+/// withdrawal must observe the balance written by the deposit within the same transaction.
+///
+/// Input notes are executed in the order they are listed, so the deposit note must come
+/// before the withdraw request note. With the withdrawal listed first the balance is still
+/// zero when it runs and the transaction is rejected.
+#[tokio::test]
+async fn deposit_and_withdraw_same_transaction_test() -> anyhow::Result<()> {
+    let deposit_amount: u64 = 1000;
+    let withdraw_amount: u64 = 400;
+
+    let mut fixture = BankFixture::builder().faucet_supply(deposit_amount).build().await?;
+
+    let deposit_note = fixture.deposit_note(deposit_amount)?;
+    let (withdraw_request_note, p2id_output_note) = fixture.withdraw_request_note(withdraw_amount)?;
+    fixture
+        .publish(&[deposit_note.clone(), withdraw_request_note.clone()])
+        .await?;
+
+    // Withdrawal listed before the deposit: the balance is still zero, so this must fail
+    let result = fixture
+        .consume(&[withdraw_request_note.clone(), deposit_note.clone()], vec![])
+        .await;
+
+    assert!(
+        result.is_err(),
+        "Expected withdrawal ordered before its deposit to fail, but it succeeded"
+    );
+
+    // Deposit listed before the withdrawal: both succeed in one transaction
+    let executed_transaction = fixture
+        .consume(&[deposit_note, withdraw_request_note], vec![p2id_output_note])
+        .await?;
+
+    // The P2ID note must pay the withdrawn amount back to the depositor
+    let OutputNote::Full(withdrawn_note) = executed_transaction.output_notes().get_note(0) else {
+        panic!("Expected the withdrawal to output a full P2ID note");
+    };
+    assert_p2id_recipient_targets(
+        withdrawn_note,
+        fixture.sender.id(),
+        FungibleAsset::new(fixture.faucet.id(), withdraw_amount)?.into(),
+    );
+
+    // The final balance reflects both operations
+    assert_balance(
+        &fixture.bank_account,
+        fixture.sender.id(),
+        fixture.faucet.id(),
+        deposit_amount - withdraw_amount,
+    );
+
+    println!("Same-transaction deposit and withdraw test passed!");
+    Ok(())
+}
+
+/// Test that a withdraw request carrying a deadline block is rejected once the chain
+/// has moved past that block, while a request whose deadline is still ahead succeeds.
+#[tokio::test]
+async fn withdraw_deadline_test() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let deposit_amount: u64 = 1000;
+    let withdraw_amount: u64 = 400;
+
+    // Deadlines in block numbers: one the chain will pass, one far ahead
+    let expired_deadline: u64 = 3;
+    let future_deadline: u64 = 1_000;
+
+    // Create a faucet to mint test assets
+    let faucet =
+        builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", deposit_amount, Some(10))?;
+
+    // Create note sender account (the depositor)
+    let sender = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet.id(), deposit_amount)?.into()],
+    )?;
+
+    // Build contracts
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+    let withdraw_request_note_package =
+        build_project_cached(Path::new("../contracts/withdraw-request-note"), true)?;
+
+    // Create the bank account with named storage slots
+    let bank_cfg = AccountCreationConfig::bank_default()?;
+
+    // Craft the deposit note
+    let deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount)?.into()])?,
+            ..Default::default()
+        },
+    )?;
+
+    // Craft two otherwise identical withdraw request notes with different deadlines
+    let p2id_tag = p2id_tag_for_account(sender.id());
+    let p2id_output_note_serial_num = Word::from([
+        Felt::new(0x5555555555555555),
+        Felt::new(0x6666666666666666),
+        Felt::new(0x7777777777777777),
+        Felt::new(0x8888888888888888),
+    ]);
+
+    let withdraw_request_note = |deadline: u64| {
+        // The deadline block number follows the 10 standard inputs
+        let mut inputs = build_withdraw_note_inputs(
+            FungibleAsset::new(faucet.id(), withdraw_amount)?,
+            p2id_output_note_serial_num,
+            p2id_tag,
+            NoteType::Public,
+        );
+        inputs.push(Felt::new(deadline));
+
+        create_testing_note_from_package(
+            withdraw_request_note_package.clone(),
+            sender.id(),
+            NoteCreationConfig {
+                inputs,
+                ..Default::default()
+            },
+        )
+    };
+    let expired_note = withdraw_request_note(expired_deadline)?;
+    let valid_note = withdraw_request_note(future_deadline)?;
+
+    builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+    builder.add_output_note(OutputNote::Full(expired_note.clone()));
+    builder.add_output_note(OutputNote::Full(valid_note.clone()));
+
+    // Create and initialize the bank
+    let InitializedBank {
+        mut bank_account,
+        mut mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
+
+    // Deposit
+    let deposit_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+        .build()?;
+
+    let executed_deposit = deposit_tx_context.execute().await?;
+    bank_account.apply_delta(executed_deposit.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_deposit)?;
+    mock_chain.prove_next_block()?;
+
+    // Advance the chain past the expired deadline
+    mock_chain.prove_until_block(expired_deadline as u32 + 2)?;
+    assert!(
+        u64::from(mock_chain.latest_block_header().block_num().as_u32()) > expired_deadline,
+        "Chain should be past the expired deadline"
+    );
+
+    // Expected P2ID output note produced by either withdrawal
+    let recipient = build_p2id_recipient(sender.id(), p2id_output_note_serial_num)?;
+    let p2id_output_note = Note::new(
+        NoteAssets::new(vec![FungibleAsset::new(faucet.id(), withdraw_amount)?.into()])?,
+        NoteMetadata::new(bank_account.id(), NoteType::Public, p2id_tag),
+        recipient,
+    );
+
+    // The expired request must be rejected
+    let expired_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[expired_note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(p2id_output_note.clone())])
+        .build()?;
+
+    let result = expired_tx_context.execute().await;
+
+    assert!(
+        result.is_err(),
+        "Expected withdrawal past its deadline to fail, but it succeeded"
+    );
+
+    // The request still within its deadline succeeds
+    let valid_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[valid_note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(p2id_output_note)])
+        .build()?;
+
+    let executed_withdraw = valid_tx_context.execute().await?;
+    bank_account.apply_delta(executed_withdraw.account_delta())?;
+
+    // Balance after the in-deadline withdrawal
+    assert_balance(&bank_account, sender.id(), faucet.id(), deposit_amount - withdraw_amount);
+
+    Ok(())
+}
+
+/// Test that a bank configured with a minimum account age rejects a withdrawal made
+/// right after the first deposit, and accepts it once enough blocks have passed.
+#[tokio::test]
+async fn withdraw_min_account_age_test() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let deposit_amount: u64 = 1000;
+    let withdraw_amount: u64 = 400;
+    let min_account_age: u64 = 5;
+
+    // Create a faucet to mint test assets
+    let faucet =
+        builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", deposit_amount, Some(10))?;
+
+    // Create note sender account (the depositor)
+    let sender = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet.id(), deposit_amount)?.into()],
+    )?;
+
+    // Build contracts
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+    let withdraw_request_note_package =
+        build_project_cached(Path::new("../contracts/withdraw-request-note"), true)?;
+
+    // Create the bank account with a minimum account age
+    let storage_slots = AccountCreationConfig::bank_default()?.storage_slots;
+    let min_account_age_slot = bank_slot_name("min_account_age")?;
+    let first_deposit_blocks_slot = bank_slot_name("first_deposit_blocks")?;
+    let storage_slots = storage_slots
+        .into_iter()
+        .map(|slot| {
+            if slot.name() == &min_account_age_slot {
+                StorageSlot::with_value(
+                    min_account_age_slot.clone(),
+                    Word::from([Felt::new(min_account_age), Felt::new(0), Felt::new(0), Felt::new(0)]),
+                )
+            } else {
+                slot
+            }
+        })
+        .collect();
+    let bank_cfg = AccountCreationConfig {
+        storage_slots,
+        ..Default::default()
+    };
+
+    // Craft the deposit note
+    let deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount)?.into()])?,
+            ..Default::default()
+        },
+    )?;
+
+    // Craft the withdraw request note
+    let p2id_tag = p2id_tag_for_account(sender.id());
+    let p2id_output_note_serial_num = Word::from([
+        Felt::new(0xdddddddddddddddd),
+        Felt::new(0xeeeeeeeeeeeeeeee),
+        Felt::new(0x1010101010101010),
+        Felt::new(0x2020202020202020),
+    ]);
+
+    let withdraw_request_note = create_testing_note_from_package(
+        withdraw_request_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            inputs: build_withdraw_note_inputs(
+                FungibleAsset::new(faucet.id(), withdraw_amount)?,
+                p2id_output_note_serial_num,
+                p2id_tag,
+                NoteType::Public,
+            ),
+            ..Default::default()
+        },
+    )?;
+
+    builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+    builder.add_output_note(OutputNote::Full(withdraw_request_note.clone()));
+
+    // Create and initialize the bank
+    let InitializedBank {
+        mut bank_account,
+        mut mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
+
+    // Deposit, recording the first deposit block
+    let deposit_block = mock_chain.latest_block_header().block_num().as_u32();
+    let deposit_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+        .build()?;
+
+    let executed_deposit = deposit_tx_context.execute().await?;
+    bank_account.apply_delta(executed_deposit.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_deposit)?;
+    mock_chain.prove_next_block()?;
+
+    let age_key = Word::from([
+        sender.id().prefix().as_felt(),
+        sender.id().suffix(),
+        Felt::new(0),
+        Felt::new(0),
+    ]);
+    assert_word_eq(
+        bank_account.storage().get_map_item(&first_deposit_blocks_slot, age_key)?,
+        Word::from([Felt::new(0), Felt::new(0), Felt::new(0), Felt::new(deposit_block as u64)]),
+        "First deposit block",
+    );
+
+    // Expected P2ID output note produced by the withdrawal
+    let recipient = build_p2id_recipient(sender.id(), p2id_output_note_serial_num)?;
+    let p2id_output_note = Note::new(
+        NoteAssets::new(vec![FungibleAsset::new(faucet.id(), withdraw_amount)?.into()])?,
+        NoteMetadata::new(bank_account.id(), NoteType::Public, p2id_tag),
+        recipient,
+    );
+
+    // Withdrawing right after the deposit is too early
+    let early_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[withdraw_request_note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(p2id_output_note.clone())])
+        .build()?;
+
+    let result = early_tx_context.execute().await;
+
+    assert!(
+        result.is_err(),
+        "Expected withdrawal before the minimum account age to fail, but it succeeded"
+    );
+
+    // Once the minimum age is reached the same withdrawal succeeds
+    mock_chain.prove_until_block(deposit_block + min_account_age as u32)?;
+
+    let tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[withdraw_request_note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(p2id_output_note)])
+        .build()?;
+
+    let executed_withdraw = tx_context.execute().await?;
+    bank_account.apply_delta(executed_withdraw.account_delta())?;
+
+    // Balance after withdrawing at the minimum account age
+    assert_balance(&bank_account, sender.id(), faucet.id(), deposit_amount - withdraw_amount);
+
+    Ok(())
+}
+
+/// Test that withdrawals can drain the bank's vault to exactly zero but never past it.
+///
+/// A depositor deposits a known amount and withdraws it in several steps summing to the
+/// deposit. After each step the vault balance must equal the depositor's remaining
+/// balance (the bank's only liability). A further withdrawal must then fail.
+#[tokio::test]
+async fn withdraw_vault_tracks_liabilities_test() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let deposit_amount: u64 = 1000;
+    let withdraw_amounts: [u64; 3] = [300, 300, 400];
+
+    // Create a faucet to mint test assets
+    let faucet =
+        builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", deposit_amount, Some(10))?;
+
+    // Create note sender account (the depositor)
+    let sender = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet.id(), deposit_amount)?.into()],
+    )?;
+
+    // Build contracts
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+    let withdraw_request_note_package =
+        build_project_cached(Path::new("../contracts/withdraw-request-note"), true)?;
+
+    // Create the bank account with named storage slots
+    let bank_cfg = AccountCreationConfig::bank_default()?;
+
+    // Craft the deposit note
+    let deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount)?.into()])?,
+            ..Default::default()
+        },
+    )?;
+
+    // Craft one withdraw request note per step, plus one more than the deposit covers.
+    // Each P2ID output note gets its own serial number, which also keeps the request
+    // notes distinct when amounts repeat.
+    let p2id_tag = p2id_tag_for_account(sender.id());
+    let withdrawals = withdraw_amounts
+        .iter()
+        .copied()
+        .chain([1])
+        .enumerate()
+        .map(|(step, amount)| {
+            let serial_num =
+                Word::from([Felt::new(step as u64 + 1), Felt::new(0), Felt::new(0), Felt::new(0)]);
+            let note = create_testing_note_from_package(
+                withdraw_request_note_package.clone(),
+                sender.id(),
+                NoteCreationConfig {
+                    inputs: build_withdraw_note_inputs(
+                        FungibleAsset::new(faucet.id(), amount)?,
+                        serial_num,
+                        p2id_tag,
+                        NoteType::Public,
+                    ),
+                    ..Default::default()
+                },
+            )?;
+            Ok((note, serial_num, amount))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+    for (note, _, _) in &withdrawals {
+        builder.add_output_note(OutputNote::Full(note.clone()));
+    }
+
+    // Create and initialize the bank
+    let InitializedBank {
+        mut bank_account,
+        mut mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
+
+    // Deposit
+    let deposit_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+        .build()?;
+
+    let executed_deposit = deposit_tx_context.execute().await?;
+    bank_account.apply_delta(executed_deposit.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_deposit)?;
+    mock_chain.prove_next_block()?;
+
+    let (overdraw, steps) = withdrawals.split_last().expect("At least one withdrawal");
+
+    // Withdraw the full deposit step by step
+    let mut remaining = deposit_amount;
+    for (note, serial_num, amount) in steps {
+        let p2id_output_note = Note::new(
+            NoteAssets::new(vec![FungibleAsset::new(faucet.id(), *amount)?.into()])?,
+            NoteMetadata::new(bank_account.id(), NoteType::Public, p2id_tag),
+            build_p2id_recipient(sender.id(), *serial_num)?,
+        );
+
+        let tx_context = mock_chain
+            .build_tx_context(bank_account.id(), &[note.id()], &[])?
+            .extend_expected_output_notes(vec![OutputNote::Full(p2id_output_note)])
+            .build()?;
+
+        let executed_withdraw = tx_context.execute().await?;
+        bank_account.apply_delta(executed_withdraw.account_delta())?;
+        mock_chain.add_pending_executed_transaction(&executed_withdraw)?;
+        mock_chain.prove_next_block()?;
+
+        remaining -= amount;
+
+        // The vault must exactly back the remaining liability
+        let liability = get_balance_for_asset(&bank_account, sender.id(), faucet.id())?;
+        assert_eq!(liability, remaining);
+        assert_eq!(
+            bank_account.vault().get_balance(faucet.id())?,
+            remaining,
+            "Vault balance should track the remaining liability"
+        );
+    }
+
+    assert_eq!(bank_account.vault().get_balance(faucet.id())?, 0);
+
+    // One more withdrawal can't push the vault below zero
+    let (note, serial_num, amount) = overdraw;
+    let p2id_output_note = Note::new(
+        NoteAssets::new(vec![FungibleAsset::new(faucet.id(), *amount)?.into()])?,
+        NoteMetadata::new(bank_account.id(), NoteType::Public, p2id_tag),
+        build_p2id_recipient(sender.id(), *serial_num)?,
+    );
+
+    let overdraw_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(p2id_output_note)])
+        .build()?;
+
+    let result = overdraw_tx_context.execute().await;
+
+    assert!(
+        result.is_err(),
+        "Expected a withdrawal from an empty vault to fail, but it succeeded"
+    );
+
+    Ok(())
+}
+
+/// Test that a withdraw cooldown rejects a second withdrawal too soon after the first,
+/// and allows it once enough blocks have passed.
+#[tokio::test]
+async fn withdraw_cooldown_test() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let deposit_amount: u64 = 1000;
+    // Different amounts keep the two withdraw request notes distinct
+    let first_withdraw_amount: u64 = 400;
+    let second_withdraw_amount: u64 = 300;
+    let withdraw_cooldown: u64 = 5;
+
+    // Create a faucet to mint test assets
+    let faucet =
+        builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", deposit_amount, Some(10))?;
+
+    // Create note sender account (the depositor)
+    let sender = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet.id(), deposit_amount)?.into()],
+    )?;
+
+    // Build contracts
+    let bank_package = build_project_cached(Path::new("../contracts/bank-account"), true)?;
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+    let init_tx_script_package =
+        build_project_cached(Path::new("../contracts/init-tx-script"), true)?;
+    let withdraw_request_note_package =
+        build_project_cached(Path::new("../contracts/withdraw-request-note"), true)?;
+
+    // Create the bank account with a withdraw cooldown
+    let storage_slots = AccountCreationConfig::bank_default()?.storage_slots;
+    let withdraw_cooldown_slot = bank_slot_name("withdraw_cooldown")?;
+    let last_withdraw_blocks_slot = bank_slot_name("last_withdraw_blocks")?;
+    let storage_slots = storage_slots
+        .into_iter()
+        .map(|slot| {
+            if slot.name() == &withdraw_cooldown_slot {
+                StorageSlot::with_value(
+                    withdraw_cooldown_slot.clone(),
+                    Word::from([Felt::new(withdraw_cooldown), Felt::new(0), Felt::new(0), Felt::new(0)]),
+                )
+            } else {
+                slot
+            }
+        })
+        .collect();
+    let bank_cfg = AccountCreationConfig {
+        storage_slots,
+        ..Default::default()
+    };
+
+    let mut bank_account =
+        create_testing_account_from_package(bank_package.clone(), bank_cfg).await?;
+    assert_bank_slots(&bank_account);
+
+    // Craft the deposit note
+    let deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount)?.into()])?,
+            ..Default::default()
+        },
+    )?;
+
+    // Craft two withdraw request notes, each with its own P2ID output serial number
+    let p2id_tag = p2id_tag_for_account(sender.id());
+    let first_serial_num = Word::from([
+        Felt::new(0xc001c001c001c001),
+        Felt::new(0x1),
+        Felt::new(0x2),
+        Felt::new(0x3),
+    ]);
+    let second_serial_num = Word::from([
+        Felt::new(0xc001c001c001c001),
+        Felt::new(0x4),
+        Felt::new(0x5),
+        Felt::new(0x6),
+    ]);
+
+    let mut withdraw_request_notes = Vec::new();
+    let mut p2id_output_notes = Vec::new();
+    for (amount, serial_num) in [
+        (first_withdraw_amount, first_serial_num),
+        (second_withdraw_amount, second_serial_num),
+    ] {
+        withdraw_request_notes.push(create_testing_note_from_package(
+            withdraw_request_note_package.clone(),
+            sender.id(),
+            NoteCreationConfig {
+                inputs: build_withdraw_note_inputs(
+                    FungibleAsset::new(faucet.id(), amount)?,
+                    serial_num,
+                    p2id_tag,
+                    NoteType::Public,
+                ),
+                ..Default::default()
+            },
+        )?);
+        p2id_output_notes.push(Note::new(
+            NoteAssets::new(vec![FungibleAsset::new(faucet.id(), amount)?.into()])?,
+            NoteMetadata::new(bank_account.id(), NoteType::Public, p2id_tag),
+            build_p2id_recipient(sender.id(), serial_num)?,
+        ));
+    }
+
+    builder.add_account(bank_account.clone())?;
+    builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+    for note in &withdraw_request_notes {
+        builder.add_output_note(OutputNote::Full(note.clone()));
+    }
+
+    let mut mock_chain = builder.build()?;
+
+    // Initialize the bank
+    let init_program = init_tx_script_package.unwrap_program();
+    let init_tx_script = TransactionScript::new((*init_program).clone());
+
+    let init_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[], &[])?
+        .tx_script(init_tx_script)
+        .build()?;
+
+    let executed_init = init_tx_context.execute().await?;
+    bank_account.apply_delta(executed_init.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_init)?;
+    mock_chain.prove_next_block()?;
+
+    // Deposit
+    let deposit_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+        .build()?;
+
+    let executed_deposit = deposit_tx_context.execute().await?;
+    bank_account.apply_delta(executed_deposit.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_deposit)?;
+    mock_chain.prove_next_block()?;
+
+    // The first withdrawal succeeds and records its block
+    let withdraw_block = mock_chain.latest_block_header().block_num().as_u32();
+    let tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[withdraw_request_notes[0].id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(p2id_output_notes[0].clone())])
+        .build()?;
+
+    let executed_withdraw = tx_context.execute().await?;
+    bank_account.apply_delta(executed_withdraw.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_withdraw)?;
+    mock_chain.prove_next_block()?;
+
+    let withdraw_key = Word::from([
+        sender.id().prefix().as_felt(),
+        sender.id().suffix(),
+        Felt::new(0),
+        Felt::new(0),
+    ]);
+    assert_word_eq(
+        bank_account.storage().get_map_item(&last_withdraw_blocks_slot, withdraw_key)?,
+        Word::from([Felt::new(0), Felt::new(0), Felt::new(0), Felt::new(withdraw_block as u64)]),
+        "Last withdraw block",
+    );
+
+    // Withdrawing again in the next block is within the cooldown
+    let early_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[withdraw_request_notes[1].id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(p2id_output_notes[1].clone())])
+        .build()?;
+
+    let result = early_tx_context.execute().await;
+
+    assert!(
+        result.is_err(),
+        "Expected withdrawal within the cooldown to fail, but it succeeded"
+    );
+
+    // Once the cooldown has elapsed the same withdrawal succeeds
+    mock_chain.prove_until_block(withdraw_block + withdraw_cooldown as u32)?;
+
+    let tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[withdraw_request_notes[1].id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(p2id_output_notes[1].clone())])
+        .build()?;
+
+    let executed_withdraw = tx_context.execute().await?;
+    bank_account.apply_delta(executed_withdraw.account_delta())?;
+
+    // Balance after both withdrawals
+    assert_balance(&bank_account, sender.id(), faucet.id(), deposit_amount - first_withdraw_amount - second_withdraw_amount);
+    assert_word_eq(
+        bank_account.storage().get_map_item(&last_withdraw_blocks_slot, withdraw_key)?,
+        Word::from([
+            Felt::new(0),
+            Felt::new(0),
+            Felt::new(0),
+            Felt::new((withdraw_block + withdraw_cooldown as u32) as u64),
+        ]),
+        "Last withdraw block after the second withdrawal",
+    );
+
+    Ok(())
+}
+
+/// Test that the bank only debits a balance for a withdrawal sent by its depositor.
+///
+/// Account A deposits. Account B then sends a note that calls `withdraw` with A's
+/// AccountId, which the bank must reject; the same note sent by A succeeds.
+#[tokio::test]
+async fn withdraw_by_other_account_should_fail() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let deposit_amount: u64 = 1000;
+    let withdraw_amount: u64 = 400;
+
+    // Create a faucet to mint test assets
+    let faucet =
+        builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", deposit_amount, Some(10))?;
+
+    // Account A deposits; account B holds nothing in the bank
+    let depositor = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet.id(), deposit_amount)?.into()],
+    )?;
+    let attacker = builder.add_existing_wallet(Auth::BasicAuth)?;
+
+    // Build contracts
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+    // Test fixture: a withdraw note that names the depositor in its inputs
+    let spoofed_withdraw_note_package =
+        build_project_cached(Path::new("tests/fixtures/spoofed-withdraw-note"), true)?;
+
+    // Create the bank account with named storage slots
+    let bank_cfg = AccountCreationConfig::bank_default()?;
+
+    // Craft the deposit note
+    let deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        depositor.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount)?.into()])?,
+            ..Default::default()
+        },
+    )?;
+
+    // Craft a withdrawal of A's balance sent by B, and one sent by A; each uses its own
+    // P2ID serial number so the two notes are distinct
+    let p2id_tag = p2id_tag_for_account(depositor.id());
+    let attacker_serial_num = Word::from([
+        Felt::new(0x5b00f5b00f5b00f5),
+        Felt::new(0x1),
+        Felt::new(0x2),
+        Felt::new(0x3),
+    ]);
+    let depositor_serial_num = Word::from([
+        Felt::new(0x5b00f5b00f5b00f5),
+        Felt::new(0x4),
+        Felt::new(0x5),
+        Felt::new(0x6),
+    ]);
+    let spoofed_inputs = |serial_num: Word| {
+        vec![
+            // DEPOSITOR CLAIMED BY THE NOTE
+            depositor.id().prefix().as_felt(),
+            depositor.id().suffix(),
+            // WITHDRAW ASSET WORD
+            Felt::new(withdraw_amount),
+            Felt::new(0),
+            faucet.id().suffix(),
+            faucet.id().prefix().as_felt(),
+            // P2ID OUTPUT NOTE SERIAL NUMBER
+            serial_num[0],
+            serial_num[1],
+            serial_num[2],
+            serial_num[3],
+            // TAG
+            Felt::new(p2id_tag.as_u32() as u64),
+            // NOTE TYPE (1 = Public)
+            Felt::new(1),
+        ]
+    };
+    let attacker_note = create_testing_note_from_package(
+        spoofed_withdraw_note_package.clone(),
+        attacker.id(),
+        NoteCreationConfig {
+            inputs: spoofed_inputs(attacker_serial_num),
+            ..Default::default()
+        },
+    )?;
+    let depositor_note = create_testing_note_from_package(
+        spoofed_withdraw_note_package.clone(),
+        depositor.id(),
+        NoteCreationConfig {
+            inputs: spoofed_inputs(depositor_serial_num),
+            ..Default::default()
+        },
+    )?;
+
+    builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+    builder.add_output_note(OutputNote::Full(attacker_note.clone()));
+    builder.add_output_note(OutputNote::Full(depositor_note.clone()));
+
+    // Create and initialize the bank
+    let InitializedBank {
+        mut bank_account,
+        mut mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
+
+    // A deposits
+    let deposit_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+        .build()?;
+
+    let executed_deposit = deposit_tx_context.execute().await?;
+    bank_account.apply_delta(executed_deposit.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_deposit)?;
+    mock_chain.prove_next_block()?;
+
+    // Expected P2ID output notes; either withdrawal would pay A
+    let p2id_output_note = |serial_num: Word| -> anyhow::Result<Note> {
+        Ok(Note::new(
+            NoteAssets::new(vec![FungibleAsset::new(faucet.id(), withdraw_amount)?.into()])?,
+            NoteMetadata::new(bank_account.id(), NoteType::Public, p2id_tag),
+            build_p2id_recipient(depositor.id(), serial_num)?,
+        ))
+    };
+
+    // B's request for A's balance is rejected
+    let attacker_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[attacker_note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(p2id_output_note(attacker_serial_num)?)])
+        .build()?;
+
+    let result = attacker_tx_context.execute().await;
+
+    assert!(
+        result.is_err(),
+        "Expected a withdrawal sent by another account to fail, but it succeeded"
+    );
+
+    // The same request sent by A is accepted
+    let tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[depositor_note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(p2id_output_note(depositor_serial_num)?)])
+        .build()?;
+
+    let executed_withdraw = tx_context.execute().await?;
+    bank_account.apply_delta(executed_withdraw.account_delta())?;
+
+    // Balance after the depositor's own withdrawal
+    assert_balance(&bank_account, depositor.id(), faucet.id(), deposit_amount - withdraw_amount);
+
+    Ok(())
+}
+
+/// Test that a withdraw-all note pays out the depositor's entire balance and zeroes it.
+///
+/// The same note is first consumed before any deposit, which must fail rather than
+/// emit an empty P2ID note; after a deposit of 1000 it withdraws exactly 1000.
+#[tokio::test]
+async fn withdraw_all_test() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let deposit_amount: u64 = 1000;
+
+    // Create a faucet to mint test assets
+    let faucet =
+        builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", deposit_amount, Some(10))?;
+
+    // Create note sender account (the depositor)
+    let sender = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet.id(), deposit_amount)?.into()],
+    )?;
+
+    // Build contracts
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+    let withdraw_all_note_package =
+        build_project_cached(Path::new("../contracts/withdraw-all-note"), true)?;
+
+    // Create the bank account with named storage slots
+    let bank_cfg = AccountCreationConfig::bank_default()?;
+
+    // Craft the deposit note
+    let deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount)?.into()])?,
+            ..Default::default()
+        },
+    )?;
+
+    // Craft the withdraw-all note; it names the faucet but no amount
+    let p2id_tag = p2id_tag_for_account(sender.id());
+    let p2id_output_note_serial_num = Word::from([
+        Felt::new(0x9999999999999999),
+        Felt::new(0xaaaaaaaaaaaaaaaa),
+        Felt::new(0xbbbbbbbbbbbbbbbb),
+        Felt::new(0xcccccccccccccccc),
+    ]);
+
+    // Note inputs layout (8 Felts):
+    // [0-1]: faucet (prefix, suffix)
+    // [2-5]: serial_num
+    // [6]: tag
+    // [7]: note_type (1 = Public)
+    let withdraw_all_note = create_testing_note_from_package(
+        withdraw_all_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            inputs: vec![
+                faucet.id().prefix().as_felt(),
+                faucet.id().suffix(),
+                p2id_output_note_serial_num[0],
+                p2id_output_note_serial_num[1],
+                p2id_output_note_serial_num[2],
+                p2id_output_note_serial_num[3],
+                Felt::new(p2id_tag.as_u32() as u64),
+                Felt::new(1),
+            ],
+            ..Default::default()
+        },
+    )?;
+
+    builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+    builder.add_output_note(OutputNote::Full(withdraw_all_note.clone()));
+
+    // Create and initialize the bank
+    let InitializedBank {
+        mut bank_account,
+        mut mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
+
+    // With nothing deposited yet, withdrawing everything must fail
+    let empty_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[withdraw_all_note.id()], &[])?
+        .build()?;
+
+    let result = empty_tx_context.execute().await;
+
+    assert!(
+        result.is_err(),
+        "Expected withdrawing an empty balance to fail, but it succeeded"
+    );
+
+    // Deposit
+    let deposit_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+        .build()?;
+
+    let executed_deposit = deposit_tx_context.execute().await?;
+    bank_account.apply_delta(executed_deposit.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_deposit)?;
+    mock_chain.prove_next_block()?;
+
+    // Expected P2ID output note carrying the whole balance
+    let p2id_output_note = Note::new(
+        NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount)?.into()])?,
+        NoteMetadata::new(bank_account.id(), NoteType::Public, p2id_tag),
+        build_p2id_recipient(sender.id(), p2id_output_note_serial_num)?,
+    );
+
+    let tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[withdraw_all_note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(p2id_output_note)])
+        .build()?;
+
+    let executed_withdraw = tx_context.execute().await?;
+
+    let OutputNote::Full(withdrawn_note) = executed_withdraw.output_notes().get_note(0) else {
+        panic!("Expected the withdrawal to output a full P2ID note");
+    };
+    assert_p2id_recipient_targets(
+        withdrawn_note,
+        sender.id(),
+        FungibleAsset::new(faucet.id(), deposit_amount)?.into(),
+    );
+
+    bank_account.apply_delta(executed_withdraw.account_delta())?;
+
+    // Balance after withdrawing everything
+    assert_balance(&bank_account, sender.id(), faucet.id(), 0);
+
+    Ok(())
+}
+
+/// Test that a withdrawal requesting a note type other than Public (1) or Private (2)
+/// is rejected instead of creating a P2ID note with undefined routing.
+#[tokio::test]
+async fn withdraw_invalid_note_type_should_fail() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let deposit_amount: u64 = 1000;
+    let withdraw_amount: u64 = 400;
+
+    // Create a faucet to mint test assets
+    let faucet =
+        builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", deposit_amount, Some(10))?;
+
+    // Create note sender account (the depositor)
+    let sender = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet.id(), deposit_amount)?.into()],
+    )?;
+
+    // Build contracts
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+    let withdraw_request_note_package =
+        build_project_cached(Path::new("../contracts/withdraw-request-note"), true)?;
+
+    // Create the bank account with named storage slots
+    let bank_cfg = AccountCreationConfig::bank_default()?;
+
+    // Craft the deposit note
+    let deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount)?.into()])?,
+            ..Default::default()
+        },
+    )?;
+
+    // Craft a withdraw request note asking for note type 3
+    let p2id_tag = p2id_tag_for_account(sender.id());
+    let p2id_output_note_serial_num = Word::from([
+        Felt::new(0x3333333333333333),
+        Felt::new(0x1),
+        Felt::new(0x2),
+        Felt::new(0x3),
+    ]);
+
+    // Note inputs layout (10 Felts):
+    // [0-3]: withdraw asset (amount, 0, faucet_suffix, faucet_prefix)
+    // [4-7]: serial_num of the P2ID output note
+    // [8]: tag
+    // [9]: note_type (3 = invalid)
+    let withdraw_request_note = create_testing_note_from_package(
+        withdraw_request_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            inputs: vec![
+                Felt::new(withdraw_amount),
+                Felt::new(0),
+                faucet.id().suffix(),
+                faucet.id().prefix().as_felt(),
+                p2id_output_note_serial_num[0],
+                p2id_output_note_serial_num[1],
+                p2id_output_note_serial_num[2],
+                p2id_output_note_serial_num[3],
+                Felt::new(p2id_tag.as_u32() as u64),
+                Felt::new(3),
+            ],
+            ..Default::default()
+        },
+    )?;
+
+    builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+    builder.add_output_note(OutputNote::Full(withdraw_request_note.clone()));
+
+    // Create and initialize the bank
+    let InitializedBank {
+        mut bank_account,
+        mut mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
+
+    // Deposit
+    let deposit_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+        .build()?;
+
+    let executed_deposit = deposit_tx_context.execute().await?;
+    bank_account.apply_delta(executed_deposit.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_deposit)?;
+    mock_chain.prove_next_block()?;
+
+    // The withdrawal names an undefined note type and must be rejected
+    let tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[withdraw_request_note.id()], &[])?
+        .build()?;
+
+    let result = tx_context.execute().await;
+
+    assert!(
+        result.is_err(),
+        "Expected a withdrawal with note type 3 to fail, but it succeeded"
+    );
+
+    Ok(())
+}
+
+/// Test that a withdraw request note leaving its note type at 0 gets a Private P2ID
+/// payout instead of being rejected as an undefined note type.
+#[tokio::test]
+async fn withdraw_default_note_type_is_private_test() -> anyhow::Result<()> {
+    let deposit_amount: u64 = 1000;
+    let withdraw_amount: u64 = 400;
+
+    let mut fixture = BankFixture::builder().faucet_supply(deposit_amount).build().await?;
+    fixture.deposit(deposit_amount).await?;
+
+    let withdraw_request_note_package =
+        build_project_cached(Path::new("../contracts/withdraw-request-note"), true)?;
+
+    let p2id_tag = p2id_tag_for_account(fixture.sender.id());
+    let p2id_output_note_serial_num = Word::from([
+        Felt::new(0x4444444444444444),
+        Felt::new(0x1),
+        Felt::new(0x2),
+        Felt::new(0x3),
+    ]);
+
+    // Note inputs layout (10 Felts):
+    // [0-3]: withdraw asset (amount, 0, faucet_suffix, faucet_prefix)
+    // [4-7]: serial_num of the P2ID output note
+    // [8]: tag
+    // [9]: note_type (0 = default)
+    let withdraw_request_note = create_testing_note_from_package(
+        withdraw_request_note_package,
+        fixture.sender.id(),
+        NoteCreationConfig {
+            inputs: vec![
+                Felt::new(withdraw_amount),
+                Felt::new(0),
+                fixture.faucet.id().suffix(),
+                fixture.faucet.id().prefix().as_felt(),
+                p2id_output_note_serial_num[0],
+                p2id_output_note_serial_num[1],
+                p2id_output_note_serial_num[2],
+                p2id_output_note_serial_num[3],
+                Felt::new(p2id_tag.as_u32() as u64),
+                Felt::new(0),
+            ],
+            ..Default::default()
+        },
+    )?;
+
+    // The payout defaults to a Private P2ID note
+    let expected_p2id_note = Note::new(
+        NoteAssets::new(vec![FungibleAsset::new(fixture.faucet.id(), withdraw_amount)?.into()])?,
+        NoteMetadata::new(fixture.bank_account.id(), NoteType::Private, p2id_tag),
+        build_p2id_recipient(fixture.sender.id(), p2id_output_note_serial_num)?,
+    );
+
+    fixture.publish(std::slice::from_ref(&withdraw_request_note)).await?;
+    let executed_withdraw = fixture
+        .consume(&[withdraw_request_note], vec![expected_p2id_note.clone()])
+        .await?;
+
+    let output_note = executed_withdraw.output_notes().get_note(0);
+    assert_eq!(output_note.id(), expected_p2id_note.id(), "Unexpected P2ID note");
+    assert_eq!(
+        output_note.metadata().note_type(),
+        NoteType::Private,
+        "A withdrawal without a note type should pay out a Private note"
+    );
+    assert_eq!(
+        fixture.balance_of(fixture.sender.id())?,
+        deposit_amount - withdraw_amount,
+        "Balance after the withdrawal"
+    );
+
+    Ok(())
+}
+
+/// Test that a withdraw request note missing its note type input is rejected.
+///
+/// The note type is the last required input, so a note with only 9 inputs cannot say
+/// how the payout note is routed.
+#[tokio::test]
+async fn withdraw_missing_inputs_should_fail() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let deposit_amount: u64 = 1000;
+    let withdraw_amount: u64 = 400;
+
+    // Create a faucet to mint test assets
+    let faucet =
+        builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", deposit_amount, Some(10))?;
+
+    // Create note sender account (the depositor)
+    let sender = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet.id(), deposit_amount)?.into()],
+    )?;
+
+    // Build contracts
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+    let withdraw_request_note_package =
+        build_project_cached(Path::new("../contracts/withdraw-request-note"), true)?;
+
+    // Create the bank account with named storage slots
+    let bank_cfg = AccountCreationConfig::bank_default()?;
+
+    // Craft the deposit note
+    let deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount)?.into()])?,
+            ..Default::default()
+        },
+    )?;
+
+    // Craft a withdraw request note without the note type
+    let p2id_tag = p2id_tag_for_account(sender.id());
+    let p2id_output_note_serial_num = Word::from([
+        Felt::new(0x4444444444444444),
+        Felt::new(0x1),
+        Felt::new(0x2),
+        Felt::new(0x3),
+    ]);
+
+    // Note inputs layout (9 Felts):
+    // [0-3]: withdraw asset (amount, 0, faucet_suffix, faucet_prefix)
+    // [4-7]: serial_num of the P2ID output note
+    // [8]: tag
+    let withdraw_request_note = create_testing_note_from_package(
+        withdraw_request_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            inputs: vec![
+                Felt::new(withdraw_amount),
+                Felt::new(0),
+                faucet.id().suffix(),
+                faucet.id().prefix().as_felt(),
+                p2id_output_note_serial_num[0],
+                p2id_output_note_serial_num[1],
+                p2id_output_note_serial_num[2],
+                p2id_output_note_serial_num[3],
+                Felt::new(p2id_tag.as_u32() as u64),
+            ],
+            ..Default::default()
+        },
+    )?;
 
-    (initialized_slot, balances_slot, slots)
+    builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+    builder.add_output_note(OutputNote::Full(withdraw_request_note.clone()));
+
+    // Create and initialize the bank
+    let InitializedBank {
+        mut bank_account,
+        mut mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
+
+    // Deposit
+    let deposit_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+        .build()?;
+
+    let executed_deposit = deposit_tx_context.execute().await?;
+    bank_account.apply_delta(executed_deposit.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_deposit)?;
+    mock_chain.prove_next_block()?;
+
+    // The withdrawal is missing its note type and must be rejected
+    let tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[withdraw_request_note.id()], &[])?
+        .build()?;
+
+    let result = tx_context.execute().await;
+
+    assert!(
+        result.is_err(),
+        "Expected a withdrawal with 9 inputs to fail, but it succeeded"
+    );
+
+    Ok(())
 }
 
+/// Test that two withdrawals in one limit window cannot together exceed the
+/// depositor's withdrawal limit.
+///
+/// The owner caps depositor 0 at 600 per window; withdrawing 400 succeeds, and the
+/// following 300 is rejected even though the balance still covers it.
 #[tokio::test]
-async fn withdraw_test() -> anyhow::Result<()> {
-    // *********************************************************************************
-    // SETUP
-    // *********************************************************************************
+async fn withdraw_limit_test() -> anyhow::Result<()> {
+    let result = run_scenario(Scenario {
+        faucets: vec![FaucetSpec {
+            symbol: "TEST",
+            max_supply: 1000,
+        }],
+        depositors: vec![DepositorSpec {
+            assets: vec![(0, 1000)],
+        }],
+        owner: Some(0),
+        operations: vec![
+            Operation::Deposit {
+                depositor: 0,
+                faucet: 0,
+                amount: 1000,
+            },
+            Operation::SetWithdrawLimit {
+                sender: 0,
+                depositor: 0,
+                faucet: 0,
+                limit: 600,
+            },
+            Operation::Withdraw {
+                depositor: 0,
+                faucet: 0,
+                amount: 400,
+            },
+            Operation::Withdraw {
+                depositor: 0,
+                faucet: 0,
+                amount: 300,
+            },
+        ],
+        ..Default::default()
+    })
+    .await;
+
+    // The first withdrawal (operation 2) fits the limit; the second (operation 3) does not
+    let failure = result
+        .err()
+        .expect("Expected the second withdrawal to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 3, "{}", failure);
+    assert_eq!(failure.state.balance(0, 0), 600, "Only the first withdrawal should fit the limit");
+
+    Ok(())
+}
+
+/// Test that a withdraw request for zero tokens is rejected instead of emitting an empty
+/// P2ID note.
+#[tokio::test]
+async fn withdraw_zero_amount_should_fail() -> anyhow::Result<()> {
+    let result = run_scenario(single_depositor_scenario(vec![
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: 1000,
+        },
+        Operation::Withdraw {
+            depositor: 0,
+            faucet: 0,
+            amount: 0,
+        },
+    ]))
+    .await;
+
+    let failure = result
+        .err()
+        .expect("Expected the zero-amount withdrawal to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 1, "{}", failure);
+    assert_eq!(failure.state.balance(0, 0), 1000, "The balance should be untouched");
+
+    Ok(())
+}
+
+/// Test that a withdrawal the fee would consume entirely is rejected.
+///
+/// With a 10,000 bps fee the whole amount would be retained, leaving an empty P2ID note
+/// for the depositor.
+#[tokio::test]
+async fn withdraw_full_fee_should_fail() -> anyhow::Result<()> {
+    let scenario = Scenario::builder()
+        .faucet("TEST", 1000)
+        .depositor(&[(0, 1000)])
+        .config("fee_bps", 10_000);
+    let result = run_scenario(scenario.operations(vec![
+        Operation::Deposit {
+            depositor: 0,
+            faucet: 0,
+            amount: 1000,
+        },
+        Operation::Withdraw {
+            depositor: 0,
+            faucet: 0,
+            amount: 100,
+        },
+    ]))
+    .await;
+
+    let failure = result
+        .err()
+        .expect("Expected the fully charged withdrawal to fail, but it succeeded")
+        .downcast::<ScenarioFailure>()?;
+    assert_eq!(failure.operation, 1, "{}", failure);
+    let state = &failure.state;
+    assert_eq!(state.balance(0, 0), 1000, "The balance should be untouched");
+    assert_eq!(get_fees(&state.bank_account, state.faucet_ids[0])?, 0, "No fee should be retained");
 
-    // Test that after executing the deposit note, the depositor's balance is updated
+    Ok(())
+}
+
+/// Test that a bank with a 100 bps withdrawal fee retains 1% of each withdrawal.
+///
+/// Withdrawing a 1000 deposit debits the full 1000 from the depositor's balance, pays
+/// out 990 in the P2ID note and leaves the 10 fee in the vault, recorded under the
+/// faucet's fees key.
+#[tokio::test]
+async fn withdraw_fee_test() -> anyhow::Result<()> {
     let mut builder = MockChain::builder();
 
-    // Define the deposit amount
     let deposit_amount: u64 = 1000;
+    let fee_bps: u64 = 100;
+    let fee = deposit_amount * fee_bps / 10_000;
 
     // Create a faucet to mint test assets
     let faucet =
@@ -56,195 +1623,541 @@ async fn withdraw_test() -> anyhow::Result<()> {
     )?;
 
     // Build contracts
-    let bank_package = Arc::new(build_project_in_dir(
-        Path::new("../contracts/bank-account"),
-        true,
-    )?);
-    let deposit_note_package = Arc::new(build_project_in_dir(
-        Path::new("../contracts/deposit-note"),
-        true,
-    )?);
-    let init_tx_script_package = Arc::new(build_project_in_dir(
-        Path::new("../contracts/init-tx-script"),
-        true,
-    )?);
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+    let withdraw_request_note_package =
+        build_project_cached(Path::new("../contracts/withdraw-request-note"), true)?;
 
-    // Create the bank account with named storage slots
-    let (_initialized_slot, _balances_slot, storage_slots) = bank_storage_slots();
+    // Create the bank account with a 100 bps withdrawal fee
+    let storage_slots = AccountCreationConfig::bank_default()?.storage_slots;
+    let fee_bps_slot = bank_slot_name("fee_bps")?;
+    let fees_slot = bank_slot_name("fees")?;
+    let storage_slots = storage_slots
+        .into_iter()
+        .map(|slot| {
+            if slot.name() == &fee_bps_slot {
+                StorageSlot::with_value(
+                    fee_bps_slot.clone(),
+                    Word::from([Felt::new(fee_bps), Felt::new(0), Felt::new(0), Felt::new(0)]),
+                )
+            } else {
+                slot
+            }
+        })
+        .collect();
     let bank_cfg = AccountCreationConfig {
         storage_slots,
         ..Default::default()
     };
 
-    let mut bank_account =
-        create_testing_account_from_package(bank_package.clone(), bank_cfg).await?;
-
-    // *********************************************************************************
-    // STEP 1: CRAFT DEPOSIT NOTE
-    // *********************************************************************************
-
-    // Create a fungible asset to deposit
-    let fungible_asset = FungibleAsset::new(faucet.id(), deposit_amount)?;
-    let note_assets = NoteAssets::new(vec![Asset::Fungible(fungible_asset)])?;
-
-    // Create the deposit note with assets attached
-    // The sender becomes the depositor
+    // Craft the deposit note
     let deposit_note = create_testing_note_from_package(
         deposit_note_package.clone(),
         sender.id(),
         NoteCreationConfig {
-            assets: note_assets,
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount)?.into()])?,
+            ..Default::default()
+        },
+    )?;
+
+    // Craft a withdraw request note for the whole deposit
+    let p2id_tag = p2id_tag_for_account(sender.id());
+    let p2id_output_note_serial_num = Word::from([
+        Felt::new(0xfeefeefeefeefee0),
+        Felt::new(0x1),
+        Felt::new(0x2),
+        Felt::new(0x3),
+    ]);
+
+    let withdraw_request_note = create_testing_note_from_package(
+        withdraw_request_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            inputs: build_withdraw_note_inputs(
+                FungibleAsset::new(faucet.id(), deposit_amount)?,
+                p2id_output_note_serial_num,
+                p2id_tag,
+                NoteType::Public,
+            ),
             ..Default::default()
         },
     )?;
 
-    // Add bank account and deposit note to mockchain
-    builder.add_account(bank_account.clone())?;
     builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+    builder.add_output_note(OutputNote::Full(withdraw_request_note.clone()));
 
-    // *********************************************************************************
-    // STEP 2: CRAFT WITHDRAW REQUEST NOTE
-    // *********************************************************************************
+    // Create and initialize the bank
+    let InitializedBank {
+        mut bank_account,
+        mut mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
 
-    let withdraw_amount = deposit_amount / 2;
+    // Deposit
+    let deposit_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+        .build()?;
+
+    let executed_deposit = deposit_tx_context.execute().await?;
+    bank_account.apply_delta(executed_deposit.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_deposit)?;
+    mock_chain.prove_next_block()?;
 
-    // Compute proper P2ID tag for the sender (depositor) who will consume the output note
-    let p2id_tag = NoteTag::with_account_target(sender.id());
-    let p2id_tag_felt = Felt::new(p2id_tag.as_u32() as u64);
+    // Expected P2ID output note carrying the withdrawal less the fee
+    let p2id_output_note = Note::new(
+        NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount - fee)?.into()])?,
+        NoteMetadata::new(bank_account.id(), NoteType::Public, p2id_tag),
+        build_p2id_recipient(sender.id(), p2id_output_note_serial_num)?,
+    );
 
-    println!("Computed P2ID tag for sender: 0x{:08X}", p2id_tag.as_u32());
+    let tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[withdraw_request_note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(p2id_output_note)])
+        .build()?;
 
-    // Random serial number - MUST be unique per note
-    // In production, this would be generated randomly. For testing, we use fixed values.
-    let p2id_output_note_serial_num = Word::from([
-        Felt::new(0x1234567890abcdef),
-        Felt::new(0xfedcba0987654321),
-        Felt::new(0xdeadbeefcafebabe),
-        Felt::new(0x0123456789abcdef),
-    ]);
+    let executed_withdraw = tx_context.execute().await?;
 
-    println!("Serial num (random): {:?}", p2id_output_note_serial_num);
+    let OutputNote::Full(withdrawn_note) = executed_withdraw.output_notes().get_note(0) else {
+        panic!("Expected the withdrawal to output a full P2ID note");
+    };
+    assert_p2id_recipient_targets(
+        withdrawn_note,
+        sender.id(),
+        FungibleAsset::new(faucet.id(), deposit_amount - fee)?.into(),
+    );
 
-    // Note type for the P2ID output note
-    let note_type_felt = Felt::new(1); // 1 = Public note (stored on-chain)
+    bank_account.apply_delta(executed_withdraw.account_delta())?;
 
-    // Note inputs layout (10 Felts):
-    // [0-3]: withdraw asset (amount, 0, faucet_suffix, faucet_prefix)
-    // [4-7]: serial_num (random/unique per note)
-    // [8]: tag (P2ID note tag for routing)
-    // [9]: note_type (1 = Public, 2 = Private)
-    let withdraw_request_note_inputs = vec![
-        // WITHDRAW ASSET WORD
-        Felt::new(withdraw_amount),
-        Felt::new(0),
-        faucet.id().suffix(),
+    // The full amount was debited and the fee stayed in the vault
+    // Balance after withdrawing the whole deposit
+    assert_balance(&bank_account, sender.id(), faucet.id(), 0);
+    let fees_key = Word::from([
         faucet.id().prefix().as_felt(),
-        // P2ID OUTPUT NOTE SERIAL NUMBER (random, unique per note)
-        p2id_output_note_serial_num[0],
-        p2id_output_note_serial_num[1],
-        p2id_output_note_serial_num[2],
-        p2id_output_note_serial_num[3],
-        // TAG (directly passed, no advice provider needed)
-        p2id_tag_felt,
-        // NOTE TYPE (1 = Public)
-        note_type_felt,
-    ];
+        faucet.id().suffix(),
+        Felt::new(0),
+        Felt::new(0),
+    ]);
+    assert_word_eq(
+        bank_account.storage().get_map_item(&fees_slot, fees_key)?,
+        Word::from([Felt::new(0), Felt::new(0), Felt::new(0), Felt::new(fee)]),
+        "Retained withdrawal fee",
+    );
+    assert_eq!(
+        bank_account.vault().get_balance(faucet.id())?,
+        fee,
+        "Only the fee should remain in the bank's vault"
+    );
+
+    Ok(())
+}
+
+/// Test that a bank created with a P2ID root in its `p2id_root` slot builds its payout
+/// notes with that root instead of the compiled-in one.
+///
+/// The P2IDE script stands in for an updated P2ID script: the payout note's recipient
+/// must commit to the P2IDE root with the usual P2ID inputs.
+#[tokio::test]
+async fn withdraw_custom_p2id_root_test() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let deposit_amount: u64 = 1000;
+    let custom_root = WellKnownNote::P2IDE.script_root();
+
+    // Create a faucet to mint test assets
+    let faucet =
+        builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", deposit_amount, Some(10))?;
+
+    // Create note sender account (the depositor)
+    let sender = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet.id(), deposit_amount)?.into()],
+    )?;
+
+    // Build contracts
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+    let withdraw_request_note_package =
+        build_project_cached(Path::new("../contracts/withdraw-request-note"), true)?;
+
+    // Create the bank account with the custom P2ID root
+    let storage_slots = AccountCreationConfig::bank_default()?.storage_slots;
+    let p2id_root_slot = bank_slot_name("p2id_root")?;
+    let storage_slots = storage_slots
+        .into_iter()
+        .map(|slot| {
+            if slot.name() == &p2id_root_slot {
+                StorageSlot::with_value(p2id_root_slot.clone(), custom_root)
+            } else {
+                slot
+            }
+        })
+        .collect();
+    let bank_cfg = AccountCreationConfig {
+        storage_slots,
+        ..Default::default()
+    };
+
+    // Craft the deposit note
+    let deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        sender.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount)?.into()])?,
+            ..Default::default()
+        },
+    )?;
 
-    let withdraw_request_note_package = Arc::new(build_project_in_dir(
-        Path::new("../contracts/withdraw-request-note"),
-        true,
-    )?);
+    // Craft a withdraw request note for the whole deposit
+    let p2id_tag = p2id_tag_for_account(sender.id());
+    let p2id_output_note_serial_num = Word::from([
+        Felt::new(0x1234123412341234),
+        Felt::new(0x5),
+        Felt::new(0x6),
+        Felt::new(0x7),
+    ]);
 
     let withdraw_request_note = create_testing_note_from_package(
         withdraw_request_note_package.clone(),
         sender.id(),
         NoteCreationConfig {
-            inputs: withdraw_request_note_inputs,
+            inputs: build_withdraw_note_inputs(
+                FungibleAsset::new(faucet.id(), deposit_amount)?,
+                p2id_output_note_serial_num,
+                p2id_tag,
+                NoteType::Public,
+            ),
             ..Default::default()
         },
     )?;
 
+    builder.add_output_note(OutputNote::Full(deposit_note.clone()));
     builder.add_output_note(OutputNote::Full(withdraw_request_note.clone()));
 
-    // *********************************************************************************
-    // STEP 3: INITIALIZE THE BANK VIA TX SCRIPT
-    // *********************************************************************************
-    // The bank must be initialized before deposits are accepted.
+    // Create and initialize the bank
+    let InitializedBank {
+        mut bank_account,
+        mut mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
 
-    // Build the mock chain
-    let mut mock_chain = builder.build()?;
+    // Deposit
+    let deposit_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
+        .build()?;
 
-    let init_program = init_tx_script_package.unwrap_program();
-    let init_tx_script = TransactionScript::new((*init_program).clone());
+    let executed_deposit = deposit_tx_context.execute().await?;
+    bank_account.apply_delta(executed_deposit.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_deposit)?;
+    mock_chain.prove_next_block()?;
 
-    let init_tx_context = mock_chain
-        .build_tx_context(bank_account.id(), &[], &[])?
-        .tx_script(init_tx_script)
+    // Expected payout note: P2ID inputs, but the custom script root
+    let expected_recipient = NoteRecipient::new(
+        p2id_output_note_serial_num,
+        WellKnownNote::P2IDE.script(),
+        NoteInputs::new(vec![sender.id().suffix(), sender.id().prefix().as_felt()])?,
+    );
+    let p2id_output_note = Note::new(
+        NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount)?.into()])?,
+        NoteMetadata::new(bank_account.id(), NoteType::Public, p2id_tag),
+        expected_recipient.clone(),
+    );
+
+    let tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[withdraw_request_note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(p2id_output_note)])
         .build()?;
 
-    let executed_init = init_tx_context.execute().await?;
-    bank_account.apply_delta(&executed_init.account_delta())?;
-    mock_chain.add_pending_executed_transaction(&executed_init)?;
-    mock_chain.prove_next_block()?;
+    let executed_withdraw = tx_context.execute().await?;
+
+    let OutputNote::Full(withdrawn_note) = executed_withdraw.output_notes().get_note(0) else {
+        panic!("Expected the withdrawal to output a full note");
+    };
+    assert_word_eq(
+        withdrawn_note.recipient().script().root(),
+        custom_root,
+        "Payout note script root",
+    );
+    assert_word_eq(
+        withdrawn_note.recipient().digest(),
+        expected_recipient.digest(),
+        "Payout note recipient",
+    );
+
+    Ok(())
+}
+
+/// Test that `withdraw` returns the depositor's balance left after the withdrawal.
+///
+/// After a deposit of 1000, a fixture note withdraws 400 and checks the returned
+/// balance: the note expecting 600 succeeds, while one expecting any other value fails.
+#[tokio::test]
+async fn withdraw_returns_new_balance_test() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let deposit_amount: u64 = 1000;
+    let withdraw_amount: u64 = 400;
+
+    // Create a faucet to mint test assets
+    let faucet =
+        builder.add_existing_basic_faucet(Auth::BasicAuth, "TEST", deposit_amount, Some(10))?;
+
+    // Create a depositor with assets to deposit
+    let depositor = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::new(faucet.id(), deposit_amount)?.into()],
+    )?;
+
+    // Build contracts
+    let deposit_note_package = build_project_cached(Path::new("../contracts/deposit-note"), true)?;
+    // Test fixture: a withdraw note that checks the balance `withdraw` returns
+    let checked_withdraw_note_package =
+        build_project_cached(Path::new("tests/fixtures/checked-withdraw-note"), true)?;
+
+    // Create the bank account with named storage slots
+    let bank_cfg = AccountCreationConfig::bank_default()?;
+
+    // Craft the deposit note
+    let deposit_note = create_testing_note_from_package(
+        deposit_note_package.clone(),
+        depositor.id(),
+        NoteCreationConfig {
+            assets: NoteAssets::new(vec![FungibleAsset::new(faucet.id(), deposit_amount)?.into()])?,
+            ..Default::default()
+        },
+    )?;
+
+    // Craft two withdrawals of the same amount, one expecting a wrong balance; each
+    // uses its own P2ID serial number so the two notes are distinct
+    let p2id_tag = p2id_tag_for_account(depositor.id());
+    let wrong_serial_num = Word::from([
+        Felt::new(0x5b00f5b00f5b00f5),
+        Felt::new(0x1),
+        Felt::new(0x2),
+        Felt::new(0x3),
+    ]);
+    let checked_serial_num = Word::from([
+        Felt::new(0x5b00f5b00f5b00f5),
+        Felt::new(0x4),
+        Felt::new(0x5),
+        Felt::new(0x6),
+    ]);
+    let checked_inputs = |serial_num: Word, expected_balance: u64| {
+        vec![
+            // WITHDRAW ASSET WORD
+            Felt::new(withdraw_amount),
+            Felt::new(0),
+            faucet.id().suffix(),
+            faucet.id().prefix().as_felt(),
+            // P2ID OUTPUT NOTE SERIAL NUMBER
+            serial_num[0],
+            serial_num[1],
+            serial_num[2],
+            serial_num[3],
+            // TAG
+            Felt::new(p2id_tag.as_u32() as u64),
+            // NOTE TYPE (1 = Public)
+            Felt::new(1),
+            // EXPECTED BALANCE AFTER THE WITHDRAWAL
+            Felt::new(expected_balance),
+        ]
+    };
+    let wrong_note = create_testing_note_from_package(
+        checked_withdraw_note_package.clone(),
+        depositor.id(),
+        NoteCreationConfig {
+            inputs: checked_inputs(wrong_serial_num, deposit_amount),
+            ..Default::default()
+        },
+    )?;
+    let checked_note = create_testing_note_from_package(
+        checked_withdraw_note_package.clone(),
+        depositor.id(),
+        NoteCreationConfig {
+            inputs: checked_inputs(checked_serial_num, deposit_amount - withdraw_amount),
+            ..Default::default()
+        },
+    )?;
 
-    println!("Bank initialized successfully");
+    builder.add_output_note(OutputNote::Full(deposit_note.clone()));
+    builder.add_output_note(OutputNote::Full(wrong_note.clone()));
+    builder.add_output_note(OutputNote::Full(checked_note.clone()));
 
-    // *********************************************************************************
-    // STEP 4: MAKE DEPOSIT
-    // *********************************************************************************
+    // Create and initialize the bank
+    let InitializedBank {
+        mut bank_account,
+        mut mock_chain,
+        ..
+    } = create_initialized_bank(builder, bank_cfg).await?;
 
-    // Build the transaction context where bank consumes the deposit note
+    // Deposit
     let deposit_tx_context = mock_chain
         .build_tx_context(bank_account.id(), &[deposit_note.id()], &[])?
         .build()?;
 
-    // Execute the transaction
-    let executed_deposit_transaction = deposit_tx_context.execute().await?;
+    let executed_deposit = deposit_tx_context.execute().await?;
+    bank_account.apply_delta(executed_deposit.account_delta())?;
+    mock_chain.add_pending_executed_transaction(&executed_deposit)?;
+    mock_chain.prove_next_block()?;
 
-    // Apply the account delta to the bank account
-    bank_account.apply_delta(&executed_deposit_transaction.account_delta())?;
+    // Expected P2ID output notes
+    let p2id_output_note = |serial_num: Word| -> anyhow::Result<Note> {
+        Ok(Note::new(
+            NoteAssets::new(vec![FungibleAsset::new(faucet.id(), withdraw_amount)?.into()])?,
+            NoteMetadata::new(bank_account.id(), NoteType::Public, p2id_tag),
+            build_p2id_recipient(depositor.id(), serial_num)?,
+        ))
+    };
 
-    // Add the executed transaction to the mockchain and prove
-    mock_chain.add_pending_executed_transaction(&executed_deposit_transaction)?;
-    mock_chain.prove_next_block()?;
+    // A note expecting the untouched balance is rejected
+    let wrong_tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[wrong_note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(p2id_output_note(wrong_serial_num)?)])
+        .build()?;
 
-    println!("Bank deposit successful");
+    let result = wrong_tx_context.execute().await;
 
-    // *********************************************************************************
-    // STEP 5: MAKE WITHDRAW
-    // *********************************************************************************
+    assert!(
+        result.is_err(),
+        "Expected a withdrawal expecting the wrong balance to fail, but it succeeded"
+    );
 
-    // Create expected P2ID output note with the computed tag
-    let recipient = build_p2id_recipient(sender.id(), p2id_output_note_serial_num)?;
-    let p2id_output_note_asset = FungibleAsset::new(faucet.id(), withdraw_amount)?;
-    let p2id_output_note_assets = NoteAssets::new(vec![p2id_output_note_asset.into()])?;
-    let p2id_output_note_metadata = NoteMetadata::new(
-        bank_account.id(),
-        NoteType::Public,
-        p2id_tag,
+    // A note expecting deposit - withdraw is accepted
+    let tx_context = mock_chain
+        .build_tx_context(bank_account.id(), &[checked_note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(p2id_output_note(checked_serial_num)?)])
+        .build()?;
+
+    let executed_withdraw = tx_context.execute().await?;
+    bank_account.apply_delta(executed_withdraw.account_delta())?;
+
+    // Balance after the checked withdrawal
+    assert_balance(&bank_account, depositor.id(), faucet.id(), deposit_amount - withdraw_amount);
+
+    Ok(())
+}
+
+/// Test that `withdraw` returns the index of the P2ID note it creates, and that the
+/// output note at that index is the depositor's expected payout note.
+#[tokio::test]
+async fn withdraw_returns_p2id_note_index_test() -> anyhow::Result<()> {
+    let deposit_amount: u64 = 1000;
+    let withdraw_amount: u64 = 400;
+
+    let mut fixture = BankFixture::builder().faucet_supply(deposit_amount).build().await?;
+    fixture.deposit(deposit_amount).await?;
+
+    // Test fixture: a withdraw note that checks the note index `withdraw` returns
+    let checked_withdraw_note_package =
+        build_project_cached(Path::new("tests/fixtures/checked-withdraw-note"), true)?;
+
+    let p2id_tag = p2id_tag_for_account(fixture.sender.id());
+    let checked_note = |serial_num: Word, expected_index: u64| {
+        // Expected balance and P2ID note index follow the 10 standard inputs
+        let mut inputs = build_withdraw_note_inputs(
+            FungibleAsset::new(fixture.faucet.id(), withdraw_amount)?,
+            serial_num,
+            p2id_tag,
+            NoteType::Public,
+        );
+        inputs.push(Felt::new(deposit_amount - withdraw_amount));
+        inputs.push(Felt::new(expected_index));
+
+        create_testing_note_from_package(
+            checked_withdraw_note_package.clone(),
+            fixture.sender.id(),
+            NoteCreationConfig {
+                inputs,
+                ..Default::default()
+            },
+        )
+    };
+    let p2id_output_note = |serial_num: Word| -> anyhow::Result<Note> {
+        Ok(Note::new(
+            NoteAssets::new(vec![FungibleAsset::new(fixture.faucet.id(), withdraw_amount)?.into()])?,
+            NoteMetadata::new(fixture.bank_account.id(), NoteType::Public, p2id_tag),
+            build_p2id_recipient(fixture.sender.id(), serial_num)?,
+        ))
+    };
+
+    let wrong_serial_num = Word::from([Felt::new(0x1d), Felt::new(0x1), Felt::new(0x2), Felt::new(0x3)]);
+    let checked_serial_num = Word::from([Felt::new(0x1d), Felt::new(0x4), Felt::new(0x5), Felt::new(0x6)]);
+    let wrong_note = checked_note(wrong_serial_num, 1)?;
+    let indexed_note = checked_note(checked_serial_num, 0)?;
+    let expected_p2id_note = p2id_output_note(checked_serial_num)?;
+    let wrong_p2id_note = p2id_output_note(wrong_serial_num)?;
+
+    fixture.publish(&[wrong_note.clone(), indexed_note.clone()]).await?;
+
+    // The only output note of the transaction is at index 0, not 1
+    let result = fixture.consume(&[wrong_note], vec![wrong_p2id_note]).await;
+    assert!(
+        result.is_err(),
+        "Expected a withdrawal expecting note index 1 to fail, but it succeeded"
     );
 
-    println!("Recipient digest: {:?}", recipient.digest().to_hex());
+    let executed_withdraw = fixture
+        .consume(&[indexed_note], vec![expected_p2id_note.clone()])
+        .await?;
 
-    let p2id_output_note = Note::new(
-        p2id_output_note_assets,
-        p2id_output_note_metadata,
-        recipient,
+    assert_eq!(
+        executed_withdraw.output_notes().get_note(0).id(),
+        expected_p2id_note.id(),
+        "The note at the returned index should be the depositor's P2ID note"
     );
 
-    let withdraw_request_tx_context = mock_chain
-        .build_tx_context(bank_account.id(), &[withdraw_request_note.id()], &[])?
-        .extend_expected_output_notes(vec![OutputNote::Full(p2id_output_note)])
-        .build()?;
+    Ok(())
+}
 
-    let executed_withdraw_request_transaction = withdraw_request_tx_context.execute().await?;
+/// Test that the bank's vault holdings match its tracked total after every deposit
+/// and withdrawal.
+///
+/// Each step runs the audit fixture script in the same transaction as the bank's note,
+/// so the bank itself compares `get_vault_balance` with `get_total` plus `get_fees`
+/// once the note is consumed. The client-side vault and totals slot are compared too.
+#[tokio::test]
+async fn withdraw_vault_balance_matches_total_test() -> anyhow::Result<()> {
+    let mut fixture = BankFixture::builder().build().await?;
+    let faucet_id = fixture.faucet.id();
 
-    bank_account.apply_delta(&executed_withdraw_request_transaction.account_delta())?;
+    let audit_tx_script_package =
+        build_project_cached(Path::new("tests/fixtures/audit-tx-script"), true)?;
+    let audit_program = audit_tx_script_package.unwrap_program();
+    let audit_tx_script = TransactionScript::new((*audit_program).clone());
 
-    mock_chain.add_pending_executed_transaction(&executed_withdraw_request_transaction)?;
-    mock_chain.prove_next_block()?;
+    // Script argument: [0, 0, faucet_prefix, faucet_suffix]
+    let audit_arg = Word::from([Felt::new(0), Felt::new(0), faucet_id.prefix().as_felt(), faucet_id.suffix()]);
 
-    println!("Withdraw test passed!");
+    let deposit_note = fixture.deposit_note(600)?;
+    let (first_withdraw_note, first_p2id_note) = fixture.withdraw_request_note(250)?;
+    let (second_withdraw_note, second_p2id_note) = fixture.withdraw_request_note(350)?;
+    let steps = [
+        (deposit_note, None, 600),
+        (first_withdraw_note, Some(first_p2id_note), 350),
+        (second_withdraw_note, Some(second_p2id_note), 0),
+    ];
+
+    for (note, p2id_note, expected_total) in steps {
+        fixture.publish(std::slice::from_ref(&note)).await?;
+
+        let tx_context = fixture
+            .mock_chain
+            .build_tx_context(fixture.bank_account.id(), &[note.id()], &[])?
+            .extend_expected_output_notes(p2id_note.into_iter().map(OutputNote::Full).collect())
+            .tx_script(audit_tx_script.clone())
+            .tx_script_args(audit_arg)
+            .build()?;
+
+        let executed = tx_context.execute().await?;
+        fixture.bank_account.apply_delta(executed.account_delta())?;
+        fixture.mock_chain.add_pending_executed_transaction(&executed)?;
+        fixture.mock_chain.prove_next_block()?;
+
+        let total = get_total(&fixture.bank_account, faucet_id)?;
+        assert_eq!(total, expected_total, "Tracked total after the step");
+        assert_eq!(
+            fixture.bank_account.vault().get_balance(faucet_id)?,
+            total,
+            "Vault balance should match the tracked total"
+        );
+    }
 
     Ok(())
 }